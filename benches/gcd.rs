@@ -0,0 +1,51 @@
+//! Euclidean vs binary (Stein's) GCD.
+//!
+//! Run with `cargo bench --bench gcd`.
+
+use {
+    algorist::math::gcd::{BinaryGcd, gcd},
+    std::{hint::black_box, time::Instant},
+};
+
+const PAIRS: usize = 1_000_000;
+
+/// Deterministic pseudo-random pairs of numbers.
+fn pairs() -> Vec<(u64, u64)> {
+    let mut x = 0x2545_f491_4f6c_dd1d_u64;
+    let mut next = move || {
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        x
+    };
+    (0..PAIRS).map(|_| (next(), next())).collect()
+}
+
+/// Runs `f` over all pairs, and prints the elapsed time.
+fn run<T: Copy>(name: &str, pairs: &[(T, T)], f: impl Fn(T, T) -> T) {
+    let start = Instant::now();
+    for &(a, b) in pairs {
+        black_box(f(black_box(a), black_box(b)));
+    }
+    println!(
+        "{name:>12}: {:?} for {} pairs",
+        start.elapsed(),
+        pairs.len()
+    );
+}
+
+fn main() {
+    let p64 = pairs();
+    let p32: Vec<(u32, u32)> = p64.iter().map(|&(a, b)| (a as u32, b as u32)).collect();
+    let p128: Vec<(u128, u128)> = p64
+        .iter()
+        .map(|&(a, b)| ((u128::from(a) << 64) | u128::from(b), u128::from(a ^ b)))
+        .collect();
+
+    run("gcd u32", &p32, gcd);
+    run("binary u32", &p32, u32::binary_gcd);
+    run("gcd u64", &p64, gcd);
+    run("binary u64", &p64, u64::binary_gcd);
+    run("gcd u128", &p128, gcd);
+    run("binary u128", &p128, u128::binary_gcd);
+}
@@ -0,0 +1,46 @@
+//! Throughput of `Scanner` and `Writer`.
+//!
+//! Run with `cargo bench --features bench`.
+
+use algorist::io::bench::{self, Throughput};
+
+const TOKENS: usize = 1_000_000;
+const RUNS: usize = 5;
+
+/// Runs the measurement several times, and returns the fastest run.
+fn best(f: impl Fn() -> Throughput) -> Throughput {
+    (0..RUNS).map(|_| f()).min_by_key(|t| t.elapsed).unwrap()
+}
+
+fn main() {
+    for per_line in [1, 10, 1000] {
+        let input = bench::input(TOKENS, per_line);
+        println!(
+            "scanner u64, {per_line:>4} per line: {}",
+            best(|| bench::scanner::<u64>(&input, TOKENS))
+        );
+        println!(
+            "scanner i32, {per_line:>4} per line: {}",
+            best(|| bench::scanner::<i32>(&input, TOKENS))
+        );
+        println!(
+            "scanner int, {per_line:>4} per line: {}",
+            best(|| bench::scanner_int::<u64>(&input, TOKENS))
+        );
+        println!(
+            "scanner str, {per_line:>4} per line: {}",
+            best(|| bench::scanner::<String>(&input, TOKENS))
+        );
+    }
+
+    for capacity in [1 << 10, 8 << 10, 64 << 10, 1 << 20] {
+        println!(
+            "writer, capacity {capacity:>7}: {}",
+            best(|| bench::writer(TOKENS, capacity))
+        );
+        println!(
+            "writer int, capacity {capacity:>7}: {}",
+            best(|| bench::writer_int(TOKENS, capacity))
+        );
+    }
+}
@@ -3,8 +3,8 @@
 //! See the [`Arr`] documentation for more details.
 
 use {
-    crate::io::Scanner,
-    std::{fmt::Debug, io::BufRead},
+    crate::{io::Scanner, math::Number, misc::direction::Dir8},
+    std::{cmp::Reverse, collections::BinaryHeap, fmt::Debug, io::BufRead},
 };
 
 /// A 2D array implementation.
@@ -110,9 +110,15 @@ use {
 /// To find the minimum element by a key function, you can use the
 /// [`min_by_key`](Arr::min_by_key) method.
 ///
+/// To move from a cell in a [`Dir4`](crate::misc::direction::Dir4) or [`Dir8`]
+/// direction, staying within the bounds, use the [`step`](Arr::step) method.
+///
 /// To get the coordinates of adjacent cells, you can use the
 /// [`adj_cells`](Arr::adj_cells) method, which allows you to specify whether
 /// you want adjacent cells, diagonal cells, or both.
+///
+/// To find minimal accumulated costs of reaching each cell on a weighted grid,
+/// use the [`dijkstra`](Arr::dijkstra) method.
 #[derive(Debug, PartialEq, Eq)]
 pub struct Arr<T: Debug> {
     data: Vec<T>,
@@ -588,6 +594,31 @@ impl<T: Debug> Arr<T> {
         self.data.swap(idx1, idx2);
     }
 
+    /// Returns the coordinates of the cell next to `pos` in the direction
+    /// `dir` (either [`Dir4`](crate::misc::direction::Dir4) or [`Dir8`]), or
+    /// `None` if it is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::{
+    ///     collections::arr_2d::Arr,
+    ///     misc::direction::{Dir4, Dir8},
+    /// };
+    ///
+    /// let arr: Arr<u8> = Arr::new(2, 3);
+    /// assert_eq!(arr.step((0, 0), Dir4::Right), Some((0, 1)));
+    /// assert_eq!(arr.step((0, 0), Dir4::Up), None);
+    /// assert_eq!(arr.step((0, 2), Dir8::DownLeft), Some((1, 1)));
+    /// assert_eq!(arr.step((1, 2), Dir8::DownRight), None);
+    /// ```
+    pub fn step(&self, pos: (usize, usize), dir: impl Into<Dir8>) -> Option<(usize, usize)> {
+        let (dr, dc) = dir.into().delta();
+        let row = pos.0.checked_add_signed(dr).filter(|&r| r < self.rows)?;
+        let col = pos.1.checked_add_signed(dc).filter(|&c| c < self.cols)?;
+        Some((row, col))
+    }
+
     /// Returns the number of rows in the 2D array.
     pub fn rows(&self) -> usize {
         self.rows
@@ -630,6 +661,79 @@ impl<T: Debug + Ord> Arr<T> {
     }
 }
 
+impl<T: Debug> Arr<T> {
+    /// Finds the minimal accumulated costs of reaching each cell from any of
+    /// the `starts` cells, using Dijkstra's algorithm.
+    ///
+    /// The `cost_fn` function is called for a cell being entered, and returns
+    /// the cost of entering it, or `None` if the cell cannot be entered at all
+    /// (i.e. it is a wall). Starting cells have zero cost, and the movement is
+    /// done either over 4-neighborhood ([`AdjacentCells::Adjacent`]) or over
+    /// 8-neighborhood ([`AdjacentCells::Both`]).
+    ///
+    /// Returns a 2D array of the same dimensions, where each element is the
+    /// minimal cost of reaching the cell, or `None` if the cell is
+    /// unreachable.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::collections::arr_2d::{AdjacentCells, Arr};
+    ///
+    /// // Cost of entering a cell is its value, `0` is a wall.
+    /// let arr = Arr::from_vec(vec![1, 9, 1, 1, 0, 1, 1, 1, 1], 3, 3);
+    /// let dist = arr.dijkstra(&[(0, 0)], AdjacentCells::Adjacent, |cell| {
+    ///     (*cell.value() > 0).then(|| *cell.value() as u64)
+    /// });
+    ///
+    /// assert_eq!(dist[(0, 0)], Some(0));
+    /// assert_eq!(dist[(1, 1)], None);
+    /// // Going around the wall is cheaper than going through the `9`.
+    /// assert_eq!(dist[(0, 2)], Some(6));
+    ///
+    /// // Diagonal moves are allowed with `AdjacentCells::Both`.
+    /// let dist = arr.dijkstra(&[(0, 0)], AdjacentCells::Both, |cell| {
+    ///     (*cell.value() > 0).then(|| *cell.value() as u64)
+    /// });
+    /// assert_eq!(dist[(2, 2)], Some(3));
+    /// ```
+    pub fn dijkstra<C, F>(
+        &self,
+        starts: &[(usize, usize)],
+        cell_type: AdjacentCells,
+        cost_fn: F,
+    ) -> Arr<Option<C>>
+    where
+        C: Number + Ord,
+        F: Fn(Cell<T>) -> Option<C>,
+    {
+        let mut dist: Arr<Option<C>> = Arr::with_generator(self.rows, self.cols, |_, _| None);
+        let mut heap = BinaryHeap::new();
+        for &(row, col) in starts {
+            dist[(row, col)] = Some(C::zero());
+            heap.push(Reverse((C::zero(), row, col)));
+        }
+
+        while let Some(Reverse((d, row, col))) = heap.pop() {
+            if dist[(row, col)].is_some_and(|best| best < d) {
+                continue;
+            }
+            for cell in self.adj_cells(row, col, cell_type) {
+                let (r, c) = (cell.row(), cell.col());
+                let Some(cost) = cost_fn(cell) else {
+                    continue;
+                };
+                let nd = d + cost;
+                if dist[(r, c)].map_or(true, |best| nd < best) {
+                    dist[(r, c)] = Some(nd);
+                    heap.push(Reverse((nd, r, c)));
+                }
+            }
+        }
+        dist
+    }
+}
+
 impl<T: Debug> std::fmt::Display for Arr<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         for i in 0..self.rows {
@@ -973,7 +1077,7 @@ mod tests {
         let arr = Arr::with_generator(3, 3, |i, j| i * 3 + j);
         let diags = arr
             .diags_sw()
-            .map(|x| x.map(|v| *v).collect::<Vec<_>>())
+            .map(|x| x.copied().collect::<Vec<_>>())
             .collect::<Vec<_>>();
         assert_eq!(diags, vec![
             vec![0],
@@ -989,7 +1093,7 @@ mod tests {
         let arr = Arr::with_generator(3, 3, |i, j| i * 3 + j);
         let diags = arr
             .diags_se()
-            .map(|x| x.map(|v| *v).collect::<Vec<_>>())
+            .map(|x| x.copied().collect::<Vec<_>>())
             .collect::<Vec<_>>();
         assert_eq!(diags, vec![
             vec![2],
@@ -1005,17 +1109,44 @@ mod tests {
         let arr = Arr::with_generator(3, 2, |i, j| i * 2 + j);
         let diags = arr
             .diags_sw()
-            .map(|x| x.map(|v| *v).collect::<Vec<_>>())
+            .map(|x| x.copied().collect::<Vec<_>>())
             .collect::<Vec<_>>();
         assert_eq!(diags, vec![vec![0], vec![1, 2], vec![3, 4], vec![5],]);
 
         let diags = arr
             .diags_se()
-            .map(|x| x.map(|v| *v).collect::<Vec<_>>())
+            .map(|x| x.copied().collect::<Vec<_>>())
             .collect::<Vec<_>>();
         assert_eq!(diags, vec![vec![1], vec![0, 3], vec![2, 5], vec![4],]);
     }
 
+    #[test]
+    fn test_dijkstra() {
+        #[rustfmt::skip]
+        let arr = Arr::from_vec(vec![
+            1, 1, 1, 1,
+            5, 0, 0, 1,
+            1, 1, 2, 1,
+        ], 3, 4);
+        let cost = |cell: Cell<i32>| (*cell.value() > 0).then(|| i64::from(*cell.value()));
+
+        let dist = arr.dijkstra(&[(0, 0)], AdjacentCells::Adjacent, cost);
+        assert_eq!(dist[0], [Some(0), Some(1), Some(2), Some(3)]);
+        assert_eq!(dist[1], [Some(5), None, None, Some(4)]);
+        assert_eq!(dist[2], [Some(6), Some(7), Some(7), Some(5)]);
+
+        let dist = arr.dijkstra(&[(0, 0)], AdjacentCells::Both, cost);
+        assert_eq!(dist[(2, 1)], Some(6));
+        assert_eq!(dist[(2, 2)], Some(5));
+        assert_eq!(dist[(2, 3)], Some(4));
+
+        // Multiple sources.
+        let dist = arr.dijkstra(&[(0, 0), (2, 0)], AdjacentCells::Adjacent, cost);
+        assert_eq!(dist[(1, 0)], Some(5));
+        assert_eq!(dist[(2, 1)], Some(1));
+        assert_eq!(dist[(2, 2)], Some(3));
+    }
+
     #[test]
     fn test_cell_diags_pos() {
         let arr = Arr::with_generator(3, 3, |i, j| i * 3 + j);
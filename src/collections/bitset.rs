@@ -0,0 +1,267 @@
+//! Fixed-size set of bits, packed into 64-bit words.
+//!
+//! [`BitSet`] speeds up DPs over boolean states by a factor of 64: the
+//! typical example is the subset sum, where the set of reachable sums is
+//! shifted and merged for every item.
+//!
+//! ```
+//! use algorist::collections::bitset::BitSet;
+//!
+//! // Reachable sums of subsets of [3, 5, 7], up to 20.
+//! let mut reach = BitSet::new(21);
+//! reach.set(0, true);
+//! for w in [3, 5, 7] {
+//!     reach.shl_or(w);
+//! }
+//! assert_eq!(reach.iter_ones().collect::<Vec<_>>(), vec![
+//!     0, 3, 5, 7, 8, 10, 12, 15
+//! ]);
+//! ```
+
+use std::ops::{BitAndAssign, BitOrAssign, BitXorAssign, Shl, Shr};
+
+/// Fixed-size set of bits, see the [module documentation](self).
+///
+/// Bits beyond the length are always kept unset.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitSet {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl BitSet {
+    /// Creates a set of `len` unset bits.
+    pub fn new(len: usize) -> Self {
+        Self {
+            words: vec![0; len.div_ceil(64)],
+            len,
+        }
+    }
+
+    /// Returns the number of bits (both set and unset).
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks whether the set has no bits at all.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the bit at `i`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is out of bounds.
+    pub fn get(&self, i: usize) -> bool {
+        assert!(i < self.len, "Index {i} is out of bounds");
+        self.words[i / 64] >> (i % 64) & 1 == 1
+    }
+
+    /// Sets the bit at `i` to `val`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is out of bounds.
+    pub fn set(&mut self, i: usize, val: bool) {
+        assert!(i < self.len, "Index {i} is out of bounds");
+        if val {
+            self.words[i / 64] |= 1 << (i % 64);
+        } else {
+            self.words[i / 64] &= !(1 << (i % 64));
+        }
+    }
+
+    /// Sets all the bits to `val`.
+    pub fn fill(&mut self, val: bool) {
+        self.words.fill(if val { u64::MAX } else { 0 });
+        self.trim();
+    }
+
+    /// Returns the number of set bits.
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// Returns an iterator over indexes of set bits, in increasing order.
+    pub fn iter_ones(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(i, &w)| {
+            let mut w = w;
+            std::iter::from_fn(move || {
+                (w != 0).then(|| {
+                    let bit = w.trailing_zeros() as usize;
+                    w &= w - 1;
+                    i * 64 + bit
+                })
+            })
+        })
+    }
+
+    /// Performs `self |= self << k`, in place, in `O(len / 64)`.
+    pub fn shl_or(&mut self, k: usize) {
+        let (ws, bs) = (k / 64, k % 64);
+        for i in (ws..self.words.len()).rev() {
+            let mut w = self.words[i - ws] << bs;
+            if bs > 0 && i > ws {
+                w |= self.words[i - ws - 1] >> (64 - bs);
+            }
+            self.words[i] |= w;
+        }
+        self.trim();
+    }
+
+    /// Returns the underlying words, the bit `i` being the bit `i % 64` of the
+    /// word `i / 64`.
+    pub fn words(&self) -> &[u64] {
+        &self.words
+    }
+
+    /// Returns the underlying words mutably. Bits beyond the length must be
+    /// kept unset.
+    pub fn words_mut(&mut self) -> &mut [u64] {
+        &mut self.words
+    }
+
+    /// Unsets the bits beyond the length.
+    fn trim(&mut self) {
+        if self.len % 64 != 0 {
+            let last = self.words.len() - 1;
+            self.words[last] &= (1 << (self.len % 64)) - 1;
+        }
+    }
+}
+
+macro_rules! bit_op_impl {
+    ($($tr: ident $f: ident $op: tt),+) => {$(
+        impl $tr<&BitSet> for BitSet {
+            /// # Panics
+            ///
+            /// Panics if sets are of different lengths.
+            fn $f(&mut self, other: &BitSet) {
+                assert_eq!(self.len, other.len, "Bit sets must be of the same length");
+                for (a, b) in self.words.iter_mut().zip(&other.words) {
+                    *a $op *b;
+                }
+            }
+        }
+    )+};
+}
+
+bit_op_impl!(BitAndAssign bitand_assign &=, BitOrAssign bitor_assign |=, BitXorAssign bitxor_assign ^=);
+
+impl Shl<usize> for &BitSet {
+    type Output = BitSet;
+
+    /// Shifts bits towards higher indexes, dropping the ones beyond the
+    /// length.
+    fn shl(self, k: usize) -> BitSet {
+        let mut res = BitSet::new(self.len);
+        let (ws, bs) = (k / 64, k % 64);
+        for i in ws..self.words.len() {
+            res.words[i] = self.words[i - ws] << bs;
+            if bs > 0 && i > ws {
+                res.words[i] |= self.words[i - ws - 1] >> (64 - bs);
+            }
+        }
+        res.trim();
+        res
+    }
+}
+
+impl Shr<usize> for &BitSet {
+    type Output = BitSet;
+
+    /// Shifts bits towards lower indexes.
+    fn shr(self, k: usize) -> BitSet {
+        let mut res = BitSet::new(self.len);
+        let (ws, bs) = (k / 64, k % 64);
+        let n = self.words.len();
+        for i in 0..n.saturating_sub(ws) {
+            res.words[i] = self.words[i + ws] >> bs;
+            if bs > 0 && i + ws + 1 < n {
+                res.words[i] |= self.words[i + ws + 1] << (64 - bs);
+            }
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::misc::rng::Rng};
+
+    fn random(rng: &mut Rng, n: usize) -> (BitSet, Vec<bool>) {
+        let naive: Vec<bool> = (0..n).map(|_| rng.gen_range(0..2) == 1).collect();
+        let mut bs = BitSet::new(n);
+        for (i, &b) in naive.iter().enumerate() {
+            bs.set(i, b);
+        }
+        (bs, naive)
+    }
+
+    fn to_vec(bs: &BitSet) -> Vec<bool> {
+        (0..bs.len()).map(|i| bs.get(i)).collect()
+    }
+
+    #[test]
+    fn test_against_naive() {
+        let mut rng = Rng::new(6);
+        for _ in 0..100 {
+            let n = rng.gen_range(0..200);
+            let (a, na) = random(&mut rng, n);
+            let (b, nb) = random(&mut rng, n);
+            assert_eq!(a.count_ones(), na.iter().filter(|&&x| x).count());
+            let ones: Vec<usize> = (0..n).filter(|&i| na[i]).collect();
+            assert_eq!(a.iter_ones().collect::<Vec<_>>(), ones);
+
+            let mut c = a.clone();
+            c |= &b;
+            assert_eq!(
+                to_vec(&c),
+                (0..n).map(|i| na[i] | nb[i]).collect::<Vec<_>>()
+            );
+            let mut c = a.clone();
+            c &= &b;
+            assert_eq!(
+                to_vec(&c),
+                (0..n).map(|i| na[i] & nb[i]).collect::<Vec<_>>()
+            );
+            let mut c = a.clone();
+            c ^= &b;
+            assert_eq!(
+                to_vec(&c),
+                (0..n).map(|i| na[i] ^ nb[i]).collect::<Vec<_>>()
+            );
+
+            let k = rng.gen_range(0..=n + 70);
+            let shl: Vec<bool> = (0..n).map(|i| i >= k && na[i - k]).collect();
+            assert_eq!(to_vec(&(&a << k)), shl);
+            let shr: Vec<bool> = (0..n).map(|i| i + k < n && na[i + k]).collect();
+            assert_eq!(to_vec(&(&a >> k)), shr);
+            let mut c = a.clone();
+            c.shl_or(k);
+            assert_eq!(
+                to_vec(&c),
+                (0..n).map(|i| na[i] | shl[i]).collect::<Vec<_>>()
+            );
+            assert_eq!(c.count_ones(), c.iter_ones().count());
+        }
+    }
+
+    #[test]
+    fn test_fill() {
+        let mut bs = BitSet::new(70);
+        bs.fill(true);
+        assert_eq!(bs.count_ones(), 70);
+        bs.set(3, false);
+        assert!(!bs.get(3) && bs.get(69));
+        bs.fill(false);
+        assert_eq!(bs.count_ones(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Index 70 is out of bounds")]
+    fn test_out_of_bounds() {
+        BitSet::new(70).get(70);
+    }
+}
@@ -0,0 +1,277 @@
+//! Binary indexed trees (Fenwick trees).
+//!
+//! [`Fenwick`] maintains prefix sums under point updates, both in `O(log n)`.
+//!
+//! ```
+//! use algorist::collections::fenwick::Fenwick;
+//!
+//! let mut f = Fenwick::new(5);
+//! f.add(1, 3);
+//! f.add(3, 4);
+//! assert_eq!(f.prefix_sum(2), 3); // sum of [0, 2)
+//! assert_eq!(f.range_sum(1..4), 7);
+//! ```
+//!
+//! # Prefix and suffix maximums
+//!
+//! When values only grow, the same structure maintains prefix maximums:
+//! [`FenwickMax`] supports point `chmax` updates and prefix maximum queries,
+//! and [`SuffixFenwickMax`] does the same for suffixes. This is the standard
+//! way to accelerate DPs like "longest increasing subsequence, where each
+//! element has a weight":
+//!
+//! ```
+//! use algorist::collections::fenwick::FenwickMax;
+//!
+//! // Maximum weight of a strictly increasing subsequence.
+//! let (a, w) = ([3, 1, 4, 1, 5, 9, 2, 6], [5, 1, 2, 8, 1, 1, 7, 3]);
+//! let mut best = FenwickMax::new(10);
+//! for (&x, &w) in a.iter().zip(&w) {
+//!     let cur = best.prefix_max(x).unwrap_or(0) + w;
+//!     best.chmax(x, cur);
+//! }
+//! assert_eq!(best.prefix_max(10), Some(18)); // 1, 2, 6: 8 + 7 + 3
+//! ```
+
+use {crate::math::Number, std::ops::Range};
+
+/// Fenwick tree over sums.
+#[derive(Debug, Clone)]
+pub struct Fenwick<T> {
+    tree: Vec<T>,
+}
+
+impl<T: Number> Fenwick<T> {
+    /// Creates a tree of `n` zeros.
+    pub fn new(n: usize) -> Self {
+        Self {
+            tree: vec![T::zero(); n],
+        }
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.tree.len()
+    }
+
+    /// Checks whether the tree has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.tree.is_empty()
+    }
+
+    /// Adds `delta` to the element at `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    pub fn add(&mut self, idx: usize, delta: T) {
+        assert!(idx < self.len(), "Index {idx} is out of bounds");
+        let mut i = idx + 1;
+        while i <= self.len() {
+            self.tree[i - 1] += delta;
+            i += i & i.wrapping_neg();
+        }
+    }
+
+    /// Returns the sum of elements in `[0, end)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `end` exceeds the length.
+    pub fn prefix_sum(&self, end: usize) -> T {
+        assert!(end <= self.len(), "Prefix end {end} is out of bounds");
+        let mut sum = T::zero();
+        let mut i = end;
+        while i > 0 {
+            sum += self.tree[i - 1];
+            i &= i - 1;
+        }
+        sum
+    }
+
+    /// Returns the sum of elements in the `range`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    pub fn range_sum(&self, range: Range<usize>) -> T {
+        if range.start >= range.end {
+            return T::zero();
+        }
+        self.prefix_sum(range.end) - self.prefix_sum(range.start)
+    }
+}
+
+/// Fenwick tree over maximums, supporting prefix queries.
+///
+/// Values can only be increased, see [`FenwickMax::chmax`].
+#[derive(Debug, Clone)]
+pub struct FenwickMax<T> {
+    tree: Vec<Option<T>>,
+}
+
+impl<T: Copy + Ord> FenwickMax<T> {
+    /// Creates a tree of `n` empty elements.
+    pub fn new(n: usize) -> Self {
+        Self {
+            tree: vec![None; n],
+        }
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.tree.len()
+    }
+
+    /// Checks whether the tree has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.tree.is_empty()
+    }
+
+    /// Sets the element at `idx` to the maximum of its current value and
+    /// `val`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    pub fn chmax(&mut self, idx: usize, val: T) {
+        assert!(idx < self.len(), "Index {idx} is out of bounds");
+        let mut i = idx + 1;
+        while i <= self.len() {
+            let node = &mut self.tree[i - 1];
+            *node = Some(node.map_or(val, |cur| cur.max(val)));
+            i += i & i.wrapping_neg();
+        }
+    }
+
+    /// Returns the maximum of elements in `[0, end)`, or `None` if none of
+    /// them has been set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `end` exceeds the length.
+    pub fn prefix_max(&self, end: usize) -> Option<T> {
+        assert!(end <= self.len(), "Prefix end {end} is out of bounds");
+        let mut res = None;
+        let mut i = end;
+        while i > 0 {
+            res = res.max(self.tree[i - 1]);
+            i &= i - 1;
+        }
+        res
+    }
+}
+
+/// Fenwick tree over maximums, supporting suffix queries.
+///
+/// # Example
+///
+/// ```
+/// use algorist::collections::fenwick::SuffixFenwickMax;
+///
+/// let mut f = SuffixFenwickMax::new(5);
+/// f.chmax(1, 10);
+/// f.chmax(3, 7);
+/// assert_eq!(f.suffix_max(0), Some(10));
+/// assert_eq!(f.suffix_max(2), Some(7));
+/// assert_eq!(f.suffix_max(4), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SuffixFenwickMax<T> {
+    inner: FenwickMax<T>,
+}
+
+impl<T: Copy + Ord> SuffixFenwickMax<T> {
+    /// Creates a tree of `n` empty elements.
+    pub fn new(n: usize) -> Self {
+        Self {
+            inner: FenwickMax::new(n),
+        }
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Checks whether the tree has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Sets the element at `idx` to the maximum of its current value and
+    /// `val`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    pub fn chmax(&mut self, idx: usize, val: T) {
+        assert!(idx < self.len(), "Index {idx} is out of bounds");
+        self.inner.chmax(self.len() - 1 - idx, val);
+    }
+
+    /// Returns the maximum of elements in `[start, n)`, or `None` if none of
+    /// them has been set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start` exceeds the length.
+    pub fn suffix_max(&self, start: usize) -> Option<T> {
+        assert!(start <= self.len(), "Suffix start {start} is out of bounds");
+        self.inner.prefix_max(self.len() - start)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::misc::rng::Rng};
+
+    #[test]
+    fn test_fenwick() {
+        let mut rng = Rng::new(1);
+        let n = 50;
+        let mut f = Fenwick::new(n);
+        let mut naive = vec![0i64; n];
+        for _ in 0..500 {
+            let (i, d) = (rng.gen_range(0..n), rng.gen_range(-100i64..=100));
+            f.add(i, d);
+            naive[i] += d;
+            let (l, r) = (rng.gen_range(0..=n), rng.gen_range(0..=n));
+            let want: i64 = naive[l.min(r)..r.max(l)].iter().sum();
+            assert_eq!(f.range_sum(l.min(r)..r.max(l)), want);
+            assert_eq!(f.prefix_sum(r), naive[..r].iter().sum::<i64>());
+        }
+        assert_eq!(f.range_sum(5..5), 0);
+    }
+
+    #[test]
+    fn test_fenwick_max() {
+        let mut rng = Rng::new(2);
+        let n = 50;
+        let mut pre = FenwickMax::new(n);
+        let mut suf = SuffixFenwickMax::new(n);
+        let mut naive: Vec<Option<i32>> = vec![None; n];
+        for _ in 0..500 {
+            let (i, v) = (rng.gen_range(0..n), rng.gen_range(-1_000..1_000));
+            pre.chmax(i, v);
+            suf.chmax(i, v);
+            naive[i] = naive[i].max(Some(v));
+            let k = rng.gen_range(0..=n);
+            assert_eq!(
+                pre.prefix_max(k),
+                naive[..k].iter().copied().max().flatten()
+            );
+            assert_eq!(
+                suf.suffix_max(k),
+                naive[k..].iter().copied().max().flatten()
+            );
+        }
+        assert_eq!(FenwickMax::<i32>::new(0).prefix_max(0), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Index 3 is out of bounds")]
+    fn test_out_of_bounds() {
+        FenwickMax::new(3).chmax(3, 1);
+    }
+}
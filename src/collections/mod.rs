@@ -5,5 +5,16 @@
 //! | Module | Description
 //! | --- | ---
 //! | [`arr_2d::Arr`] | A 2D array implementation with various utility methods.
+//! | [`bitset::BitSet`] | Fixed-size set of bits, for 64 times faster DPs over boolean states.
+//! | [`fenwick::Fenwick`] | Binary indexed trees, over sums and over prefix/suffix maximums.
+//! | [`one_indexed::Vec1`] | Vector indexed from 1, for 1-based vertices and permutations.
+//! | [`prefix_sum_2d::DiagPrefixSum2d`] | Prefix sums along diagonals of a 2D array.
+//! | [`treap::Treap`] | A randomized treap, both as an ordered map and an implicit sequence (requires the `heavy-collections` feature).
 
 pub mod arr_2d;
+pub mod bitset;
+pub mod fenwick;
+pub mod one_indexed;
+pub mod prefix_sum_2d;
+#[cfg(feature = "heavy-collections")]
+pub mod treap;
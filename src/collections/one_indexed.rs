@@ -0,0 +1,229 @@
+//! Vector, indexed from 1.
+//!
+//! Trees, DSUs and permutations are usually given with 1-based vertices. Rather
+//! than converting every index (and every answer) back and forth, the data can
+//! be kept in [`Vec1`], which indexes from 1 and panics on index 0:
+//!
+//! ```
+//! use algorist::{collections::one_indexed::Vec1, io::Scanner};
+//!
+//! // Parents of vertices 2..=5 of a rooted tree.
+//! let mut scan = Scanner::new("5\n1 1 2 2".as_bytes());
+//! let n = scan.u();
+//! let mut parent = Vec1::new(n, 0);
+//! for v in 2..=n {
+//!     parent[v] = scan.u();
+//! }
+//!
+//! let mut depth = Vec1::new(n, 0);
+//! for v in 2..=n {
+//!     depth[v] = depth[parent[v]] + 1;
+//! }
+//! assert_eq!(depth.as_ref(), &[0, 1, 1, 2, 2]);
+//! ```
+
+use {
+    crate::io::Scanner,
+    std::{
+        io::BufRead,
+        ops::{Index, IndexMut, RangeInclusive},
+        str::FromStr,
+    },
+};
+
+/// Vector with elements at indexes `1..=len`.
+///
+/// # Panics
+///
+/// Indexing panics on index `0`, and on indexes greater than the length.
+///
+/// ```should_panic
+/// use algorist::collections::one_indexed::Vec1;
+///
+/// let v = Vec1::from(vec![1, 2, 3]);
+/// v[0]; // panics: "Index 0 is not a valid 1-based index"
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Vec1<T> {
+    data: Vec<T>,
+}
+
+impl<T> Vec1<T> {
+    /// Creates a vector of `n` copies of `val`, at indexes `1..=n`.
+    pub fn new(n: usize, val: T) -> Self
+    where
+        T: Clone,
+    {
+        Self { data: vec![val; n] }
+    }
+
+    /// Reads `n` elements from the input, placing them at indexes `1..=n`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::{collections::one_indexed::Vec1, io::Scanner};
+    ///
+    /// let mut scan = Scanner::new("10 20 30".as_bytes());
+    /// let v: Vec1<u32> = Vec1::from_scan(&mut scan, 3);
+    /// assert_eq!((v[1], v[3]), (10, 30));
+    /// ```
+    pub fn from_scan<B: BufRead>(scan: &mut Scanner<B>, n: usize) -> Self
+    where
+        T: FromStr,
+    {
+        Self {
+            data: (0..n).map(|_| scan.next()).collect(),
+        }
+    }
+
+    /// Returns the number of elements, i.e. the largest valid index.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Checks whether the vector is empty.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns the range of valid indexes, `1..=len`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::collections::one_indexed::Vec1;
+    ///
+    /// let v = Vec1::from(vec![5, 7]);
+    /// let sum: usize = v.indices().map(|i| i * v[i]).sum();
+    /// assert_eq!(sum, 19);
+    /// ```
+    pub fn indices(&self) -> RangeInclusive<usize> {
+        1..=self.len()
+    }
+
+    /// Appends an element, at index `len + 1`.
+    pub fn push(&mut self, val: T) {
+        self.data.push(val);
+    }
+
+    /// Returns an iterator over the elements, in the order of indexes.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.data.iter()
+    }
+
+    /// Returns an iterator over `(index, element)` pairs, with 1-based
+    /// indexes.
+    pub fn enumerate(&self) -> impl Iterator<Item = (usize, &T)> {
+        (1..).zip(&self.data)
+    }
+
+    /// Returns the underlying 0-indexed vector.
+    pub fn into_inner(self) -> Vec<T> {
+        self.data
+    }
+
+    fn offset(&self, idx: usize) -> usize {
+        assert!(idx != 0, "Index 0 is not a valid 1-based index");
+        assert!(idx <= self.len(), "Index {idx} is out of bounds");
+        idx - 1
+    }
+}
+
+impl<T> From<Vec<T>> for Vec1<T> {
+    /// Wraps a vector, so that its first element gets index 1.
+    fn from(data: Vec<T>) -> Self {
+        Self { data }
+    }
+}
+
+impl<T> FromIterator<T> for Vec1<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self {
+            data: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T> Index<usize> for Vec1<T> {
+    type Output = T;
+
+    fn index(&self, idx: usize) -> &T {
+        &self.data[self.offset(idx)]
+    }
+}
+
+impl<T> IndexMut<usize> for Vec1<T> {
+    fn index_mut(&mut self, idx: usize) -> &mut T {
+        let i = self.offset(idx);
+        &mut self.data[i]
+    }
+}
+
+impl<T> AsRef<[T]> for Vec1<T> {
+    fn as_ref(&self) -> &[T] {
+        &self.data
+    }
+}
+
+impl<T> IntoIterator for Vec1<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Vec1<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_indexing() {
+        let mut v: Vec1<i32> = (1..=3).collect();
+        assert_eq!((v[1], v[2], v[3]), (1, 2, 3));
+        v[3] *= 10;
+        v.push(4);
+        assert_eq!(v.len(), 4);
+        assert_eq!(v.indices(), 1..=4);
+        let pairs: Vec<_> = v.enumerate().map(|(i, &x)| (i, x)).collect();
+        assert_eq!(pairs, vec![(1, 1), (2, 2), (3, 30), (4, 4)]);
+        assert_eq!(v.into_inner(), vec![1, 2, 30, 4]);
+
+        let empty = Vec1::<u8>::default();
+        assert!(empty.is_empty());
+        assert!(empty.indices().next().is_none());
+    }
+
+    #[test]
+    fn test_from_scan() {
+        let mut scan = Scanner::new("3\n-1 0 7\n".as_bytes());
+        let n = scan.u();
+        let v: Vec1<i64> = Vec1::from_scan(&mut scan, n);
+        assert_eq!(v.iter().copied().collect::<Vec<_>>(), vec![-1, 0, 7]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Index 0 is not a valid 1-based index")]
+    fn test_index_zero() {
+        let mut v = Vec1::new(2, 0);
+        v[0] = 1;
+    }
+
+    #[test]
+    #[should_panic(expected = "Index 3 is out of bounds")]
+    fn test_index_out_of_bounds() {
+        let v = Vec1::new(2, 0);
+        let _ = v[3];
+    }
+}
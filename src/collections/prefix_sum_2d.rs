@@ -0,0 +1,135 @@
+//! Prefix sums over 2D arrays.
+//!
+//! See the [`DiagPrefixSum2d`] documentation for more details.
+
+use {super::arr_2d::Arr, crate::math::Number, std::fmt::Debug};
+
+/// Prefix sums along the diagonals of a 2D array.
+///
+/// After `O(rows * cols)` preprocessing, allows to get sums over any segment of
+/// a "↘" (south-east) or "↙" (south-west) diagonal in `O(1)`. This is useful
+/// for problems where pieces move diagonally (bishops), or when sums over
+/// triangles are built from diagonal segments.
+///
+/// # Example
+///
+/// ```
+/// use algorist::collections::{arr_2d::Arr, prefix_sum_2d::DiagPrefixSum2d};
+///
+/// // 1 2 3
+/// // 4 5 6
+/// // 7 8 9
+/// let arr = Arr::with_generator(3, 3, |i, j| (i * 3 + j + 1) as i64);
+/// let sums = DiagPrefixSum2d::new(&arr);
+///
+/// // "↘" diagonal from (0, 0): 1 + 5 + 9
+/// assert_eq!(sums.sum_se(0, 0, 3), 15);
+/// // "↘" diagonal from (1, 0): 4 + 8
+/// assert_eq!(sums.sum_se(1, 0, 2), 12);
+///
+/// // "↙" diagonal from (0, 2): 3 + 5 + 7
+/// assert_eq!(sums.sum_sw(0, 2, 3), 15);
+/// // "↙" diagonal from (1, 2), single cell: 6
+/// assert_eq!(sums.sum_sw(1, 2, 1), 6);
+/// ```
+pub struct DiagPrefixSum2d<T: Debug> {
+    se: Arr<T>,
+    sw: Arr<T>,
+}
+
+impl<T: Number> DiagPrefixSum2d<T> {
+    /// Precomputes diagonal prefix sums of the array.
+    pub fn new(arr: &Arr<T>) -> Self {
+        let (rows, cols) = (arr.rows(), arr.cols());
+
+        // se[(r + 1, c + 1)] is the sum of (r, c), (r - 1, c - 1), ...
+        let mut se = Arr::new(rows + 1, cols + 1);
+        // sw[(r + 1, c)] is the sum of (r, c), (r - 1, c + 1), ...
+        let mut sw = Arr::new(rows + 1, cols + 1);
+        for r in 0..rows {
+            for c in 0..cols {
+                se[(r + 1, c + 1)] = se[(r, c)] + arr[(r, c)];
+                sw[(r + 1, c)] = sw[(r, c + 1)] + arr[(r, c)];
+            }
+        }
+        Self { se, sw }
+    }
+
+    /// Returns the sum of `len` cells on the "↘" (south-east) diagonal,
+    /// starting from `(row, col)`, i.e. cells `(row + i, col + i)` for `i` in
+    /// `0..len`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the segment goes out of bounds.
+    pub fn sum_se(&self, row: usize, col: usize, len: usize) -> T {
+        self.se[(row + len, col + len)] - self.se[(row, col)]
+    }
+
+    /// Returns the sum of `len` cells on the "↙" (south-west) diagonal,
+    /// starting from `(row, col)`, i.e. cells `(row + i, col - i)` for `i` in
+    /// `0..len`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the segment goes out of bounds.
+    pub fn sum_sw(&self, row: usize, col: usize, len: usize) -> T {
+        if len == 0 {
+            return T::zero();
+        }
+        assert!(len <= col + 1, "Diagonal segment is out of bounds");
+        self.sw[(row + len, col + 1 - len)] - self.sw[(row, col + 1)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diag_sums() {
+        let (rows, cols) = (4, 5);
+        let arr = Arr::with_generator(rows, cols, |i, j| (i * 7 + j * 3) as i64 % 11 - 5);
+        let sums = DiagPrefixSum2d::new(&arr);
+
+        for r in 0..rows {
+            for c in 0..cols {
+                for len in 0..=(rows - r).min(cols - c) {
+                    let want: i64 = (0..len).map(|i| arr[(r + i, c + i)]).sum();
+                    assert_eq!(sums.sum_se(r, c, len), want, "se ({r}, {c}), {len}");
+                }
+                for len in 0..=(rows - r).min(c + 1) {
+                    let want: i64 = (0..len).map(|i| arr[(r + i, c - i)]).sum();
+                    assert_eq!(sums.sum_sw(r, c, len), want, "sw ({r}, {c}), {len}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_diags_match_arr() {
+        let arr = Arr::with_generator(3, 4, |i, j| (i * 4 + j) as u64);
+        let sums = DiagPrefixSum2d::new(&arr);
+
+        // Whole "↙" diagonals, as returned by `Arr::diags_sw`.
+        let got: Vec<u64> = (0..arr.rows() + arr.cols() - 1)
+            .map(|d| {
+                let (r, c) = if d < arr.cols() {
+                    (0, d)
+                } else {
+                    (d + 1 - arr.cols(), arr.cols() - 1)
+                };
+                sums.sum_sw(r, c, (arr.rows() - r).min(c + 1))
+            })
+            .collect();
+        let want: Vec<u64> = arr.diags_sw().map(|d| d.sum()).collect();
+        assert_eq!(got, want);
+    }
+
+    #[test]
+    #[should_panic(expected = "Diagonal segment is out of bounds")]
+    fn test_sum_sw_out_of_bounds() {
+        let arr = Arr::with_generator(3, 3, |i, j| i + j);
+        DiagPrefixSum2d::new(&arr).sum_sw(0, 1, 3);
+    }
+}
@@ -0,0 +1,510 @@
+//! Randomized treap, usable both as an ordered map and as an implicit
+//! sequence.
+//!
+//! See the [`Treap`] documentation for more details.
+
+use std::{
+    cmp::Ordering,
+    ops::Range,
+    sync::atomic::{self, AtomicU64},
+};
+
+type Link<K, V> = Option<Box<Node<K, V>>>;
+
+struct Node<K, V> {
+    key: K,
+    val: V,
+    agg: V,
+    prio: u64,
+    size: usize,
+    left: Link<K, V>,
+    right: Link<K, V>,
+}
+
+impl<K, V: Clone> Node<K, V> {
+    fn new(key: K, val: V) -> Self {
+        Self {
+            key,
+            agg: val.clone(),
+            val,
+            prio: random_priority(),
+            size: 1,
+            left: None,
+            right: None,
+        }
+    }
+
+    fn update(&mut self, op: fn(&V, &V) -> V) {
+        self.size = 1 + size(&self.left) + size(&self.right);
+        self.agg = match (&self.left, &self.right) {
+            (None, None) => self.val.clone(),
+            (Some(l), None) => op(&l.agg, &self.val),
+            (None, Some(r)) => op(&self.val, &r.agg),
+            (Some(l), Some(r)) => op(&op(&l.agg, &self.val), &r.agg),
+        };
+    }
+}
+
+/// Returns a pseudo-random priority (`splitmix64` over a global counter).
+fn random_priority() -> u64 {
+    static STATE: AtomicU64 = AtomicU64::new(0x2545_F491_4F6C_DD1D);
+    let mut z = STATE.fetch_add(0x9E37_79B9_7F4A_7C15, atomic::Ordering::Relaxed);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+fn size<K, V>(t: &Link<K, V>) -> usize {
+    t.as_ref().map_or(0, |n| n.size)
+}
+
+fn merge<K, V: Clone>(a: Link<K, V>, b: Link<K, V>, op: fn(&V, &V) -> V) -> Link<K, V> {
+    match (a, b) {
+        (None, t) | (t, None) => t,
+        (Some(mut a), Some(mut b)) => {
+            if a.prio > b.prio {
+                a.right = merge(a.right.take(), Some(b), op);
+                a.update(op);
+                Some(a)
+            } else {
+                b.left = merge(Some(a), b.left.take(), op);
+                b.update(op);
+                Some(b)
+            }
+        }
+    }
+}
+
+/// Splits into the first `k` nodes and the rest.
+fn split_by_size<K, V: Clone>(
+    t: Link<K, V>,
+    k: usize,
+    op: fn(&V, &V) -> V,
+) -> (Link<K, V>, Link<K, V>) {
+    let Some(mut n) = t else {
+        return (None, None);
+    };
+    let left_size = size(&n.left);
+    if k <= left_size {
+        let (l, r) = split_by_size(n.left.take(), k, op);
+        n.left = r;
+        n.update(op);
+        (l, Some(n))
+    } else {
+        let (l, r) = split_by_size(n.right.take(), k - left_size - 1, op);
+        n.right = l;
+        n.update(op);
+        (Some(n), r)
+    }
+}
+
+/// Splits into nodes with keys `< key` and nodes with keys `>= key`.
+fn split_by_key<K: Ord, V: Clone>(
+    t: Link<K, V>,
+    key: &K,
+    op: fn(&V, &V) -> V,
+) -> (Link<K, V>, Link<K, V>) {
+    let Some(mut n) = t else {
+        return (None, None);
+    };
+    if n.key < *key {
+        let (l, r) = split_by_key(n.right.take(), key, op);
+        n.right = l;
+        n.update(op);
+        (Some(n), r)
+    } else {
+        let (l, r) = split_by_key(n.left.take(), key, op);
+        n.left = r;
+        n.update(op);
+        (l, Some(n))
+    }
+}
+
+/// Randomized treap (Cartesian tree) with subtree aggregates.
+///
+/// The same structure supports two use-cases:
+///
+/// - **Ordered map**: nodes are ordered by keys, see [`insert`](Treap::insert),
+///   [`get`](Treap::get), [`remove`](Treap::remove) and
+///   [`range_agg`](Treap::range_agg).
+/// - **Implicit sequence**: nodes are ordered by their position, keys are
+///   irrelevant (normally, `()`), see [`insert_at`](Treap::insert_at),
+///   [`get_at`](Treap::get_at), [`remove_at`](Treap::remove_at) and
+///   [`agg_at`](Treap::agg_at).
+///
+/// Both rely on the same core operations:
+/// [`split_by_key`](Treap::split_by_key),
+/// [`split_by_size`](Treap::split_by_size) and [`merge`](Treap::merge), all
+/// running in expected `O(log n)`.
+///
+/// Every node stores an aggregate of values in its subtree, computed using an
+/// associative operation `op` given at construction (e.g. sum, min, max).
+///
+/// # Ordered map
+///
+/// ```
+/// use algorist::collections::treap::Treap;
+///
+/// let mut map = Treap::new(|a: &i64, b: &i64| a + b);
+/// map.insert(5, 50);
+/// map.insert(1, 10);
+/// map.insert(3, 30);
+/// map.insert(3, 33); // replaces the value
+///
+/// assert_eq!(map.len(), 3);
+/// assert_eq!(map.get(&3), Some(&33));
+/// assert_eq!(map.get(&4), None);
+///
+/// // Sum of values for keys in `[1, 5)`.
+/// assert_eq!(map.range_agg(&1, &5), Some(43));
+///
+/// assert_eq!(map.remove(&1), Some(10));
+/// assert_eq!(map.iter().collect::<Vec<_>>(), vec![(&3, &33), (&5, &50)]);
+/// ```
+///
+/// # Implicit sequence
+///
+/// ```
+/// use algorist::collections::treap::Treap;
+///
+/// let mut seq = Treap::new(|a: &i32, b: &i32| *a.min(b));
+/// for (i, x) in [5, 3, 8, 1].into_iter().enumerate() {
+///     seq.insert_at(i, (), x);
+/// }
+/// seq.insert_at(1, (), 7); // 5 7 3 8 1
+///
+/// assert_eq!(seq.values(), vec![5, 7, 3, 8, 1]);
+/// assert_eq!(seq.agg_at(0..3), Some(3));
+/// assert_eq!(seq.agg_at(3..5), Some(1));
+///
+/// // Cut the sequence and glue parts in a different order.
+/// let (left, right) = seq.split_by_size(2);
+/// let seq = right.merge(left);
+/// assert_eq!(seq.values(), vec![3, 8, 1, 5, 7]);
+/// ```
+pub struct Treap<K, V> {
+    root: Link<K, V>,
+    op: fn(&V, &V) -> V,
+}
+
+impl<K, V: Clone> Treap<K, V> {
+    /// Creates an empty treap, with `op` used to aggregate values.
+    ///
+    /// If no aggregation is needed, any function can be used, for example
+    /// `|a, _| a.clone()`.
+    pub fn new(op: fn(&V, &V) -> V) -> Self {
+        Self { root: None, op }
+    }
+
+    fn with_root(&self, root: Link<K, V>) -> Self {
+        Self { root, op: self.op }
+    }
+
+    /// Returns the number of nodes in the treap.
+    pub fn len(&self) -> usize {
+        size(&self.root)
+    }
+
+    /// Returns whether the treap is empty.
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    /// Returns the aggregate of all values in the treap.
+    pub fn agg(&self) -> Option<&V> {
+        self.root.as_ref().map(|n| &n.agg)
+    }
+
+    /// Concatenates two treaps, nodes of `other` go after the nodes of `self`.
+    ///
+    /// When the treap is used as an ordered map, all keys of `self` must be
+    /// smaller than the keys of `other`.
+    #[must_use]
+    pub fn merge(mut self, other: Self) -> Self {
+        let root = merge(self.root.take(), other.root, self.op);
+        self.with_root(root)
+    }
+
+    /// Splits the treap into the first `k` nodes, and the rest.
+    #[must_use]
+    pub fn split_by_size(mut self, k: usize) -> (Self, Self) {
+        let (l, r) = split_by_size(self.root.take(), k, self.op);
+        (self.with_root(l), self.with_root(r))
+    }
+
+    /// Inserts a node at position `pos` of the sequence.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pos > len`.
+    pub fn insert_at(&mut self, pos: usize, key: K, val: V) {
+        assert!(pos <= self.len(), "Position out of bounds: {pos}");
+        let (l, r) = split_by_size(self.root.take(), pos, self.op);
+        let mid = Some(Box::new(Node::new(key, val)));
+        self.root = merge(merge(l, mid, self.op), r, self.op);
+    }
+
+    /// Removes the node at position `pos` of the sequence, returning its key
+    /// and value.
+    pub fn remove_at(&mut self, pos: usize) -> Option<(K, V)> {
+        if pos >= self.len() {
+            return None;
+        }
+        let (l, r) = split_by_size(self.root.take(), pos, self.op);
+        let (mid, r) = split_by_size(r, 1, self.op);
+        self.root = merge(l, r, self.op);
+        mid.map(|n| (n.key, n.val))
+    }
+
+    /// Returns the key and the value of the node at position `pos`.
+    pub fn get_at(&self, mut pos: usize) -> Option<(&K, &V)> {
+        let mut cur = self.root.as_ref();
+        while let Some(n) = cur {
+            let left_size = size(&n.left);
+            match pos.cmp(&left_size) {
+                Ordering::Less => cur = n.left.as_ref(),
+                Ordering::Equal => return Some((&n.key, &n.val)),
+                Ordering::Greater => {
+                    pos -= left_size + 1;
+                    cur = n.right.as_ref();
+                }
+            }
+        }
+        None
+    }
+
+    /// Returns the aggregate of values at positions in `range`, or `None` if
+    /// the range is empty.
+    pub fn agg_at(&mut self, range: Range<usize>) -> Option<V> {
+        let (l, r) = split_by_size(self.root.take(), range.start, self.op);
+        let (mid, r) = split_by_size(r, range.end.saturating_sub(range.start), self.op);
+        let res = mid.as_ref().map(|n| n.agg.clone());
+        self.root = merge(merge(l, mid, self.op), r, self.op);
+        res
+    }
+
+    /// Iterates over keys and values, in order.
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        let mut iter = Iter { stack: Vec::new() };
+        iter.push_left(self.root.as_deref());
+        iter
+    }
+
+    /// Returns all values, in order.
+    pub fn values(&self) -> Vec<V> {
+        self.iter().map(|(_, v)| v.clone()).collect()
+    }
+}
+
+impl<K: Ord, V: Clone> Treap<K, V> {
+    /// Splits the treap into nodes with keys `< key`, and nodes with keys `>=
+    /// key`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::collections::treap::Treap;
+    ///
+    /// let mut map = Treap::new(|a: &i32, b: &i32| a + b);
+    /// for k in 1..=5 {
+    ///     map.insert(k, k * 10);
+    /// }
+    /// let (lo, hi) = map.split_by_key(&3);
+    /// assert_eq!(lo.values(), vec![10, 20]);
+    /// assert_eq!(hi.values(), vec![30, 40, 50]);
+    /// ```
+    #[must_use]
+    pub fn split_by_key(mut self, key: &K) -> (Self, Self) {
+        let (l, r) = split_by_key(self.root.take(), key, self.op);
+        (self.with_root(l), self.with_root(r))
+    }
+
+    /// Inserts a key-value pair, returning the previous value for the key.
+    pub fn insert(&mut self, key: K, val: V) -> Option<V> {
+        let prev = self.remove(&key);
+        let (l, r) = split_by_key(self.root.take(), &key, self.op);
+        let mid = Some(Box::new(Node::new(key, val)));
+        self.root = merge(merge(l, mid, self.op), r, self.op);
+        prev
+    }
+
+    /// Removes a key, returning its value.
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let (l, r) = split_by_key(self.root.take(), key, self.op);
+        let (mid, r) = split_by_size(r, 1, self.op);
+        match mid {
+            Some(n) if n.key == *key => {
+                self.root = merge(l, r, self.op);
+                Some(n.val)
+            }
+            mid => {
+                self.root = merge(merge(l, mid, self.op), r, self.op);
+                None
+            }
+        }
+    }
+
+    /// Returns the value for the key.
+    pub fn get(&self, key: &K) -> Option<&V> {
+        let mut cur = self.root.as_ref();
+        while let Some(n) = cur {
+            match key.cmp(&n.key) {
+                Ordering::Less => cur = n.left.as_ref(),
+                Ordering::Equal => return Some(&n.val),
+                Ordering::Greater => cur = n.right.as_ref(),
+            }
+        }
+        None
+    }
+
+    /// Returns whether the key is in the treap.
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Returns the number of keys that are `< key`.
+    pub fn rank(&self, key: &K) -> usize {
+        let mut cur = self.root.as_ref();
+        let mut rank = 0;
+        while let Some(n) = cur {
+            if n.key < *key {
+                rank += size(&n.left) + 1;
+                cur = n.right.as_ref();
+            } else {
+                cur = n.left.as_ref();
+            }
+        }
+        rank
+    }
+
+    /// Returns the aggregate of values for keys in `[lo, hi)`, or `None` if
+    /// there are no such keys.
+    pub fn range_agg(&mut self, lo: &K, hi: &K) -> Option<V> {
+        let (l, r) = split_by_key(self.root.take(), lo, self.op);
+        let (mid, r) = split_by_key(r, hi, self.op);
+        let res = mid.as_ref().map(|n| n.agg.clone());
+        self.root = merge(merge(l, mid, self.op), r, self.op);
+        res
+    }
+}
+
+/// In-order iterator over a [`Treap`].
+pub struct Iter<'a, K, V> {
+    stack: Vec<&'a Node<K, V>>,
+}
+
+impl<'a, K, V> Iter<'a, K, V> {
+    fn push_left(&mut self, mut cur: Option<&'a Node<K, V>>) {
+        while let Some(n) = cur {
+            self.stack.push(n);
+            cur = n.left.as_deref();
+        }
+    }
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let n = self.stack.pop()?;
+        self.push_left(n.right.as_deref());
+        Some((&n.key, &n.val))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, std::collections::BTreeMap};
+
+    fn sum(a: &i64, b: &i64) -> i64 {
+        a + b
+    }
+
+    #[test]
+    fn ordered_map() {
+        let mut map = Treap::new(sum);
+        let mut expected = BTreeMap::new();
+        let mut x = 12345i64;
+        for _ in 0..2000 {
+            x = (x * 1_103_515_245 + 12345) % 1_000_003;
+            let key = x % 300;
+            if x % 3 == 0 {
+                assert_eq!(map.remove(&key), expected.remove(&key));
+            } else {
+                assert_eq!(map.insert(key, x), expected.insert(key, x));
+            }
+            assert_eq!(map.len(), expected.len());
+        }
+
+        let items: Vec<_> = map.iter().map(|(&k, &v)| (k, v)).collect();
+        assert_eq!(items, expected.clone().into_iter().collect::<Vec<_>>());
+
+        for (lo, hi) in [(0, 300), (10, 20), (150, 151), (299, 300), (20, 10)] {
+            let want: i64 = expected.range(lo..hi.max(lo)).map(|(_, v)| v).sum();
+            let got = map.range_agg(&lo, &hi);
+            assert_eq!(got.unwrap_or(0), want, "range [{lo}, {hi})");
+        }
+        for key in 0..300 {
+            assert_eq!(map.get(&key), expected.get(&key));
+            assert_eq!(map.rank(&key), expected.range(..key).count());
+        }
+        assert_eq!(map.len(), expected.len());
+    }
+
+    #[test]
+    fn implicit_sequence() {
+        let mut seq = Treap::new(|a: &i64, b: &i64| *a.max(b));
+        let mut expected = Vec::new();
+        let mut x = 42i64;
+        for _ in 0..1000 {
+            x = (x * 1_103_515_245 + 12345) % 1_000_003;
+            let pos = (x as usize) % (expected.len() + 1);
+            if x % 4 == 0 && !expected.is_empty() {
+                let pos = pos.min(expected.len() - 1);
+                assert_eq!(
+                    seq.remove_at(pos).map(|(_, v)| v),
+                    Some(expected.remove(pos))
+                );
+            } else {
+                seq.insert_at(pos, (), x);
+                expected.insert(pos, x);
+            }
+        }
+        assert_eq!(seq.values(), expected);
+        assert_eq!(seq.remove_at(expected.len()), None);
+
+        for (l, r) in [(0, expected.len()), (3, 17), (5, 6), (7, 7)] {
+            assert_eq!(seq.agg_at(l..r), expected[l..r].iter().copied().max());
+        }
+        for (i, &v) in expected.iter().enumerate() {
+            assert_eq!(seq.get_at(i), Some((&(), &v)));
+        }
+        assert_eq!(seq.get_at(expected.len()), None);
+    }
+
+    #[test]
+    fn split_and_merge() {
+        let mut map = Treap::new(sum);
+        for k in 0..10 {
+            map.insert(k, k);
+        }
+        assert_eq!(map.agg(), Some(&45));
+
+        let (lo, hi) = map.split_by_key(&4);
+        assert_eq!(lo.len(), 4);
+        assert_eq!(lo.agg(), Some(&6));
+        assert_eq!(hi.agg(), Some(&39));
+
+        let (a, b) = hi.split_by_size(2);
+        assert_eq!(a.values(), vec![4, 5]);
+        assert_eq!(b.values(), vec![6, 7, 8, 9]);
+
+        let map = lo.merge(a).merge(b);
+        assert_eq!(map.values(), (0..10).collect::<Vec<_>>());
+
+        let (empty, all) = map.split_by_size(0);
+        assert!(empty.is_empty());
+        assert_eq!(empty.agg(), None);
+        assert_eq!(all.len(), 10);
+    }
+}
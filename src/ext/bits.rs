@@ -0,0 +1,267 @@
+//! Bit manipulation helpers for bitmask DP.
+//!
+//! The [`BitOps`] trait gives all primitive integers the same set of bit
+//! queries (for signed integers, the bits of the two's complement
+//! representation are considered):
+//!
+//! ```
+//! use algorist::ext::bits::BitOps;
+//!
+//! let mask = 0b0110_1000u32;
+//! assert_eq!(mask.popcount(), 3);
+//! assert_eq!(mask.lowest_set_bit(), Some(3));
+//! assert_eq!(mask.highest_set_bit(), Some(6));
+//! assert_eq!(0u32.lowest_set_bit(), None);
+//! assert!(64i64.is_pow2());
+//! assert_eq!(100u32.next_pow2(), 128);
+//! ```
+//!
+//! Masks are enumerated with [`iterate_submasks`] and
+//! [`iterate_subsets_of_size`]:
+//!
+//! ```
+//! use algorist::ext::bits::{iterate_submasks, iterate_subsets_of_size};
+//!
+//! let subs: Vec<_> = iterate_submasks(0b101).collect();
+//! assert_eq!(subs, vec![0b101, 0b100, 0b001, 0b000]);
+//!
+//! let pairs: Vec<_> = iterate_subsets_of_size(4, 2).collect();
+//! assert_eq!(pairs, vec![0b0011, 0b0101, 0b0110, 0b1001, 0b1010, 0b1100]);
+//! ```
+//!
+//! Iterating over submasks of every mask takes `O(3^n)` in total, which is
+//! the standard way to run DP over subsets, where each mask is split into two
+//! parts:
+//!
+//! ```
+//! use algorist::ext::bits::iterate_submasks;
+//!
+//! // Number of ways to partition a set of 4 elements (Bell number).
+//! let n = 4;
+//! let mut ways = vec![0u64; 1 << n];
+//! ways[0] = 1;
+//! for mask in 1usize..1 << n {
+//!     // Fix the lowest element, and choose the rest of its part.
+//!     let low = mask & mask.wrapping_neg();
+//!     for sub in iterate_submasks(mask ^ low) {
+//!         ways[mask] += ways[mask ^ low ^ sub];
+//!     }
+//! }
+//! assert_eq!(ways[(1 << n) - 1], 15);
+//! ```
+
+/// Bit queries on primitive integers, see the [module
+/// documentation](self).
+pub trait BitOps: Copy {
+    /// Returns the number of set bits.
+    fn popcount(self) -> u32;
+
+    /// Returns the index of the lowest set bit, or `None` if the number is
+    /// zero.
+    fn lowest_set_bit(self) -> Option<u32>;
+
+    /// Returns the index of the highest set bit, or `None` if the number is
+    /// zero.
+    fn highest_set_bit(self) -> Option<u32>;
+
+    /// Checks whether the number is a (positive) power of two.
+    ///
+    /// Unlike the inherent `is_power_of_two` of unsigned integers, is also
+    /// available for signed ones.
+    fn is_pow2(self) -> bool;
+
+    /// Returns the smallest power of two, which is not less than the number
+    /// (`1` for numbers up to `1`).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result doesn't fit into the type (unlike the inherent
+    /// `next_power_of_two` of unsigned integers, also in release builds).
+    fn next_pow2(self) -> Self;
+}
+
+macro_rules! bit_ops_impl {
+    ($($t: ident => $u: ident),+) => {$(
+        impl BitOps for $t {
+            fn popcount(self) -> u32 {
+                self.count_ones()
+            }
+
+            fn lowest_set_bit(self) -> Option<u32> {
+                (self != 0).then(|| self.trailing_zeros())
+            }
+
+            fn highest_set_bit(self) -> Option<u32> {
+                (self != 0).then(|| Self::BITS - 1 - self.leading_zeros())
+            }
+
+            fn is_pow2(self) -> bool {
+                self > 0 && self.count_ones() == 1
+            }
+
+            #[allow(clippy::unnecessary_cast)]
+            fn next_pow2(self) -> Self {
+                (self.max(1) as $u)
+                    .checked_next_power_of_two()
+                    .filter(|&p| p <= Self::MAX as $u)
+                    .unwrap_or_else(|| panic!("Next power of two of {self} overflows"))
+                    as Self
+            }
+        }
+    )+};
+}
+
+bit_ops_impl!(
+    i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize,
+    u8 => u8, u16 => u16, u32 => u32, u64 => u64, u128 => u128, usize => usize
+);
+
+/// Iterator over all submasks of a mask, see [`iterate_submasks`].
+#[derive(Debug, Clone)]
+pub struct Submasks {
+    mask: usize,
+    next: Option<usize>,
+}
+
+impl Iterator for Submasks {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        let cur = self.next?;
+        self.next = (cur != 0).then(|| (cur - 1) & self.mask);
+        Some(cur)
+    }
+}
+
+/// Returns an iterator over all submasks of `mask` (including `mask` itself
+/// and `0`), in decreasing order.
+///
+/// # Example
+///
+/// ```
+/// use algorist::ext::bits::iterate_submasks;
+///
+/// assert_eq!(iterate_submasks(0b1011).count(), 8);
+/// assert_eq!(iterate_submasks(0).collect::<Vec<_>>(), vec![0]);
+/// ```
+pub fn iterate_submasks(mask: usize) -> Submasks {
+    Submasks {
+        mask,
+        next: Some(mask),
+    }
+}
+
+/// Iterator over all `k`-element subsets of `n` bits, see
+/// [`iterate_subsets_of_size`].
+#[derive(Debug, Clone)]
+pub struct SubsetsOfSize {
+    n: u32,
+    next: Option<usize>,
+}
+
+impl Iterator for SubsetsOfSize {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        let cur = self.next?;
+        self.next = if cur == 0 {
+            None
+        } else {
+            // Gosper's hack: the next number with the same number of set bits.
+            let low = cur & cur.wrapping_neg();
+            let ripple = cur + low;
+            let next = (((ripple ^ cur) >> 2) / low) | ripple;
+            (next >> self.n == 0).then_some(next)
+        };
+        Some(cur)
+    }
+}
+
+/// Returns an iterator over all masks of `n` bits with exactly `k` bits set,
+/// in increasing order.
+///
+/// # Panics
+///
+/// Panics if `n` is not less than the number of bits in `usize`.
+///
+/// # Example
+///
+/// ```
+/// use algorist::ext::bits::iterate_subsets_of_size;
+///
+/// assert_eq!(iterate_subsets_of_size(5, 3).count(), 10);
+/// assert_eq!(iterate_subsets_of_size(3, 0).collect::<Vec<_>>(), vec![0]);
+/// assert_eq!(iterate_subsets_of_size(3, 4).count(), 0);
+/// ```
+pub fn iterate_subsets_of_size(n: u32, k: u32) -> SubsetsOfSize {
+    assert!(
+        n < usize::BITS,
+        "Number of bits must be less than {}, got {n}",
+        usize::BITS
+    );
+    SubsetsOfSize {
+        n,
+        next: (k <= n).then(|| (1 << k) - 1),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bit_ops() {
+        for x in 0..=u8::MAX {
+            let bits: Vec<u32> = (0..8).filter(|&i| x >> i & 1 == 1).collect();
+            assert_eq!(x.popcount(), bits.len() as u32);
+            assert_eq!(x.lowest_set_bit(), bits.first().copied());
+            assert_eq!(x.highest_set_bit(), bits.last().copied());
+            assert_eq!(x.is_pow2(), x.is_power_of_two());
+            let y = x as i8;
+            assert_eq!(y.popcount(), x.popcount());
+            assert_eq!(y.highest_set_bit(), x.highest_set_bit());
+            assert_eq!(y.is_pow2(), y > 0 && x.is_power_of_two());
+        }
+        assert!(!i64::MIN.is_pow2());
+        assert_eq!((-5i32).next_pow2(), 1);
+        assert_eq!(0u64.next_pow2(), 1);
+        assert_eq!((1u64 << 40).next_pow2(), 1 << 40);
+        assert_eq!(((1i64 << 40) + 1).next_pow2(), 1 << 41);
+        assert_eq!((u128::MAX >> 1).next_pow2(), 1 << 127);
+    }
+
+    #[test]
+    #[should_panic(expected = "Next power of two of 65 overflows")]
+    fn test_next_pow2_overflow() {
+        65i8.next_pow2();
+    }
+
+    #[test]
+    #[should_panic(expected = "Next power of two of 4294967295 overflows")]
+    fn test_next_pow2_overflow_unsigned() {
+        u32::MAX.next_pow2();
+    }
+
+    #[test]
+    fn test_submasks() {
+        for mask in 0..1 << 8 {
+            let subs: Vec<usize> = iterate_submasks(mask).collect();
+            assert_eq!(subs.len(), 1 << mask.popcount());
+            assert!(subs.windows(2).all(|w| w[0] > w[1]));
+            assert!(subs.iter().all(|&s| s & mask == s));
+        }
+    }
+
+    #[test]
+    fn test_subsets_of_size() {
+        for n in 0..=10 {
+            for k in 0..=n + 1 {
+                let subsets: Vec<usize> = iterate_subsets_of_size(n, k).collect();
+                let want: Vec<usize> = (0..1 << n).filter(|m: &usize| m.popcount() == k).collect();
+                assert_eq!(subsets, want, "n = {n}, k = {k}");
+            }
+        }
+        let top = iterate_subsets_of_size(usize::BITS - 1, 1).last();
+        assert_eq!(top, Some(1 << (usize::BITS - 2)));
+    }
+}
@@ -0,0 +1,142 @@
+//! Classification and conversion of ASCII characters, for both `char` and
+//! `u8`.
+//!
+//! ```
+//! use algorist::ext::char::{CharExt, bytes_to_string};
+//!
+//! // Letter counts, without the `(c as u8 - b'a') as usize` noise.
+//! let mut cnt = [0; 26];
+//! "Hello".chars().for_each(|c| cnt[c.alpha_index()] += 1);
+//! assert_eq!((cnt[7], cnt[11]), (1, 2));
+//!
+//! assert_eq!(b'7'.digit_val(), 7);
+//! assert!('E'.is_vowel());
+//! let s: Vec<u8> = b"aBc".iter().map(|c| c.toggle_case()).collect();
+//! assert_eq!(bytes_to_string(&s), "AbC");
+//! ```
+
+/// Extension trait for ASCII characters, implemented for `char` and `u8`.
+pub trait CharExt: Copy {
+    /// Returns the index of a letter in the alphabet, `0..26`, ignoring case.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the character is not an ASCII letter.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::ext::char::CharExt;
+    ///
+    /// assert_eq!('a'.alpha_index(), 0);
+    /// assert_eq!(b'Z'.alpha_index(), 25);
+    /// ```
+    fn alpha_index(self) -> usize;
+
+    /// Returns the value of a decimal digit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the character is not an ASCII digit.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::ext::char::CharExt;
+    ///
+    /// let sum: usize = "2024".chars().map(|c| c.digit_val()).sum();
+    /// assert_eq!(sum, 8);
+    /// ```
+    fn digit_val(self) -> usize;
+
+    /// Checks if the character is one of `aeiou`, ignoring case.
+    fn is_vowel(self) -> bool;
+
+    /// Turns lowercase ASCII letters into uppercase ones, and vice versa. Other
+    /// characters are returned as is.
+    #[must_use]
+    fn toggle_case(self) -> Self;
+}
+
+macro_rules! char_ext_impl {
+    ($($t: ident)+) => {$(
+        impl CharExt for $t {
+            fn alpha_index(self) -> usize {
+                assert!(self.is_ascii_alphabetic(), "Not a letter: {}", char::from(self));
+                (u32::from(self.to_ascii_lowercase()) - u32::from(b'a')) as usize
+            }
+
+            fn digit_val(self) -> usize {
+                assert!(self.is_ascii_digit(), "Not a digit: {}", char::from(self));
+                (u32::from(self) - u32::from(b'0')) as usize
+            }
+
+            fn is_vowel(self) -> bool {
+                matches!(char::from(self).to_ascii_lowercase(), 'a' | 'e' | 'i' | 'o' | 'u')
+            }
+
+            fn toggle_case(self) -> Self {
+                if self.is_ascii_lowercase() {
+                    self.to_ascii_uppercase()
+                } else {
+                    self.to_ascii_lowercase()
+                }
+            }
+        }
+    )+};
+}
+
+char_ext_impl!(char u8);
+
+/// Converts bytes (e.g. read with
+/// [`Scanner::bytes`](crate::io::Scanner::bytes)) into a `String`.
+///
+/// # Panics
+///
+/// Panics if the bytes are not valid UTF-8.
+///
+/// # Example
+///
+/// ```
+/// use algorist::ext::char::bytes_to_string;
+///
+/// let mut s = b"dlrow".to_vec();
+/// s.reverse();
+/// assert_eq!(bytes_to_string(&s), "world");
+/// ```
+pub fn bytes_to_string(bytes: &[u8]) -> String {
+    String::from_utf8(bytes.to_vec()).expect("Bytes are not valid UTF-8")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_char_ext() {
+        for (i, (lo, up)) in ('a'..='z').zip('A'..='Z').enumerate() {
+            assert_eq!((lo.alpha_index(), up.alpha_index()), (i, i));
+            assert_eq!((lo as u8).alpha_index(), i);
+            assert_eq!((lo.toggle_case(), up.toggle_case()), (up, lo));
+            assert_eq!(lo.is_vowel(), "aeiou".contains(lo));
+            assert_eq!(up.is_vowel(), (up as u8).is_vowel());
+        }
+        for (i, c) in (b'0'..=b'9').enumerate() {
+            assert_eq!((c.digit_val(), char::from(c).digit_val()), (i, i));
+        }
+        assert_eq!(b'-'.toggle_case(), b'-');
+        assert!(!'y'.is_vowel());
+    }
+
+    #[test]
+    #[should_panic(expected = "Not a letter: 1")]
+    fn test_alpha_index_panics() {
+        b'1'.alpha_index();
+    }
+
+    #[test]
+    #[should_panic(expected = "Not a digit: x")]
+    fn test_digit_val_panics() {
+        'x'.digit_val();
+    }
+}
@@ -0,0 +1,250 @@
+/// Extension trait for iterators to enumerate combinations, permutations and
+/// cartesian products of their items.
+///
+/// Combinations and permutations are produced as vectors of cloned items, in
+/// lexicographic order of positions in the source iterator (so for a sorted
+/// source, in lexicographic order of values). Equal items are treated as
+/// distinct.
+///
+/// # Example
+///
+/// ```
+/// use algorist::ext::iter::combinatorics::CombinatoricsExt;
+///
+/// let c: Vec<_> = (1..=4).combinations(2).collect();
+/// assert_eq!(c, vec![[1, 2], [1, 3], [1, 4], [2, 3], [2, 4], [3, 4]]);
+///
+/// let p: Vec<_> = "abc".chars().permutations(2).collect();
+/// assert_eq!(p.len(), 6);
+/// assert_eq!(p[1], ['a', 'c']);
+///
+/// let cells: Vec<_> = (0..2).cartesian_product(0..3).collect();
+/// assert_eq!(cells, vec![(0, 0), (0, 1), (0, 2), (1, 0), (1, 1), (1, 2)]);
+/// ```
+pub trait CombinatoricsExt: Iterator {
+    /// Returns an iterator over all `k`-element combinations of the items.
+    ///
+    /// There is exactly one (empty) combination for `k = 0`, and none for `k`
+    /// exceeding the number of items.
+    fn combinations(self, k: usize) -> Combinations<Self::Item>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        Combinations::new(self.collect(), k)
+    }
+
+    /// Returns an iterator over all `k`-element permutations of the items.
+    ///
+    /// Use `permutations(n)` to get all the full permutations of `n` items.
+    fn permutations(self, k: usize) -> Permutations<Self::Item>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        Permutations::new(self.collect(), k)
+    }
+
+    /// Returns an iterator over all pairs `(a, b)`, where `a` is from this
+    /// iterator, and `b` is from the `other` one (which is restarted, by
+    /// cloning, for each `a`).
+    fn cartesian_product<J>(self, other: J) -> CartesianProduct<Self, J::IntoIter>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        J: IntoIterator,
+        J::IntoIter: Clone,
+    {
+        CartesianProduct::new(self, other.into_iter())
+    }
+}
+
+impl<I: Iterator> CombinatoricsExt for I {}
+
+/// Iterator over combinations, see [`CombinatoricsExt::combinations`].
+pub struct Combinations<T> {
+    pool: Vec<T>,
+    /// Increasing positions of the next combination, `None` when exhausted.
+    idx: Option<Vec<usize>>,
+}
+
+impl<T: Clone> Combinations<T> {
+    pub fn new(pool: Vec<T>, k: usize) -> Self {
+        let idx = (k <= pool.len()).then(|| (0..k).collect());
+        Self { pool, idx }
+    }
+}
+
+impl<T: Clone> Iterator for Combinations<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let idx = self.idx.as_mut()?;
+        let res = idx.iter().map(|&i| self.pool[i].clone()).collect();
+
+        // Advance the rightmost position, which is not at its maximum.
+        let (n, k) = (self.pool.len(), idx.len());
+        match (0..k).rev().find(|&i| idx[i] < n - k + i) {
+            Some(i) => {
+                idx[i] += 1;
+                for j in i + 1..k {
+                    idx[j] = idx[j - 1] + 1;
+                }
+            }
+            None => self.idx = None,
+        }
+        Some(res)
+    }
+}
+
+/// Iterator over permutations, see [`CombinatoricsExt::permutations`].
+pub struct Permutations<T> {
+    pool: Vec<T>,
+    k: usize,
+    /// Permutation of all positions, the first `k` of which are the next
+    /// permutation.
+    idx: Vec<usize>,
+    /// `cycles[i]` is the number of remaining choices for the position `i`.
+    cycles: Vec<usize>,
+    done: bool,
+}
+
+impl<T: Clone> Permutations<T> {
+    pub fn new(pool: Vec<T>, k: usize) -> Self {
+        let n = pool.len();
+        Self {
+            k,
+            idx: (0..n).collect(),
+            cycles: (n.saturating_sub(k) + 1..=n).rev().collect(),
+            done: k > n,
+            pool,
+        }
+    }
+}
+
+impl<T: Clone> Iterator for Permutations<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let res = self.idx[..self.k]
+            .iter()
+            .map(|&i| self.pool[i].clone())
+            .collect();
+
+        // Same as in Python's `itertools.permutations`.
+        let n = self.pool.len();
+        self.done = true;
+        for i in (0..self.k).rev() {
+            self.cycles[i] -= 1;
+            if self.cycles[i] == 0 {
+                self.idx[i..].rotate_left(1);
+                self.cycles[i] = n - i;
+            } else {
+                let j = n - self.cycles[i];
+                self.idx.swap(i, j);
+                self.done = false;
+                break;
+            }
+        }
+        Some(res)
+    }
+}
+
+/// Iterator over pairs of items, see [`CombinatoricsExt::cartesian_product`].
+pub struct CartesianProduct<I: Iterator, J> {
+    a: I,
+    cur: Option<I::Item>,
+    b_orig: J,
+    b: J,
+}
+
+impl<I: Iterator, J: Clone> CartesianProduct<I, J> {
+    pub fn new(a: I, b: J) -> Self {
+        Self {
+            a,
+            cur: None,
+            b_orig: b.clone(),
+            b,
+        }
+    }
+}
+
+impl<I, J> Iterator for CartesianProduct<I, J>
+where
+    I: Iterator,
+    I::Item: Clone,
+    J: Iterator + Clone,
+{
+    type Item = (I::Item, J::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.cur.is_none() {
+                self.cur = Some(self.a.next()?);
+                self.b = self.b_orig.clone();
+            }
+            match self.b.next() {
+                Some(y) => return Some((self.cur.clone().unwrap(), y)),
+                None => self.cur = None,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, std::collections::HashSet};
+
+    fn binomial(n: usize, k: usize) -> usize {
+        (0..k).fold(1, |acc, i| acc * (n - i) / (i + 1))
+    }
+
+    #[test]
+    fn test_combinations() {
+        for n in 0..7 {
+            for k in 0..=n + 1 {
+                let c: Vec<Vec<usize>> = (0..n).combinations(k).collect();
+                assert_eq!(c.len(), if k > n { 0 } else { binomial(n, k) });
+                assert!(
+                    c.iter()
+                        .all(|v| v.len() == k && v.windows(2).all(|w| w[0] < w[1]))
+                );
+                assert!(c.windows(2).all(|w| w[0] < w[1]));
+            }
+        }
+        let c: Vec<_> = [1, 1, 2].into_iter().combinations(2).collect();
+        assert_eq!(c, vec![[1, 1], [1, 2], [1, 2]]);
+    }
+
+    #[test]
+    fn test_permutations() {
+        for n in 0..6 {
+            for k in 0..=n + 1 {
+                let p: Vec<Vec<usize>> = (0..n).permutations(k).collect();
+                let want = if k > n { 0 } else { (n - k + 1..=n).product() };
+                assert_eq!(p.len(), want);
+                assert!(p.windows(2).all(|w| w[0] < w[1]));
+                let distinct: HashSet<_> = p.iter().collect();
+                assert_eq!(distinct.len(), p.len());
+                assert!(
+                    p.iter()
+                        .all(|v| { v.len() == k && v.iter().collect::<HashSet<_>>().len() == k })
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_cartesian_product() {
+        assert_eq!((0..3).cartesian_product(0..0).count(), 0);
+        assert_eq!((0..0).cartesian_product(0..3).count(), 0);
+        let v = ['a', 'b'];
+        let p: Vec<_> = v.iter().cartesian_product([1, 2]).collect();
+        assert_eq!(p, vec![(&'a', 1), (&'a', 2), (&'b', 1), (&'b', 2)]);
+        let triples = (0..2).cartesian_product(0..2).cartesian_product(0..2);
+        assert_eq!(triples.count(), 8);
+    }
+}
@@ -0,0 +1,155 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// Extension trait for iterators to count and group items.
+///
+/// # Example
+///
+/// ```
+/// use algorist::ext::iter::counts::CountsExt;
+///
+/// let cnt = "hello".chars().counts();
+/// assert_eq!(cnt[&'l'], 2);
+/// assert_eq!(cnt.get(&'z'), None);
+///
+/// let cnt = vec![3, 1, 3, 2, 3].into_iter().counts_sorted();
+/// assert_eq!(cnt, vec![(1, 1), (2, 1), (3, 3)]);
+///
+/// let groups: Vec<_> = [1, 3, 2, 4, 5]
+///     .into_iter()
+///     .group_consecutive_by(|x| x % 2)
+///     .collect();
+/// assert_eq!(groups, vec![(1, vec![1, 3]), (0, vec![2, 4]), (1, vec![5])]);
+/// ```
+pub trait CountsExt: Iterator {
+    /// Returns the number of occurrences of each item.
+    fn counts(self) -> HashMap<Self::Item, usize>
+    where
+        Self: Sized,
+        Self::Item: Eq + Hash,
+    {
+        let mut res = HashMap::new();
+        for x in self {
+            *res.entry(x).or_default() += 1;
+        }
+        res
+    }
+
+    /// Returns distinct items with the numbers of their occurrences, sorted by
+    /// items.
+    fn counts_sorted(self) -> Vec<(Self::Item, usize)>
+    where
+        Self: Sized,
+        Self::Item: Ord,
+    {
+        let mut items: Vec<_> = self.collect();
+        items.sort_unstable();
+        let mut res: Vec<(Self::Item, usize)> = Vec::new();
+        for x in items {
+            match res.last_mut() {
+                Some((last, cnt)) if *last == x => *cnt += 1,
+                _ => res.push((x, 1)),
+            }
+        }
+        res
+    }
+
+    /// Returns an iterator over maximal runs of consecutive items with equal
+    /// keys, as `(key, items)` pairs.
+    fn group_consecutive_by<K, F>(self, key: F) -> GroupConsecutiveBy<Self, K, F>
+    where
+        Self: Sized,
+        K: PartialEq,
+        F: FnMut(&Self::Item) -> K,
+    {
+        GroupConsecutiveBy::new(self, key)
+    }
+}
+
+impl<I: Iterator> CountsExt for I {}
+
+/// Iterator over groups of consecutive items with equal keys, see
+/// [`CountsExt::group_consecutive_by`].
+pub struct GroupConsecutiveBy<I: Iterator, K, F> {
+    iter: I,
+    key: F,
+    /// First item of the next group, with its key.
+    next: Option<(K, I::Item)>,
+}
+
+impl<I, K, F> GroupConsecutiveBy<I, K, F>
+where
+    I: Iterator,
+    F: FnMut(&I::Item) -> K,
+{
+    pub fn new(mut iter: I, mut key: F) -> Self {
+        let next = iter.next().map(|x| (key(&x), x));
+        Self { iter, key, next }
+    }
+}
+
+impl<I, K, F> Iterator for GroupConsecutiveBy<I, K, F>
+where
+    I: Iterator,
+    K: PartialEq,
+    F: FnMut(&I::Item) -> K,
+{
+    type Item = (K, Vec<I::Item>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (k, first) = self.next.take()?;
+        let mut group = vec![first];
+        for x in self.iter.by_ref() {
+            let kx = (self.key)(&x);
+            if kx != k {
+                self.next = Some((kx, x));
+                break;
+            }
+            group.push(x);
+        }
+        Some((k, group))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::misc::rng::Rng};
+
+    #[test]
+    fn test_counts() {
+        let mut rng = Rng::new(15);
+        let v: Vec<u32> = (0..200).map(|_| rng.gen_range(0..10)).collect();
+        let cnt = v.iter().counts();
+        let sorted = v.iter().counts_sorted();
+        assert_eq!(cnt.len(), sorted.len());
+        assert!(sorted.windows(2).all(|w| w[0].0 < w[1].0));
+        for (x, c) in sorted {
+            assert_eq!(cnt[x], c);
+            assert_eq!(v.iter().filter(|&y| y == x).count(), c);
+        }
+        assert!(std::iter::empty::<u8>().counts_sorted().is_empty());
+    }
+
+    #[test]
+    fn test_group_consecutive_by() {
+        assert_eq!((0..0).group_consecutive_by(|&x| x).count(), 0);
+        let words = [
+            "apple",
+            "avocado",
+            "banana",
+            "blueberry",
+            "cherry",
+            "apricot",
+        ];
+        let groups: Vec<_> = words
+            .iter()
+            .group_consecutive_by(|w| w.as_bytes()[0])
+            .map(|(k, g)| (k as char, g.len()))
+            .collect();
+        assert_eq!(groups, vec![('a', 2), ('b', 2), ('c', 1), ('a', 1)]);
+        let total: usize = (0..100)
+            .group_consecutive_by(|x| x / 7)
+            .map(|(_, g)| g.len())
+            .sum();
+        assert_eq!(total, 100);
+    }
+}
@@ -0,0 +1,170 @@
+//! Positions of minimums and maximums, and the `k` smallest items.
+//!
+//! ```
+//! use algorist::ext::iter::extremes::ExtremesExt;
+//!
+//! let a = [3, 1, 4, 1, 5, 9, 2, 6];
+//! assert_eq!(a.iter().position_min(), Some(1));
+//! assert_eq!(a.iter().position_max(), Some(5));
+//! assert_eq!(a.iter().min_max(), Some((&1, &9)));
+//! assert_eq!(a.into_iter().k_smallest(3), vec![(1, 1), (3, 1), (6, 2)]);
+//! ```
+
+use std::collections::BinaryHeap;
+
+/// Extension trait for iterators to find extreme items and their indexes.
+///
+/// On ties, the item with the smallest index wins.
+pub trait ExtremesExt: Iterator {
+    /// Returns the index of the first minimum item, or `None` if the iterator
+    /// is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::ext::iter::extremes::ExtremesExt;
+    ///
+    /// assert_eq!([2, 1, 1].iter().position_min(), Some(1));
+    /// assert_eq!(std::iter::empty::<i32>().position_min(), None);
+    /// ```
+    fn position_min(self) -> Option<usize>
+    where
+        Self: Sized,
+        Self::Item: Ord,
+    {
+        self.enumerate()
+            .min_by(|(i, x), (j, y)| x.cmp(y).then(i.cmp(j)))
+            .map(|(i, _)| i)
+    }
+
+    /// Returns the index of the first maximum item, or `None` if the iterator
+    /// is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::ext::iter::extremes::ExtremesExt;
+    ///
+    /// assert_eq!([1, 3, 3].iter().position_max(), Some(1));
+    /// assert_eq!("algorist".chars().position_max(), Some(7));
+    /// ```
+    fn position_max(self) -> Option<usize>
+    where
+        Self: Sized,
+        Self::Item: Ord,
+    {
+        self.enumerate()
+            .max_by(|(i, x), (j, y)| x.cmp(y).then(j.cmp(i)))
+            .map(|(i, _)| i)
+    }
+
+    /// Returns the minimum and the maximum items, found in a single pass, or
+    /// `None` if the iterator is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::ext::iter::extremes::ExtremesExt;
+    ///
+    /// assert_eq!([5, -2, 7, 0].into_iter().min_max(), Some((-2, 7)));
+    /// assert_eq!([4].into_iter().min_max(), Some((4, 4)));
+    /// assert_eq!(std::iter::empty::<u8>().min_max(), None);
+    /// ```
+    fn min_max(mut self) -> Option<(Self::Item, Self::Item)>
+    where
+        Self: Sized,
+        Self::Item: Ord + Clone,
+    {
+        let first = self.next()?;
+        Some(self.fold((first.clone(), first), |(lo, hi), x| {
+            if x < lo {
+                (x, hi)
+            } else if x > hi {
+                (lo, x)
+            } else {
+                (lo, hi)
+            }
+        }))
+    }
+
+    /// Returns the `k` smallest items with their indexes, as `(index, item)`
+    /// pairs, sorted by items (and by indexes, for equal items). If there are
+    /// fewer than `k` items, all of them are returned.
+    ///
+    /// Runs in `O(n log k)` time and `O(k)` memory, so that the iterator is
+    /// not collected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::ext::iter::extremes::ExtremesExt;
+    ///
+    /// let a = vec![5, 1, 4, 1, 3];
+    /// assert_eq!(a.iter().k_smallest(2), vec![(1, &1), (3, &1)]);
+    /// assert_eq!(a.iter().k_smallest(0), vec![]);
+    /// assert_eq!(a.iter().k_smallest(9).len(), 5);
+    ///
+    /// // The `k` largest items, with `Reverse`:
+    /// use std::cmp::Reverse;
+    /// let top: Vec<_> = a.iter().map(Reverse).k_smallest(2);
+    /// assert_eq!(top, vec![(0, Reverse(&5)), (2, Reverse(&4))]);
+    /// ```
+    fn k_smallest(self, k: usize) -> Vec<(usize, Self::Item)>
+    where
+        Self: Sized,
+        Self::Item: Ord,
+    {
+        if k == 0 {
+            return Vec::new();
+        }
+        // Max-heap of the `k` smallest `(item, index)` pairs seen so far.
+        let mut heap = BinaryHeap::with_capacity(k + 1);
+        for (i, x) in self.enumerate() {
+            if heap.len() == k {
+                let (top, _): &(Self::Item, usize) = heap.peek().unwrap();
+                if x >= *top {
+                    continue;
+                }
+                heap.pop();
+            }
+            heap.push((x, i));
+        }
+        heap.into_sorted_vec()
+            .into_iter()
+            .map(|(x, i)| (i, x))
+            .collect()
+    }
+}
+
+impl<I: Iterator> ExtremesExt for I {}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::misc::rng::Rng};
+
+    #[test]
+    fn test_against_sort() {
+        let mut rng = Rng::new(17);
+        for _ in 0..200 {
+            let n = rng.gen_range(0..30);
+            let a: Vec<u8> = (0..n).map(|_| rng.gen_range(0..5)).collect();
+            let mut sorted: Vec<(u8, usize)> = a.iter().copied().zip(0..).collect();
+            sorted.sort();
+
+            assert_eq!(a.iter().position_min(), sorted.first().map(|p| p.1));
+            let max = a.iter().max();
+            assert_eq!(
+                a.iter().position_max(),
+                a.iter().position(|x| Some(x) == max)
+            );
+            assert_eq!(
+                a.iter().min_max(),
+                max.map(|hi| (a.iter().min().unwrap(), hi))
+            );
+
+            let k = rng.gen_range(0..n + 2);
+            let want: Vec<(usize, u8)> = sorted.iter().take(k).map(|&(x, i)| (i, x)).collect();
+            assert_eq!(a.iter().copied().k_smallest(k), want);
+        }
+    }
+}
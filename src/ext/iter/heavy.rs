@@ -0,0 +1,143 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// Finds all elements occurring more than `n / k` times, where `n` is the
+/// number of items in the iterator.
+///
+/// Uses the Misra–Gries algorithm, which keeps at most `k - 1` candidates in
+/// memory, so it works for huge streams, followed by a verification pass that
+/// drops false positives. Since the iterator is traversed twice, it must be
+/// `Clone` (iterators over slices, ranges, etc. are).
+///
+/// Elements are returned in the order of their first occurrence.
+///
+/// # Panics
+///
+/// Panics if `k < 2`.
+///
+/// # Example
+///
+/// ```
+/// use algorist::ext::iter::heavy::heavy_hitters;
+///
+/// let v = vec![1, 2, 3, 1, 1, 2, 1, 2];
+///
+/// // Elements occurring more than `8 / 2 = 4` times (majority element).
+/// assert!(heavy_hitters(v.iter(), 2).is_empty());
+///
+/// // Elements occurring more than `8 / 3` times.
+/// assert_eq!(heavy_hitters(v.iter(), 3), vec![&1, &2]);
+///
+/// let s = "abracadabra";
+/// assert_eq!(heavy_hitters(s.chars(), 3), vec!['a']);
+/// ```
+pub fn heavy_hitters<I>(iter: I, k: usize) -> Vec<I::Item>
+where
+    I: Iterator + Clone,
+    I::Item: Hash + Eq + Clone,
+{
+    assert!(k >= 2, "k must be at least 2");
+
+    let mut candidates: HashMap<I::Item, usize> = HashMap::with_capacity(k);
+    for x in iter.clone() {
+        if let Some(cnt) = candidates.get_mut(&x) {
+            *cnt += 1;
+        } else if candidates.len() < k - 1 {
+            candidates.insert(x, 1);
+        } else {
+            candidates.retain(|_, cnt| {
+                *cnt -= 1;
+                *cnt > 0
+            });
+        }
+    }
+
+    let mut n = 0;
+    let mut order = Vec::with_capacity(candidates.len());
+    for cnt in candidates.values_mut() {
+        *cnt = 0;
+    }
+    for x in iter {
+        n += 1;
+        if let Some(cnt) = candidates.get_mut(&x) {
+            if *cnt == 0 {
+                order.push(x);
+            }
+            *cnt += 1;
+        }
+    }
+    order.retain(|x| candidates[x] > n / k);
+    order
+}
+
+/// Extension trait for iterators to provide [`heavy_hitters`] as a method.
+///
+/// # Example
+///
+/// ```
+/// use algorist::ext::iter::heavy::HeavyHittersExt;
+///
+/// let v = vec![3, 3, 4, 2, 4, 4, 2, 4, 4];
+/// assert_eq!(v.iter().heavy_hitters(2), vec![&4]);
+/// ```
+pub trait HeavyHittersExt: Iterator + Clone {
+    fn heavy_hitters(self, k: usize) -> Vec<Self::Item>
+    where
+        Self::Item: Hash + Eq + Clone,
+    {
+        heavy_hitters(self, k)
+    }
+}
+
+impl<I: Iterator + Clone> HeavyHittersExt for I {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn naive(v: &[u32], k: usize) -> Vec<u32> {
+        let mut res = vec![];
+        for &x in v {
+            if !res.contains(&x) && v.iter().filter(|&&y| y == x).count() > v.len() / k {
+                res.push(x);
+            }
+        }
+        res
+    }
+
+    #[test]
+    fn test_heavy_hitters() {
+        let v: Vec<u32> = vec![];
+        assert!(heavy_hitters(v.iter(), 2).is_empty());
+
+        let v = [7];
+        assert_eq!(heavy_hitters(v.iter(), 2), vec![&7]);
+
+        let v = [1, 1, 2, 2];
+        assert!(heavy_hitters(v.iter(), 2).is_empty());
+        assert_eq!(heavy_hitters(v.iter(), 3), vec![&1, &2]);
+
+        let mut x = 7u32;
+        for k in 2..6 {
+            for len in [1, 10, 100, 1000] {
+                let v: Vec<u32> = (0..len)
+                    .map(|_| {
+                        x = x.wrapping_mul(1_103_515_245).wrapping_add(12345);
+                        let r = (x >> 16) % 10;
+                        if r < 4 { 0 } else { r % 4 }
+                    })
+                    .collect();
+                let got: Vec<u32> = heavy_hitters(v.iter().copied(), k);
+                assert_eq!(got, naive(&v, k), "k = {k}, len = {len}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_heavy_hitters_ext() {
+        assert_eq!((0..10).chain(0..5).heavy_hitters(3), Vec::<i32>::new());
+        assert_eq!(
+            [1, 1, 1, 2, 3].iter().chain(&[1, 4]).heavy_hitters(2),
+            vec![&1]
+        );
+    }
+}
@@ -3,5 +3,13 @@
 #![doc = include_str!("./README.md")]
 
 pub mod chunks;
+pub mod combinatorics;
+pub mod counts;
+pub mod extremes;
 pub mod fold_while;
+pub mod heavy;
+pub mod run_length;
+pub mod scan_ext;
+pub mod split;
+pub mod widening;
 pub mod window;
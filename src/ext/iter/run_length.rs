@@ -0,0 +1,107 @@
+/// Extension trait for objects (iterators, vectors, slices) to get their
+/// run-length encoding.
+///
+/// This trait provides the [`run_length`](Self::run_length) method, which
+/// returns an iterator over `(item, count)` pairs, one for each maximal run of
+/// equal consecutive items. For strings, use `s.chars()` or `s.bytes()`.
+pub trait RunLengthExt: IntoIterator
+where
+    Self::Item: PartialEq,
+{
+    /// Returns an iterator over maximal runs of equal consecutive items, as
+    /// `(item, count)` pairs (where the item is the first one of the run).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::ext::iter::run_length::RunLengthExt;
+    ///
+    /// let runs: Vec<_> = "aaabccaa".chars().run_length().collect();
+    /// assert_eq!(runs, vec![('a', 3), ('b', 1), ('c', 2), ('a', 2)]);
+    ///
+    /// // You can also use it with slices, vectors and arbitrary iterators:
+    /// let v = vec![1, 1, 2, 2, 2];
+    /// let runs: Vec<_> = v[1..].run_length().collect();
+    /// assert_eq!(runs, vec![(&1, 1), (&2, 3)]);
+    /// let runs: Vec<_> = v.run_length().collect();
+    /// assert_eq!(runs, vec![(1, 2), (2, 3)]);
+    ///
+    /// let longest = (0..10).map(|x| x / 4).run_length().map(|(_, c)| c).max();
+    /// assert_eq!(longest, Some(4));
+    /// ```
+    fn run_length(self) -> RunLength<Self::IntoIter>
+    where
+        Self: Sized,
+    {
+        RunLength::new(self.into_iter())
+    }
+}
+
+impl<I: IntoIterator> RunLengthExt for I where I::Item: PartialEq {}
+
+/// Iterator over `(item, count)` runs, see [`RunLengthExt::run_length`].
+pub struct RunLength<I: Iterator> {
+    iter: I,
+    /// First item of the next run.
+    next: Option<I::Item>,
+}
+
+impl<I: Iterator> RunLength<I> {
+    pub fn new(mut iter: I) -> Self {
+        let next = iter.next();
+        Self { iter, next }
+    }
+}
+
+impl<I: Iterator> Iterator for RunLength<I>
+where
+    I::Item: PartialEq,
+{
+    type Item = (I::Item, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.next.take()?;
+        let mut count = 1;
+        for x in self.iter.by_ref() {
+            if x != item {
+                self.next = Some(x);
+                break;
+            }
+            count += 1;
+        }
+        Some((item, count))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::misc::rng::Rng};
+
+    #[test]
+    fn test_run_length() {
+        let v: Vec<i32> = vec![];
+        assert_eq!(v.run_length().next(), None);
+        assert_eq!([7].run_length().collect::<Vec<_>>(), vec![(7, 1)]);
+        let runs: Vec<_> = b"aabbbc".run_length().collect();
+        assert_eq!(runs, vec![(&b'a', 2), (&b'b', 3), (&b'c', 1)]);
+        let runs: Vec<_> = [1.0, 1.0, f64::NAN, f64::NAN].run_length().collect();
+        // NaN is not equal to itself, so each NaN is a separate run.
+        assert_eq!(runs.len(), 3);
+    }
+
+    #[test]
+    fn test_decode() {
+        let mut rng = Rng::new(13);
+        for _ in 0..100 {
+            let n = rng.gen_range(0..30);
+            let v: Vec<u8> = (0..n).map(|_| rng.gen_range(0..3)).collect();
+            let runs: Vec<(u8, usize)> = v.iter().copied().run_length().collect();
+            assert!(runs.windows(2).all(|w| w[0].0 != w[1].0));
+            let decoded: Vec<u8> = runs
+                .iter()
+                .flat_map(|&(x, c)| std::iter::repeat(x).take(c))
+                .collect();
+            assert_eq!(decoded, v);
+        }
+    }
+}
@@ -0,0 +1,130 @@
+/// Extension trait for iterators to get all running accumulations.
+///
+/// Unlike [`Iterator::scan`], there is no state to thread through, and the
+/// accumulated value itself is yielded after each element: this is a
+/// [`Iterator::fold`], which reports all of its intermediate results.
+///
+/// # Example
+///
+/// ```
+/// use algorist::{ext::iter::scan_ext::PrefixScanExt, math::gcd::gcd};
+///
+/// let a = [5, 3, 8, 1, 4];
+/// let pre_min: Vec<_> = a.iter().prefix_scan(i32::MAX, |m, &x| m.min(x)).collect();
+/// assert_eq!(pre_min, vec![5, 3, 3, 1, 1]);
+///
+/// let pre_gcd: Vec<_> = [12, 18, 8].into_iter().prefix_scan(0, gcd).collect();
+/// assert_eq!(pre_gcd, vec![12, 6, 2]);
+/// ```
+pub trait PrefixScanExt: Iterator {
+    /// Returns an iterator over `f(init, x1)`, `f(f(init, x1), x2)`, and so
+    /// on: one value per element.
+    fn prefix_scan<B, F>(self, init: B, f: F) -> PrefixScan<Self, B, F>
+    where
+        Self: Sized,
+        B: Clone,
+        F: FnMut(B, Self::Item) -> B,
+    {
+        PrefixScan {
+            iter: self,
+            acc: Some(init),
+            f,
+        }
+    }
+}
+
+impl<I: Iterator> PrefixScanExt for I {}
+
+/// Iterator over running accumulations, see [`PrefixScanExt::prefix_scan`].
+pub struct PrefixScan<I, B, F> {
+    iter: I,
+    acc: Option<B>,
+    f: F,
+}
+
+impl<I, B, F> Iterator for PrefixScan<I, B, F>
+where
+    I: Iterator,
+    B: Clone,
+    F: FnMut(B, I::Item) -> B,
+{
+    type Item = B;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let x = self.iter.next()?;
+        let acc = (self.f)(self.acc.take()?, x);
+        self.acc = Some(acc.clone());
+        Some(acc)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// Extension trait for slices to get running accumulations from the right.
+pub trait SuffixScan<T> {
+    /// Returns a vector, where element `i` is the accumulation of `self[i..]`,
+    /// folded from the right: `f(...f(f(init, a[n - 1]), a[n - 2])..., a[i])`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::ext::iter::scan_ext::SuffixScan;
+    ///
+    /// let a = [5, 3, 8, 1, 4];
+    /// assert_eq!(a.suffix_scan(0, |m, &x| m.max(x)), vec![8, 8, 8, 4, 4]);
+    /// assert_eq!(a.suffix_scan(0, |s, &x| s + x), vec![21, 16, 13, 5, 4]);
+    /// ```
+    fn suffix_scan<B, F>(&self, init: B, f: F) -> Vec<B>
+    where
+        B: Clone,
+        F: FnMut(B, &T) -> B;
+}
+
+impl<T> SuffixScan<T> for [T] {
+    fn suffix_scan<B, F>(&self, init: B, f: F) -> Vec<B>
+    where
+        B: Clone,
+        F: FnMut(B, &T) -> B,
+    {
+        let mut res: Vec<B> = self.iter().rev().prefix_scan(init, f).collect();
+        res.reverse();
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prefix_scan() {
+        let empty: [i32; 0] = [];
+        assert_eq!(empty.iter().prefix_scan(0, |s, &x| s + x).count(), 0);
+
+        let sums: Vec<i64> = (1..=5).prefix_scan(0, |s, x| s + x).collect();
+        assert_eq!(sums, vec![1, 3, 6, 10, 15]);
+
+        let words: Vec<String> = "abc"
+            .chars()
+            .prefix_scan(String::new(), |mut s, c| {
+                s.push(c);
+                s
+            })
+            .collect();
+        assert_eq!(words, vec!["a", "ab", "abc"]);
+    }
+
+    #[test]
+    fn test_suffix_scan() {
+        let empty: [i32; 0] = [];
+        assert!(empty.suffix_scan(0, |s, &x| s + x).is_empty());
+
+        let v = [3, 1, 2];
+        assert_eq!(v.suffix_scan(i32::MAX, |m, &x| m.min(x)), vec![1, 1, 2]);
+        for i in 0..v.len() {
+            assert_eq!(v.suffix_scan(0, |s, &x| s + x)[i], v[i..].iter().sum());
+        }
+    }
+}
@@ -0,0 +1,177 @@
+//! Iterator adapters, splitting an iterator by a predicate.
+//!
+//! ```
+//! use algorist::ext::iter::split::SplitExt;
+//!
+//! // Read the numbers up to, and including, the terminating zero.
+//! let v: Vec<_> = [3, 1, 0, 5]
+//!     .into_iter()
+//!     .take_while_inclusive(|&x| x != 0)
+//!     .collect();
+//! assert_eq!(v, vec![3, 1, 0]);
+//!
+//! // Segments between zeros.
+//! let segments: Vec<_> = [1, 2, 0, 3, 0, 0, 4]
+//!     .into_iter()
+//!     .split_by(|&x| x == 0)
+//!     .collect();
+//! assert_eq!(segments, vec![vec![1, 2], vec![3], vec![], vec![4]]);
+//! ```
+
+/// Iterator yielding items while the predicate holds, and then the first item,
+/// for which it doesn't.
+///
+/// Created by the [`SplitExt::take_while_inclusive`] method.
+pub struct TakeWhileInclusive<I, P> {
+    iter: I,
+    pred: P,
+    done: bool,
+}
+
+impl<I, P> Iterator for TakeWhileInclusive<I, P>
+where
+    I: Iterator,
+    P: FnMut(&I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let item = self.iter.next()?;
+        self.done = !(self.pred)(&item);
+        Some(item)
+    }
+}
+
+/// Iterator over segments of items, separated by items, matching the
+/// predicate.
+///
+/// Created by the [`SplitExt::split_by`] method.
+pub struct SplitBy<I, P> {
+    iter: I,
+    pred: P,
+    done: bool,
+}
+
+impl<I, P> Iterator for SplitBy<I, P>
+where
+    I: Iterator,
+    P: FnMut(&I::Item) -> bool,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let mut segment = Vec::new();
+        for item in self.iter.by_ref() {
+            if (self.pred)(&item) {
+                return Some(segment);
+            }
+            segment.push(item);
+        }
+        self.done = true;
+        Some(segment)
+    }
+}
+
+/// Extension trait for iterators to split them by a predicate.
+pub trait SplitExt: Iterator {
+    /// Returns an iterator, yielding items while `pred` holds, and then the
+    /// first item, for which it doesn't (unlike [`Iterator::take_while`],
+    /// which drops it).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::ext::iter::split::SplitExt;
+    ///
+    /// let mut it = 1..;
+    /// let v: Vec<_> = it.by_ref().take_while_inclusive(|x| x * x < 10).collect();
+    /// assert_eq!(v, vec![1, 2, 3, 4]);
+    /// assert_eq!(it.next(), Some(5));
+    /// ```
+    fn take_while_inclusive<P>(self, pred: P) -> TakeWhileInclusive<Self, P>
+    where
+        Self: Sized,
+        P: FnMut(&Self::Item) -> bool,
+    {
+        TakeWhileInclusive {
+            iter: self,
+            pred,
+            done: false,
+        }
+    }
+
+    /// Returns an iterator over vectors of items between separators, i.e.
+    /// items, for which `pred` holds. Separators are dropped.
+    ///
+    /// As with [`slice::split`], adjacent separators (and separators at the
+    /// ends) produce empty segments, and `k` separators always produce `k + 1`
+    /// segments.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::ext::iter::split::SplitExt;
+    ///
+    /// let words: Vec<String> = "  to be "
+    ///     .chars()
+    ///     .split_by(|c| c.is_whitespace())
+    ///     .map(|w| w.into_iter().collect())
+    ///     .collect();
+    /// assert_eq!(words, vec!["", "", "to", "be", ""]);
+    ///
+    /// let segments: Vec<_> = std::iter::empty::<u8>().split_by(|_| true).collect();
+    /// assert_eq!(segments, vec![vec![]]);
+    /// ```
+    fn split_by<P>(self, pred: P) -> SplitBy<Self, P>
+    where
+        Self: Sized,
+        P: FnMut(&Self::Item) -> bool,
+    {
+        SplitBy {
+            iter: self,
+            pred,
+            done: false,
+        }
+    }
+}
+
+impl<I: Iterator> SplitExt for I {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_take_while_inclusive() {
+        let v: Vec<_> = (1..=5).take_while_inclusive(|&x| x < 10).collect();
+        assert_eq!(v, vec![1, 2, 3, 4, 5]);
+        let v: Vec<_> = (1..=5).take_while_inclusive(|_| false).collect();
+        assert_eq!(v, vec![1]);
+
+        // The predicate is not called after the first failure.
+        let mut calls = 0;
+        let v: Vec<_> = (1..=5)
+            .take_while_inclusive(|&x| {
+                calls += 1;
+                x < 2
+            })
+            .collect();
+        assert_eq!((v, calls), (vec![1, 2], 2));
+    }
+
+    #[test]
+    fn test_split_by_matches_slice_split() {
+        let cases: [&[u8]; 6] = [b"", b"0", b"a", b"0a00b", b"ab0", b"a0b0c"];
+        for s in cases {
+            let want: Vec<Vec<u8>> = s.split(|&c| c == b'0').map(<[u8]>::to_vec).collect();
+            let got: Vec<_> = s.iter().copied().split_by(|&c| c == b'0').collect();
+            assert_eq!(got, want, "{s:?}");
+        }
+    }
+}
@@ -0,0 +1,164 @@
+//! Sums and products of integer iterators in a wider type.
+//!
+//! Summing `i32` inputs with `.sum::<i32>()` silently overflows in release
+//! builds, once the answer goes over `2^31`. [`WideningSum`] widens every item
+//! before adding it up:
+//!
+//! ```
+//! use algorist::{ext::iter::widening::WideningSum, math::modulo::Val7};
+//!
+//! let a = vec![i32::MAX, i32::MAX, 2];
+//! assert_eq!(a.iter().sum_i64(), 4_294_967_296);
+//!
+//! let b = [u64::MAX, u64::MAX];
+//! assert_eq!(b.into_iter().sum_i128(), 2 * u64::MAX as i128);
+//!
+//! let fact = (1..=20usize).product_mod::<Val7>();
+//! assert_eq!(fact.val(), 146_326_063);
+//! ```
+
+use crate::math::{ConstValue, modulo::Modulo};
+
+/// Primitive integer, which can be widened to `i64` and `i128`.
+pub trait Widen: Copy {
+    /// Converts the value to `i64`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value (of `u64` or `usize`) is greater than `i64::MAX`,
+    /// use [`to_i128`](Self::to_i128) for such values.
+    fn to_i64(self) -> i64;
+
+    /// Converts the value to `i128`, without loss.
+    fn to_i128(self) -> i128;
+}
+
+macro_rules! widen_impl {
+    ($($t: ident)+) => {$(
+        impl Widen for $t {
+            fn to_i64(self) -> i64 {
+                i64::try_from(self).unwrap_or_else(|_| panic!("Value {self} doesn't fit into i64"))
+            }
+
+            fn to_i128(self) -> i128 {
+                self as i128
+            }
+        }
+    )+};
+}
+
+widen_impl!(i8 i16 i32 i64 isize u8 u16 u32 u64 usize);
+
+impl<T: Widen> Widen for &T {
+    fn to_i64(self) -> i64 {
+        (*self).to_i64()
+    }
+
+    fn to_i128(self) -> i128 {
+        (*self).to_i128()
+    }
+}
+
+/// Extension trait for iterators over integers (or references to them), to
+/// sum and multiply them in a type wider than the items.
+pub trait WideningSum: Iterator
+where
+    Self::Item: Widen,
+{
+    /// Returns the sum of items, computed in `i64`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::ext::iter::widening::WideningSum;
+    ///
+    /// let v = vec![2_000_000_000; 5];
+    /// assert_eq!(v.iter().sum_i64(), 10_000_000_000);
+    /// assert_eq!(std::iter::empty::<u8>().sum_i64(), 0);
+    /// ```
+    fn sum_i64(self) -> i64
+    where
+        Self: Sized,
+    {
+        self.map(Widen::to_i64).sum()
+    }
+
+    /// Returns the sum of items, computed in `i128`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::ext::iter::widening::WideningSum;
+    ///
+    /// let v = vec![i64::MAX; 4];
+    /// assert_eq!(v.iter().sum_i128(), 4 * i64::MAX as i128);
+    /// ```
+    fn sum_i128(self) -> i128
+    where
+        Self: Sized,
+    {
+        self.map(Widen::to_i128).sum()
+    }
+
+    /// Returns the product of items modulo `M`. Negative items are taken by
+    /// their (non-negative) residue.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::{ext::iter::widening::WideningSum, math::{modulo::Val7, value}};
+    ///
+    /// let v = vec![1_000_000_000u32; 3];
+    /// assert_eq!(v.iter().product_mod::<Val7>().val(), 999_999_664);
+    /// assert_eq!([-1, 2].into_iter().product_mod::<Val7>().val(), 1_000_000_005);
+    ///
+    /// value!(Val13: i64 = 13);
+    /// assert_eq!((1..=5).product_mod::<Val13>().val(), 3);
+    /// ```
+    fn product_mod<M: ConstValue<i64>>(self) -> Modulo<i64, M>
+    where
+        Self: Sized,
+    {
+        let m = i128::from(M::value());
+        self.fold(Modulo::new(1), |acc, x| {
+            acc * Modulo::new((x.to_i128() % m) as i64)
+        })
+    }
+}
+
+impl<I: Iterator> WideningSum for I where I::Item: Widen {}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::math::modulo::Val7};
+
+    #[test]
+    fn test_sums() {
+        let v: Vec<i32> = vec![i32::MAX; 3];
+        assert_eq!(v.iter().sum_i64(), 3 * i32::MAX as i64);
+        assert_eq!(v.into_iter().sum_i128(), 3 * i32::MAX as i128);
+
+        let v = [-5i8, -128, 127];
+        assert_eq!(v.iter().sum_i64(), -6);
+        assert_eq!([usize::MAX, 1].iter().sum_i128(), 1 << 64);
+        assert_eq!([i64::MAX as u64].iter().sum_i64(), i64::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "Value 9223372036854775808 doesn't fit into i64")]
+    fn test_sum_i64_too_big() {
+        [1u64 << 63].iter().sum_i64();
+    }
+
+    #[test]
+    fn test_product_mod() {
+        let m = 1_000_000_007u64;
+        let v: Vec<u64> = (1..=1000).map(|x| x * 999_999_937).collect();
+        let want = v.iter().fold(1u64, |acc, &x| acc * (x % m) % m);
+        assert_eq!(v.iter().product_mod::<Val7>().val() as u64, want);
+
+        assert_eq!(std::iter::empty::<i32>().product_mod::<Val7>().val(), 1);
+        assert_eq!([i64::MIN].iter().product_mod::<Val7>().val(), 708_828_003);
+        assert_eq!([m, 5].iter().product_mod::<Val7>().val(), 0);
+    }
+}
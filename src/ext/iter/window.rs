@@ -172,7 +172,7 @@ mod tests {
         assert_eq!(w.next(), Some((4, 5)));
         assert_eq!(w.next(), None);
 
-        let v = vec![1, 2, 3, 4, 5];
+        let v = [1, 2, 3, 4, 5];
         let mut w = v
             .iter()
             .sliding_window()
@@ -1,5 +1,13 @@
 //! Extensions to the standard library types.
 //!
+//! # Bits
+//! The [`bits`] module provides bit queries on integers, and iterators over
+//! masks, frequently needed in bitmask DP.
+//!
+//! # Characters
+//! The [`char`](module@char) module provides classification of ASCII characters
+//! (as `char` or `u8`), and their conversion to letter indexes and digits.
+//!
 //! # Iterators
 //! The [`iter`] module provides extensions to iterators.
 
@@ -7,13 +15,20 @@
 #![doc = include_str!("./iter/README.md")]
 //!
 
+//! # Ordering
+//! The [`ord`] module provides `chmin` / `chmax` updates, returning whether
+//! the value changed.
+//!
 //! # Slices
 //!
 //! # Tuples
 //!
 //! # Vectors
 
+pub mod bits;
+pub mod char;
 pub mod iter;
+pub mod ord;
 pub mod slice;
 pub mod tuple;
 pub mod vec;
@@ -0,0 +1,68 @@
+//! Conditional updates of values, the common DP idiom of relaxing a value
+//! with a candidate.
+//!
+//! ```
+//! use algorist::ext::ord::MinMaxUpdate;
+//!
+//! // Shortest paths relaxation.
+//! let mut dist = vec![0, 5, i64::MAX];
+//! let cand = dist[1] + 3;
+//! assert!(dist[2].chmin(cand));
+//! assert!(!dist[2].chmin(9));
+//! assert_eq!(dist[2], 8);
+//!
+//! let mut best = (0, 0);
+//! assert!(best.chmax((3, 1)));
+//! assert!(!best.chmax((3, 1)));
+//! ```
+
+/// Extension trait to update a value with a smaller (or larger) one.
+pub trait MinMaxUpdate: PartialOrd + Sized {
+    /// Replaces `self` with `other`, if `other` is strictly less, and returns
+    /// whether the value changed.
+    fn chmin(&mut self, other: Self) -> bool {
+        if other < *self {
+            *self = other;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Replaces `self` with `other`, if `other` is strictly greater, and
+    /// returns whether the value changed.
+    fn chmax(&mut self, other: Self) -> bool {
+        if other > *self {
+            *self = other;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl<T: PartialOrd> MinMaxUpdate for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chmin_chmax() {
+        let mut x = 5;
+        assert!(x.chmin(3));
+        assert!(!x.chmin(3));
+        assert!(!x.chmax(2));
+        assert!(x.chmax(7));
+        assert_eq!(x, 7);
+
+        let mut f = 1.0;
+        assert!(!f.chmin(f64::NAN));
+        assert!(f.chmax(1.5));
+        assert_eq!(f, 1.5);
+
+        let mut s = String::from("b");
+        assert!(s.chmin("a".to_string()));
+        assert_eq!(s, "a");
+    }
+}
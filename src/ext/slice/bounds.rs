@@ -0,0 +1,153 @@
+use std::ops::Range;
+
+pub trait Bounds<T> {
+    /// Returns the index of the first element, which is not less than `x`
+    /// (`len` if there is none). The slice must be sorted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::ext::slice::bounds::Bounds;
+    ///
+    /// let a = [1, 3, 3, 5];
+    /// assert_eq!(a.lower_bound(&3), 1);
+    /// assert_eq!(a.lower_bound(&4), 3);
+    /// assert_eq!(a.lower_bound(&9), 4);
+    /// ```
+    fn lower_bound(&self, x: &T) -> usize
+    where
+        T: Ord;
+
+    /// Returns the index of the first element, which is greater than `x`
+    /// (`len` if there is none). The slice must be sorted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::ext::slice::bounds::Bounds;
+    ///
+    /// let a = [1, 3, 3, 5];
+    /// assert_eq!(a.upper_bound(&3), 3);
+    /// assert_eq!(a.upper_bound(&0), 0);
+    /// // Number of elements not greater than 4:
+    /// assert_eq!(a.upper_bound(&4), 3);
+    /// ```
+    fn upper_bound(&self, x: &T) -> usize
+    where
+        T: Ord;
+
+    /// Returns the range of elements equal to `x` (empty, positioned at the
+    /// lower bound, if there are none). The slice must be sorted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::ext::slice::bounds::Bounds;
+    ///
+    /// let a = [1, 3, 3, 5];
+    /// assert_eq!(a.equal_range(&3), 1..3);
+    /// assert_eq!(a.equal_range(&4), 3..3);
+    /// ```
+    fn equal_range(&self, x: &T) -> Range<usize>
+    where
+        T: Ord;
+
+    /// Same as [`lower_bound`](Self::lower_bound), for a slice sorted by the
+    /// `key`. Elements themselves need not be comparable.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::ext::slice::bounds::Bounds;
+    ///
+    /// let events = [(1, 'a'), (4, 'b'), (4, 'c'), (7, 'd')];
+    /// assert_eq!(events.lower_bound_by_key(&4, |e| e.0), 1);
+    /// assert_eq!(events.upper_bound_by_key(&4, |e| e.0), 3);
+    /// ```
+    fn lower_bound_by_key<K: Ord, F: FnMut(&T) -> K>(&self, key: &K, f: F) -> usize;
+
+    /// Same as [`upper_bound`](Self::upper_bound), for a slice sorted by the
+    /// `key`.
+    fn upper_bound_by_key<K: Ord, F: FnMut(&T) -> K>(&self, key: &K, f: F) -> usize;
+
+    /// Same as [`equal_range`](Self::equal_range), for a slice sorted by the
+    /// `key`.
+    fn equal_range_by_key<K: Ord, F: FnMut(&T) -> K>(&self, key: &K, f: F) -> Range<usize>;
+}
+
+impl<T> Bounds<T> for [T] {
+    fn lower_bound(&self, x: &T) -> usize
+    where
+        T: Ord,
+    {
+        self.partition_point(|y| y < x)
+    }
+
+    fn upper_bound(&self, x: &T) -> usize
+    where
+        T: Ord,
+    {
+        self.partition_point(|y| y <= x)
+    }
+
+    fn equal_range(&self, x: &T) -> Range<usize>
+    where
+        T: Ord,
+    {
+        self.lower_bound(x)..self.upper_bound(x)
+    }
+
+    fn lower_bound_by_key<K: Ord, F: FnMut(&T) -> K>(&self, key: &K, mut f: F) -> usize {
+        self.partition_point(|y| f(y) < *key)
+    }
+
+    fn upper_bound_by_key<K: Ord, F: FnMut(&T) -> K>(&self, key: &K, mut f: F) -> usize {
+        self.partition_point(|y| f(y) <= *key)
+    }
+
+    fn equal_range_by_key<K: Ord, F: FnMut(&T) -> K>(&self, key: &K, mut f: F) -> Range<usize> {
+        self.lower_bound_by_key(key, &mut f)..self.upper_bound_by_key(key, &mut f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::misc::rng::Rng};
+
+    #[test]
+    fn test_against_naive() {
+        let mut rng = Rng::new(16);
+        for _ in 0..100 {
+            let n = rng.gen_range(0..20);
+            let mut a: Vec<i32> = (0..n).map(|_| rng.gen_range(0..10)).collect();
+            a.sort();
+            for x in -1..11 {
+                let lo = a.iter().filter(|&&y| y < x).count();
+                let hi = a.iter().filter(|&&y| y <= x).count();
+                assert_eq!(a.lower_bound(&x), lo);
+                assert_eq!(a.upper_bound(&x), hi);
+                assert_eq!(a.equal_range(&x), lo..hi);
+
+                // Sorted in descending order, by a key.
+                let b: Vec<i32> = a.iter().map(|y| -y).collect();
+                assert_eq!(b.equal_range_by_key(&x, |y| -y), lo..hi);
+            }
+        }
+    }
+
+    #[test]
+    fn test_by_key_not_ord() {
+        // Points, sorted by `x`, with `f64` coordinates.
+        let points = [(1, 0.5), (3, -1.0), (3, 2.5), (8, 0.0)];
+        assert_eq!(points.lower_bound_by_key(&3, |p| p.0), 1);
+        assert_eq!(points.upper_bound_by_key(&3, |p| p.0), 3);
+        assert_eq!(points.equal_range_by_key(&5, |p| p.0), 3..3);
+    }
+
+    #[test]
+    fn test_empty() {
+        let a: [u8; 0] = [];
+        assert_eq!(a.lower_bound(&1), 0);
+        assert_eq!(a.equal_range(&1), 0..0);
+    }
+}
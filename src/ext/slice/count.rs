@@ -1,5 +1,30 @@
-use std::collections::HashMap;
+use {crate::math::AsPrimitive, std::collections::HashMap};
 
+/// Extension trait for slices to count occurrences of small values.
+///
+/// Values are of any primitive integer type. Other elements (e.g. `char`s)
+/// are counted by a key, see [`count_by_key`](Self::count_by_key).
+///
+/// # Example
+///
+/// ```
+/// use algorist::ext::slice::count::CountOccurrences;
+///
+/// let v: Vec<u8> = vec![1, 2, 2, 4];
+/// assert_eq!(v.count(4), vec![0, 1, 2, 0, 1]);
+///
+/// let cnt = "hello"
+///     .as_bytes()
+///     .count_by_key(25, |&c| usize::from(c - b'a'));
+/// assert_eq!((cnt[11], cnt[4]), (2, 1));
+///
+/// // Values `1` and `4` occur once, and `2` occurs twice.
+/// let groups = v.group_by_count(4, true);
+/// assert_eq!(
+///     (groups[&1].clone(), groups[&2].clone()),
+///     (vec![1, 4], vec![2])
+/// );
+/// ```
 pub trait CountOccurrences<T> {
     /// Returns the number of occurrences of each element in the array.
     ///
@@ -9,36 +34,73 @@ pub trait CountOccurrences<T> {
     ///
     /// Returned vector is of size `n + 1`, where `n` is the maximum value for
     /// which occurrence count is needed.
-    fn count(&self, n: usize) -> Vec<usize>;
+    fn count(&self, n: usize) -> Vec<usize>
+    where
+        T: AsPrimitive<usize>;
+
+    /// Same as [`count`](Self::count), but counts the values of `key` of the
+    /// elements, e.g. letter indexes of `char`s. Keys greater than `n` are
+    /// ignored.
+    fn count_by_key<F: FnMut(&T) -> usize>(&self, n: usize, key: F) -> Vec<usize>;
 
     /// Group by occurrence count.
     ///
+    /// The slice is treated as the result of [`count`](Self::count): `self[v]`
+    /// is the number of occurrences of the value `v`. Returns the values for
+    /// each occurrence count.
+    ///
     /// Additionally, if `exclude_zero` is set to true, the result will not
     /// consider occurrences of 0 (zero as element). Useful, if only natural
     /// numbers are supposed to be counted.
-    fn group(&self, exclude_zero: bool) -> HashMap<usize, Vec<usize>>;
+    fn group(&self, exclude_zero: bool) -> HashMap<usize, Vec<usize>>
+    where
+        T: AsPrimitive<usize>;
+
+    /// Groups values in `0..=n` by the number of their occurrences in the
+    /// slice, i.e. `self.count(n).group(exclude_zero)`.
+    fn group_by_count(&self, n: usize, exclude_zero: bool) -> HashMap<usize, Vec<usize>>
+    where
+        T: AsPrimitive<usize>;
 }
 
-impl CountOccurrences<usize> for [usize] {
-    fn count(&self, n: usize) -> Vec<usize> {
+impl<T> CountOccurrences<T> for [T] {
+    fn count(&self, n: usize) -> Vec<usize>
+    where
+        T: AsPrimitive<usize>,
+    {
+        self.count_by_key(n, AsPrimitive::as_primitive)
+    }
+
+    fn count_by_key<F: FnMut(&T) -> usize>(&self, n: usize, mut key: F) -> Vec<usize> {
         let mut cnt = vec![0; n + 1];
-        for i in 0..self.len() {
-            if self[i] <= n {
-                cnt[self[i]] += 1;
+        for x in self {
+            let k = key(x);
+            if k <= n {
+                cnt[k] += 1;
             }
         }
         cnt
     }
 
-    fn group(&self, exclude_zero: bool) -> HashMap<usize, Vec<usize>> {
+    fn group(&self, exclude_zero: bool) -> HashMap<usize, Vec<usize>>
+    where
+        T: AsPrimitive<usize>,
+    {
         self.iter()
             .enumerate()
             .skip(usize::from(exclude_zero))
-            .fold(HashMap::new(), |mut acc, (val, &count)| {
-                acc.entry(count).or_default().push(val);
+            .fold(HashMap::new(), |mut acc, (val, count)| {
+                acc.entry(count.as_primitive()).or_default().push(val);
                 acc
             })
     }
+
+    fn group_by_count(&self, n: usize, exclude_zero: bool) -> HashMap<usize, Vec<usize>>
+    where
+        T: AsPrimitive<usize>,
+    {
+        self.count(n).group(exclude_zero)
+    }
 }
 
 #[cfg(test)]
@@ -50,7 +112,7 @@ mod tests {
         let v = vec![1, 2, 3, 2, 0, 1, 3, 3, 2, 1];
         assert_eq!(v.count(3), vec![1, 3, 3, 3]);
 
-        let v = vec![3, 2, 8, 3];
+        let v = [3, 2, 8, 3];
         assert_eq!(v.count(8), vec![0, 0, 1, 2, 0, 0, 0, 0, 1]);
         assert_eq!(v[..2].count(3), vec![0, 0, 1, 1]);
         assert_eq!(v[..3].count(8), vec![0, 0, 1, 1, 0, 0, 0, 0, 1]);
@@ -71,4 +133,30 @@ mod tests {
         map.insert(3, vec![1, 2, 3]);
         assert_eq!(v.count(5).group(true), map);
     }
+
+    #[test]
+    fn test_count_generic() {
+        let v: [u8; 5] = [0, 3, 3, 1, 9];
+        assert_eq!(v.count(3), vec![1, 1, 0, 2]);
+        let v: Vec<u32> = vec![7, 2, 7];
+        assert_eq!(v.count(7)[7], 2);
+        // Negative values are never counted.
+        assert_eq!([-1i64, 0, 2].count(2), vec![1, 0, 1]);
+        let b = [true, false, true];
+        assert_eq!(b.count_by_key(1, |&x| x.into()), vec![1, 2]);
+
+        let s: Vec<char> = "banana".chars().collect();
+        let cnt = s.count_by_key(25, |&c| (c as u8 - b'a') as usize);
+        assert_eq!((cnt[0], cnt[1], cnt[13], cnt[25]), (3, 1, 2, 0));
+    }
+
+    #[test]
+    fn test_group_by_count() {
+        let v = vec![1, 2, 3, 5, 2, 0, 1, 3, 3, 2, 1];
+        assert_eq!(v.group_by_count(5, false), v.count(5).group(false));
+        let groups = [3, 3, 1].group_by_count(3, true);
+        assert_eq!(groups[&0], vec![2]);
+        assert_eq!(groups[&1], vec![1]);
+        assert_eq!(groups[&2], vec![3]);
+    }
 }
@@ -1,2 +1,4 @@
+pub mod bounds;
 pub mod count;
+pub mod prefix;
 pub mod sum;
@@ -0,0 +1,64 @@
+use crate::math::Number;
+
+pub trait PrefixSums<T> {
+    /// Returns prefix sums of the slice, with a leading zero: element `i` is
+    /// the sum of the first `i` elements, so that the sum over `l..r` is
+    /// `p[r] - p[l]`.
+    ///
+    /// Sums are accumulated in `S`, which can be wider than `T`, to avoid
+    /// overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::ext::slice::prefix::PrefixSums;
+    ///
+    /// let a = [3, 1, 4, 1, 5];
+    /// let p: Vec<i32> = a.prefix_sums();
+    /// assert_eq!(p, vec![0, 3, 4, 8, 9, 14]);
+    /// assert_eq!(p[4] - p[1], 1 + 4 + 1);
+    ///
+    /// // Widening to `i64`.
+    /// let a = vec![i32::MAX; 3];
+    /// let p = a.prefix_sums::<i64>();
+    /// assert_eq!(p[3], 3 * i64::from(i32::MAX));
+    /// ```
+    fn prefix_sums<S: Number + From<T>>(&self) -> Vec<S>;
+}
+
+impl<T: Copy> PrefixSums<T> for [T] {
+    fn prefix_sums<S: Number + From<T>>(&self) -> Vec<S> {
+        let mut res = Vec::with_capacity(self.len() + 1);
+        let mut sum = S::zero();
+        res.push(sum);
+        for &x in self {
+            sum += S::from(x);
+            res.push(sum);
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prefix_sums() {
+        let empty: [u8; 0] = [];
+        assert_eq!(empty.prefix_sums::<u8>(), vec![0]);
+
+        let a = [200u8, 100, 50];
+        assert_eq!(a.prefix_sums::<u32>(), vec![0, 200, 300, 350]);
+        let p = a.prefix_sums::<i128>();
+        for l in 0..=a.len() {
+            for r in l..=a.len() {
+                let want: i128 = a[l..r].iter().map(|&x| i128::from(x)).sum();
+                assert_eq!(p[r] - p[l], want);
+            }
+        }
+
+        let v = [-3i64, 1];
+        assert_eq!(v.prefix_sums::<i64>(), vec![0, -3, -2]);
+    }
+}
@@ -38,9 +38,122 @@ pub fn max_sum_from_iter<T: Number + Ord, I: Iterator<Item = T>>(iter: I) -> T {
     max_sum
 }
 
+/// Returns the maximum sum of a non-empty contiguous sub-array, or `None` if
+/// the array is empty.
+///
+/// Unlike [`max_sum`], which allows the empty sub-array (and so never returns
+/// a negative value), for an array of negative numbers this returns the
+/// largest of them.
+///
+/// # Example
+///
+/// ```
+/// use algorist::ext::slice::sum::max_sum_non_empty;
+///
+/// assert_eq!(max_sum_non_empty(&[-3, -1, -2]), Some(-1));
+/// assert_eq!(max_sum_non_empty(&[2, -1, 2]), Some(3));
+/// assert_eq!(max_sum_non_empty::<i32>(&[]), None);
+/// ```
+pub fn max_sum_non_empty<T: Number + Ord>(arr: &[T]) -> Option<T> {
+    max_sum_range(arr).map(|(l, r)| arr[l..r].iter().fold(T::zero(), |acc, &x| acc + x))
+}
+
+/// Returns the half-open range `l..r` of a non-empty contiguous sub-array
+/// with the maximum sum, or `None` if the array is empty.
+///
+/// Of several optimal sub-arrays, the one ending first is returned, and of
+/// those, the shortest one.
+///
+/// # Example
+///
+/// ```
+/// use algorist::ext::slice::sum::max_sum_range;
+///
+/// let a = [2, -5, 3, -1, 4, -9];
+/// assert_eq!(max_sum_range(&a), Some((2, 5)));
+/// assert_eq!(max_sum_range(&[-2, -1]), Some((1, 2)));
+/// assert_eq!(max_sum_range::<i64>(&[]), None);
+/// ```
+pub fn max_sum_range<T: Number + Ord>(arr: &[T]) -> Option<(usize, usize)> {
+    let (&first, rest) = arr.split_first()?;
+    // `cur` is the maximum sum of a sub-array, ending at the current element,
+    // and `start` is where the shortest such sub-array starts.
+    let (mut cur, mut start) = (first, 0);
+    let (mut best, mut range) = (first, (0, 1));
+    for (i, &x) in rest.iter().enumerate().map(|(i, x)| (i + 1, x)) {
+        if cur > T::zero() {
+            cur += x;
+        } else {
+            (cur, start) = (x, i);
+        }
+        if cur > best {
+            (best, range) = (cur, (start, i + 1));
+        }
+    }
+    Some(range)
+}
+
+/// Returns the minimum sum of a contiguous sub-array (the empty sub-array,
+/// with zero sum, is allowed).
+///
+/// # Example
+///
+/// ```
+/// use algorist::ext::slice::sum::min_sum;
+///
+/// assert_eq!(min_sum(&[3, -2, -1, 4, -5]), -5);
+/// assert_eq!(min_sum(&[1, 2]), 0);
+/// ```
+pub fn min_sum<T: Number + Ord>(arr: &[T]) -> T {
+    let mut min_sum = T::zero();
+    let mut current_sum = T::zero();
+
+    for &num in arr {
+        current_sum = current_sum.min(T::zero()) + num;
+        min_sum = min_sum.min(current_sum);
+    }
+    min_sum
+}
+
+/// Returns the maximum sum of a contiguous sub-array of the circular array,
+/// i.e. sub-arrays may wrap around the end (the empty sub-array is allowed).
+///
+/// # Example
+///
+/// ```
+/// use algorist::ext::slice::sum::max_sum_circular;
+///
+/// assert_eq!(max_sum_circular(&[5, -3, 5]), 10);
+/// assert_eq!(max_sum_circular(&[-1, -2]), 0);
+/// ```
+pub fn max_sum_circular<T: Number + Ord>(arr: &[T]) -> T {
+    let total = arr.iter().fold(T::zero(), |acc, &x| acc + x);
+    max_sum(arr).max(total - min_sum(arr))
+}
+
+/// Returns the maximum sum of a non-empty contiguous sub-array of the
+/// circular array, or `None` if the array is empty.
+///
+/// # Example
+///
+/// ```
+/// use algorist::ext::slice::sum::max_sum_circular_non_empty;
+///
+/// assert_eq!(max_sum_circular_non_empty(&[3, -1, 2, -1]), Some(4));
+/// assert_eq!(max_sum_circular_non_empty(&[-3, -2, -3]), Some(-2));
+/// ```
+pub fn max_sum_circular_non_empty<T: Number + Ord>(arr: &[T]) -> Option<T> {
+    let best = max_sum_non_empty(arr)?;
+    if best < T::zero() {
+        // All the elements are negative, the answer is a single element.
+        return Some(best);
+    }
+    Some(max_sum_circular(arr))
+}
+
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use {super::*, crate::misc::rng::Rng};
 
     #[test]
     fn test_max_sum() {
@@ -71,4 +184,46 @@ mod tests {
             14
         );
     }
+
+    /// Returns the maximum sum over all non-empty sub-arrays, optionally
+    /// wrapping around.
+    fn naive(arr: &[i64], circular: bool) -> Option<i64> {
+        let n = arr.len();
+        (0..n)
+            .flat_map(|l| {
+                let max_len = if circular { n } else { n - l };
+                (1..=max_len).map(move |len| (l..l + len).map(|i| arr[i % n]).sum())
+            })
+            .max()
+    }
+
+    #[test]
+    fn test_against_naive() {
+        let mut rng = Rng::new(29);
+        for _ in 0..500 {
+            let n = rng.gen_range(0..9);
+            let arr: Vec<i64> = (0..n).map(|_| rng.gen_range(0..11i64) - 6).collect();
+            let want = naive(&arr, false);
+            assert_eq!(max_sum_non_empty(&arr), want);
+            assert_eq!(max_sum(&arr), want.unwrap_or(0).max(0));
+            let (l, r) = max_sum_range(&arr).unwrap_or((0, 0));
+            assert_eq!(l < r, n > 0);
+            assert_eq!(Some(arr[l..r].iter().sum()), want.or(Some(0)));
+
+            let negated: Vec<i64> = arr.iter().map(|x| -x).collect();
+            assert_eq!(min_sum(&arr), -max_sum(&negated));
+
+            let want = naive(&arr, true);
+            assert_eq!(max_sum_circular_non_empty(&arr), want);
+            assert_eq!(max_sum_circular(&arr), want.unwrap_or(0).max(0));
+        }
+    }
+
+    #[test]
+    fn test_unsigned() {
+        let arr: [u32; 3] = [4, 0, 7];
+        assert_eq!(max_sum_range(&arr), Some((0, 3)));
+        assert_eq!(min_sum(&arr), 0);
+        assert_eq!(max_sum_circular(&arr), 11);
+    }
 }
@@ -1 +1,2 @@
+pub mod pair;
 pub mod transform;
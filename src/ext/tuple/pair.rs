@@ -0,0 +1,135 @@
+//! Mapping and arithmetic on pairs and triples of the same type, convenient
+//! for 2D (and 3D) coordinates.
+//!
+//! ```
+//! use algorist::ext::tuple::pair::{Pair, TupleArith};
+//!
+//! let (p, dir) = ((2, 3), (0, -1));
+//! assert_eq!(p.add(dir), (2, 2));
+//! assert_eq!(p.sub(dir).swapped(), (4, 2));
+//! assert_eq!((5, 1).minmax(), (1, 5));
+//! assert_eq!((1u8, 2).map_both(usize::from), (1usize, 2usize));
+//! assert_eq!((1, 2, 3).add((10, 20, 30)).sum(), 66);
+//! ```
+
+use std::ops::{Add, Sub};
+
+/// Extension trait for pairs of items of the same type.
+pub trait Pair<T>: Sized {
+    /// Applies `f` to both items.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::ext::tuple::pair::Pair;
+    ///
+    /// assert_eq!(("ab", "c").map_both(str::len), (2, 1));
+    /// ```
+    fn map_both<U, F: FnMut(T) -> U>(self, f: F) -> (U, U);
+
+    /// Returns the pair with the items swapped.
+    fn swapped(self) -> (T, T);
+
+    /// Returns the pair ordered, so that the first item is not greater than
+    /// the second one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::ext::tuple::pair::Pair;
+    ///
+    /// // Normalize an undirected edge.
+    /// assert_eq!((4, 2).minmax(), (2, 4));
+    /// assert_eq!((2, 4).minmax(), (2, 4));
+    /// ```
+    fn minmax(self) -> (T, T)
+    where
+        T: PartialOrd;
+}
+
+impl<T> Pair<T> for (T, T) {
+    fn map_both<U, F: FnMut(T) -> U>(self, mut f: F) -> (U, U) {
+        (f(self.0), f(self.1))
+    }
+
+    fn swapped(self) -> (T, T) {
+        (self.1, self.0)
+    }
+
+    fn minmax(self) -> (T, T)
+    where
+        T: PartialOrd,
+    {
+        if self.1 < self.0 {
+            (self.1, self.0)
+        } else {
+            self
+        }
+    }
+}
+
+/// Elementwise arithmetic on pairs and triples of numbers.
+pub trait TupleArith<T>: Sized {
+    /// Returns the elementwise sum of `self` and `other`.
+    #[must_use]
+    fn add(self, other: Self) -> Self;
+
+    /// Returns the elementwise difference of `self` and `other`.
+    #[must_use]
+    fn sub(self, other: Self) -> Self;
+
+    /// Returns the sum of the items.
+    fn sum(self) -> T;
+}
+
+impl<T: Copy + Add<Output = T> + Sub<Output = T>> TupleArith<T> for (T, T) {
+    fn add(self, other: Self) -> Self {
+        (self.0 + other.0, self.1 + other.1)
+    }
+
+    fn sub(self, other: Self) -> Self {
+        (self.0 - other.0, self.1 - other.1)
+    }
+
+    fn sum(self) -> T {
+        self.0 + self.1
+    }
+}
+
+impl<T: Copy + Add<Output = T> + Sub<Output = T>> TupleArith<T> for (T, T, T) {
+    fn add(self, other: Self) -> Self {
+        (self.0 + other.0, self.1 + other.1, self.2 + other.2)
+    }
+
+    fn sub(self, other: Self) -> Self {
+        (self.0 - other.0, self.1 - other.1, self.2 - other.2)
+    }
+
+    fn sum(self) -> T {
+        self.0 + self.1 + self.2
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pair() {
+        assert_eq!((1, 2).swapped(), (2, 1));
+        assert_eq!((3, 3).minmax(), (3, 3));
+        assert_eq!((2.5, -1.0).minmax(), (-1.0, 2.5));
+        assert_eq!((-3i32, 4).map_both(i32::unsigned_abs), (3, 4));
+    }
+
+    #[test]
+    fn test_arith() {
+        let dirs = [(0, 1), (1, 0), (0, -1), (-1, 0)];
+        let neighbors: Vec<_> = dirs.iter().map(|&d| (5, 5).add(d)).collect();
+        assert_eq!(neighbors, vec![(5, 6), (6, 5), (5, 4), (4, 5)]);
+        assert_eq!((7u64, 3).sub((2, 3)), (5, 0));
+        assert_eq!((1.5, 2.0).sum(), 3.5);
+        assert_eq!((1, 2, 3).sub((1, 1, 1)), (0, 1, 2));
+        assert_eq!((4, 5, 6).sum(), 15);
+    }
+}
@@ -0,0 +1,70 @@
+pub trait Argsort {
+    /// Returns indexes of elements, ordered by values (stable, so equal
+    /// elements keep their original order).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::ext::vec::argsort::Argsort;
+    ///
+    /// let v = vec![30, 10, 20, 10];
+    /// assert_eq!(v.argsort(), vec![1, 3, 2, 0]);
+    /// ```
+    fn argsort(&self) -> Vec<usize>;
+
+    /// Returns the rank of each element: its position in the (stable) sorted
+    /// order, so that the result is the inverse permutation of
+    /// [`argsort`](Self::argsort).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::ext::vec::argsort::Argsort;
+    ///
+    /// let v = vec![30, 10, 20, 10];
+    /// assert_eq!(v.rank(), vec![3, 0, 2, 1]);
+    /// ```
+    fn rank(&self) -> Vec<usize>;
+}
+
+impl<T: Ord> Argsort for [T] {
+    fn argsort(&self) -> Vec<usize> {
+        let mut idx: Vec<usize> = (0..self.len()).collect();
+        idx.sort_by_key(|&i| &self[i]);
+        idx
+    }
+
+    fn rank(&self) -> Vec<usize> {
+        let mut rank = vec![0; self.len()];
+        for (r, i) in self.argsort().into_iter().enumerate() {
+            rank[i] = r;
+        }
+        rank
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::misc::rng::Rng};
+
+    #[test]
+    fn test_argsort() {
+        let mut rng = Rng::new(17);
+        for _ in 0..50 {
+            let n = rng.gen_range(0..20);
+            let v: Vec<u8> = (0..n).map(|_| rng.gen_range(0..5)).collect();
+            let idx = v.argsort();
+            let sorted: Vec<u8> = idx.iter().map(|&i| v[i]).collect();
+            let mut want = v.clone();
+            want.sort();
+            assert_eq!(sorted, want);
+            // Stability.
+            assert!(idx.windows(2).all(|w| v[w[0]] < v[w[1]] || w[0] < w[1]));
+
+            let rank = v.rank();
+            for (r, &i) in idx.iter().enumerate() {
+                assert_eq!(rank[i], r);
+            }
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use crate::math::Number;
+
+/// Difference array: allows to add a value to a range in `O(1)`, after which
+/// the resulting array is restored in `O(n)`.
+///
+/// # Example
+///
+/// ```
+/// use algorist::ext::vec::diff::DiffArray;
+///
+/// let mut d = DiffArray::new(5);
+/// d.add(1, 4, 2);
+/// d.add(0, 2, -1);
+/// d.add(3, 5, 10);
+/// assert_eq!(d.materialize(), vec![-1, 1, 2, 12, 10]);
+///
+/// // Starting from an existing array.
+/// let mut d = DiffArray::from(vec![5, 5, 5]);
+/// d.add(2, 3, 1);
+/// assert_eq!(d.materialize(), vec![5, 5, 6]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct DiffArray<T> {
+    /// `diff[i]` is the difference between elements `i` and `i - 1`, with
+    /// an extra element at the end, absorbing the updates of suffixes.
+    diff: Vec<T>,
+}
+
+impl<T: Number> DiffArray<T> {
+    /// Creates a difference array of `n` zeros.
+    pub fn new(n: usize) -> Self {
+        Self {
+            diff: vec![T::zero(); n + 1],
+        }
+    }
+
+    /// Returns the length of the array.
+    pub fn len(&self) -> usize {
+        self.diff.len() - 1
+    }
+
+    /// Checks whether the array is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Adds `v` to all elements in `l..r`, in `O(1)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    pub fn add(&mut self, l: usize, r: usize, v: T) {
+        assert!(l <= r && r <= self.len(), "Range {l}..{r} is out of bounds");
+        self.diff[l] += v;
+        self.diff[r] -= v;
+    }
+
+    /// Returns the array with all the updates applied, in `O(n)`.
+    pub fn materialize(&self) -> Vec<T> {
+        let mut cur = T::zero();
+        self.diff[..self.len()]
+            .iter()
+            .map(|&d| {
+                cur += d;
+                cur
+            })
+            .collect()
+    }
+}
+
+impl<T: Number> From<Vec<T>> for DiffArray<T> {
+    fn from(arr: Vec<T>) -> Self {
+        let mut diff = Vec::with_capacity(arr.len() + 1);
+        let mut prev = T::zero();
+        for x in arr {
+            diff.push(x - prev);
+            prev = x;
+        }
+        diff.push(T::zero() - prev);
+        Self { diff }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::misc::rng::Rng};
+
+    #[test]
+    fn test_against_naive() {
+        let mut rng = Rng::new(14);
+        for _ in 0..50 {
+            let n = rng.gen_range(0..20);
+            let mut naive: Vec<i64> = (0..n).map(|_| rng.gen_range(-9..10)).collect();
+            let mut d = DiffArray::from(naive.clone());
+            assert_eq!(d.len(), n);
+            for _ in 0..20 {
+                let l = rng.gen_range(0..=n);
+                let r = rng.gen_range(l..=n);
+                let v = rng.gen_range(-100..100);
+                d.add(l, r, v);
+                naive[l..r].iter_mut().for_each(|x| *x += v);
+            }
+            assert_eq!(d.materialize(), naive);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Range 2..4 is out of bounds")]
+    fn test_out_of_bounds() {
+        DiffArray::<i32>::new(3).add(2, 4, 1);
+    }
+}
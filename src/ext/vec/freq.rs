@@ -0,0 +1,118 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// Extension trait for vectors and slices to count occurrences of items.
+///
+/// # Example
+///
+/// ```
+/// use algorist::ext::vec::freq::Frequencies;
+///
+/// let v = vec![3, 1, 3, 2, 3, 1];
+/// assert_eq!(v.freq_map()[&3], 3);
+/// assert_eq!(v.mode(), Some(3));
+///
+/// let mut s = v.clone();
+/// s.sort();
+/// assert_eq!(s.dedup_with_counts(), vec![(1, 2), (2, 1), (3, 3)]);
+/// ```
+pub trait Frequencies<T> {
+    /// Returns the number of occurrences of each item.
+    fn freq_map(&self) -> HashMap<T, usize>
+    where
+        T: Eq + Hash + Clone;
+
+    /// Returns distinct items with the numbers of their occurrences. The slice
+    /// must be sorted (otherwise, equal items are only merged when they are
+    /// adjacent).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::ext::vec::freq::Frequencies;
+    ///
+    /// let v = [1, 1, 2, 5, 5, 5];
+    /// assert_eq!(v.dedup_with_counts(), vec![(1, 2), (2, 1), (5, 3)]);
+    /// assert_eq!([0; 0].dedup_with_counts(), vec![]);
+    /// ```
+    fn dedup_with_counts(&self) -> Vec<(T, usize)>
+    where
+        T: PartialEq + Clone;
+
+    /// Returns the most frequent item, or `None` if the slice is empty. Of
+    /// several equally frequent items, the one occurring first is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::ext::vec::freq::Frequencies;
+    ///
+    /// assert_eq!(b"mississippi".mode(), Some(b'i'));
+    /// assert_eq!([2, 1, 1, 2].mode(), Some(2));
+    /// assert_eq!(Vec::<u8>::new().mode(), None);
+    /// ```
+    fn mode(&self) -> Option<T>
+    where
+        T: Eq + Hash + Clone;
+}
+
+impl<T> Frequencies<T> for [T] {
+    fn freq_map(&self) -> HashMap<T, usize>
+    where
+        T: Eq + Hash + Clone,
+    {
+        let mut res = HashMap::new();
+        for x in self {
+            *res.entry(x.clone()).or_default() += 1;
+        }
+        res
+    }
+
+    fn dedup_with_counts(&self) -> Vec<(T, usize)>
+    where
+        T: PartialEq + Clone,
+    {
+        let mut res: Vec<(T, usize)> = Vec::new();
+        for x in self {
+            match res.last_mut() {
+                Some((last, cnt)) if last == x => *cnt += 1,
+                _ => res.push((x.clone(), 1)),
+            }
+        }
+        res
+    }
+
+    fn mode(&self) -> Option<T>
+    where
+        T: Eq + Hash + Clone,
+    {
+        let freq = self.freq_map();
+        let max = freq.values().max()?;
+        self.iter().find(|x| freq[*x] == *max).cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::misc::rng::Rng};
+
+    #[test]
+    fn test_against_naive() {
+        let mut rng = Rng::new(23);
+        for _ in 0..100 {
+            let n = rng.gen_range(0..20);
+            let mut v: Vec<u8> = (0..n).map(|_| rng.gen_range(0..4)).collect();
+            let count = |x: &u8| v.iter().filter(|y| *y == x).count();
+
+            let freq = v.freq_map();
+            assert!(v.iter().all(|x| freq[x] == count(x)));
+            let best = v.iter().map(count).max();
+            assert_eq!(v.mode().map(|x| count(&x)), best);
+            assert_eq!(v.mode(), v.iter().find(|x| Some(count(x)) == best).copied());
+
+            v.sort();
+            let mut want: Vec<(u8, usize)> = freq.into_iter().collect();
+            want.sort();
+            assert_eq!(v.dedup_with_counts(), want);
+        }
+    }
+}
@@ -1,3 +1,7 @@
+pub mod argsort;
+pub mod diff;
+pub mod freq;
 pub mod reversed;
 pub mod rotated;
 pub mod sorted;
+pub mod swapped;
@@ -1,6 +1,29 @@
+use std::collections::VecDeque;
+
+/// Extension trait for vectors and deques, to reverse them (or their part) in
+/// a method chain.
+///
+/// Slices are reversed in place, see [`ReverseInPlace`].
+///
+/// # Example
+///
+/// ```
+/// use algorist::ext::vec::reversed::Reversed;
+///
+/// let v = vec![1, 2, 3, 4, 5];
+/// assert_eq!(v.reversed().reversed_in_range(1, 4), vec![5, 2, 3, 4, 1]);
+/// ```
 pub trait Reversed {
     #[must_use]
     fn reversed(self) -> Self;
+
+    /// Reverses the elements in `l..r`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    #[must_use]
+    fn reversed_in_range(self, l: usize, r: usize) -> Self;
 }
 
 impl<T> Reversed for Vec<T> {
@@ -8,4 +31,81 @@ impl<T> Reversed for Vec<T> {
         self.reverse();
         self
     }
+
+    fn reversed_in_range(mut self, l: usize, r: usize) -> Self {
+        self.reverse_range(l, r);
+        self
+    }
+}
+
+impl<T> Reversed for VecDeque<T> {
+    fn reversed(mut self) -> Self {
+        self.make_contiguous().reverse();
+        self
+    }
+
+    fn reversed_in_range(mut self, l: usize, r: usize) -> Self {
+        self.make_contiguous().reverse_range(l, r);
+        self
+    }
+}
+
+/// Extension trait for slices, to reverse their part in place.
+///
+/// # Example
+///
+/// ```
+/// use algorist::ext::vec::reversed::ReverseInPlace;
+///
+/// let mut a = [1, 2, 3, 4];
+/// a.reverse_range(1, 4);
+/// assert_eq!(a, [1, 4, 3, 2]);
+/// ```
+pub trait ReverseInPlace {
+    /// Reverses the elements in `l..r`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    fn reverse_range(&mut self, l: usize, r: usize);
+}
+
+impl<T> ReverseInPlace for [T] {
+    fn reverse_range(&mut self, l: usize, r: usize) {
+        assert!(l <= r && r <= self.len(), "Range {l}..{r} is out of bounds");
+        self[l..r].reverse();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reversed() {
+        assert_eq!(vec![1, 2, 3].reversed(), vec![3, 2, 1]);
+        assert_eq!(vec![1, 2, 3, 4].reversed_in_range(0, 2), vec![2, 1, 3, 4]);
+        assert_eq!(vec![1, 2].reversed_in_range(1, 1), vec![1, 2]);
+
+        let mut d: VecDeque<_> = (3..6).collect();
+        d.push_front(2);
+        d.push_front(1);
+        assert_eq!(d.reversed().reversed_in_range(1, 5), [5, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Range 2..4 is out of bounds")]
+    fn test_reversed_in_range_out_of_bounds() {
+        let _ = vec![1, 2, 3].reversed_in_range(2, 4);
+    }
+
+    #[test]
+    fn test_reverse_range() {
+        let mut a = [1, 2, 3, 4, 5];
+        a.reverse_range(0, 2);
+        a[2..].reverse_range(1, 3);
+        assert_eq!(a, [2, 1, 3, 5, 4]);
+        a.reverse_range(5, 5);
+        assert_eq!(a, [2, 1, 3, 5, 4]);
+    }
 }
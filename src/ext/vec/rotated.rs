@@ -1,3 +1,25 @@
+use std::collections::VecDeque;
+
+/// Extension trait for vectors and deques, to rotate them in a method chain.
+///
+/// Slices are rotated in place, with [`slice::rotate_left`] and
+/// [`slice::rotate_right`].
+///
+/// # Panics
+///
+/// Rotations panic if `k` is greater than the length.
+///
+/// # Example
+///
+/// ```
+/// use {algorist::ext::vec::rotated::Rotated, std::collections::VecDeque};
+///
+/// let v = vec![1, 2, 3, 4, 5];
+/// assert_eq!(v.rotated_left(2), vec![3, 4, 5, 1, 2]);
+///
+/// let d = VecDeque::from([1, 2, 3]);
+/// assert_eq!(d.rotated_right(1), [3, 1, 2]);
+/// ```
 pub trait Rotated {
     #[must_use]
     fn rotated_left(self, k: usize) -> Self;
@@ -6,18 +28,24 @@ pub trait Rotated {
     fn rotated_right(self, k: usize) -> Self;
 }
 
-impl<T> Rotated for Vec<T> {
-    fn rotated_left(mut self, k: usize) -> Self {
-        self.rotate_left(k);
-        self
-    }
+macro_rules! rotated_impl {
+    ($($t: ty),+) => {$(
+        impl<T> Rotated for $t {
+            fn rotated_left(mut self, k: usize) -> Self {
+                self.rotate_left(k);
+                self
+            }
 
-    fn rotated_right(mut self, k: usize) -> Self {
-        self.rotate_right(k);
-        self
-    }
+            fn rotated_right(mut self, k: usize) -> Self {
+                self.rotate_right(k);
+                self
+            }
+        }
+    )+};
 }
 
+rotated_impl!(Vec<T>, VecDeque<T>);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -33,4 +61,18 @@ mod tests {
         let v = vec![1, 2, 3, 4, 5];
         assert_eq!(v.rotated_right(2), vec![4, 5, 1, 2, 3]);
     }
+
+    #[test]
+    fn test_rotated_consistent() {
+        // Vectors, deques and slices rotate the same way.
+        for k in 0..=5 {
+            let v: Vec<_> = (0..5).collect();
+            let d: VecDeque<_> = v.iter().copied().collect();
+            let mut a = [0, 1, 2, 3, 4];
+            assert_eq!(d.clone().rotated_left(k), v.clone().rotated_left(k));
+            assert_eq!(d.rotated_right(k), v.clone().rotated_right(k));
+            a.rotate_left(k);
+            assert_eq!(a.to_vec(), v.rotated_left(k));
+        }
+    }
 }
@@ -0,0 +1,47 @@
+use std::collections::VecDeque;
+
+/// Extension trait for vectors and deques, to swap two elements in a method
+/// chain.
+///
+/// # Panics
+///
+/// Panics if either index is out of bounds.
+///
+/// # Example
+///
+/// ```
+/// use algorist::ext::vec::{reversed::Reversed, swapped::Swapped};
+///
+/// // The next permutation of `[1, 3, 2]`.
+/// let v = vec![1, 3, 2];
+/// assert_eq!(v.swapped(0, 2).reversed_in_range(1, 3), vec![2, 1, 3]);
+/// ```
+pub trait Swapped {
+    #[must_use]
+    fn swapped(self, i: usize, j: usize) -> Self;
+}
+
+macro_rules! swapped_impl {
+    ($($t: ty),+) => {$(
+        impl<T> Swapped for $t {
+            fn swapped(mut self, i: usize, j: usize) -> Self {
+                self.swap(i, j);
+                self
+            }
+        }
+    )+};
+}
+
+swapped_impl!(Vec<T>, VecDeque<T>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_swapped() {
+        assert_eq!(vec![1, 2, 3].swapped(0, 2), vec![3, 2, 1]);
+        assert_eq!(vec![1, 2, 3].swapped(1, 1), vec![1, 2, 3]);
+        assert_eq!(VecDeque::from([1, 2]).swapped(0, 1), [2, 1]);
+    }
+}
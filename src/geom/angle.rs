@@ -0,0 +1,170 @@
+//! Sorting vectors by polar angle, exactly.
+//!
+//! Sorting by `atan2` fails on integer inputs, when two directions differ by
+//! less than the precision of `f64` (easily achievable with coordinates around
+//! `1e9`). [`polar_cmp`] compares directions using half-planes and the sign of
+//! the cross product only, computed exactly in `i128`.
+//!
+//! Angles are measured counter-clockwise from the positive `x` axis, in
+//! `[0, 2π)`. Vectors with the same direction are ordered by length, and the
+//! zero vector goes first, which makes the order total.
+//!
+//! ```
+//! use algorist::geom::{angle::polar_cmp, point::Point2};
+//!
+//! let mut v = [(0, -1), (-1, 0), (1, 1), (2, 0), (1, 0), (0, 1)].map(Point2::from);
+//! v.sort_by(|&a, &b| polar_cmp(a, b));
+//! assert_eq!(
+//!     v,
+//!     [(1, 0), (2, 0), (1, 1), (0, 1), (-1, 0), (0, -1)].map(Point2::from)
+//! );
+//! ```
+//!
+//! To sort points around a pivot, use [`SortAroundExt::sort_around`]:
+//!
+//! ```
+//! use algorist::geom::{angle::SortAroundExt, point::Point2};
+//!
+//! let pivot = Point2::new(1, 1);
+//! let pts = [(0, 0), (2, 2), (0, 2), (2, 0)].map(Point2::from);
+//! let sorted: Vec<_> = pts.into_iter().sort_around(pivot).collect();
+//! assert_eq!(sorted, [(2, 2), (0, 2), (0, 0), (2, 0)].map(Point2::from));
+//! ```
+
+use {crate::geom::point::Point2, std::cmp::Ordering};
+
+/// Returns `0` for the zero vector, `1` for angles in `[0, π)`, and `2` for
+/// angles in `[π, 2π)`.
+fn half(x: i128, y: i128) -> u8 {
+    match (y.cmp(&0), x.cmp(&0)) {
+        (Ordering::Equal, Ordering::Equal) => 0,
+        (Ordering::Greater, _) | (Ordering::Equal, Ordering::Greater) => 1,
+        _ => 2,
+    }
+}
+
+/// Compares vectors by polar angle, then by length, see the [module
+/// documentation](self).
+///
+/// Exact for coordinates up to `2^62` in absolute value.
+///
+/// # Example
+///
+/// ```
+/// use {
+///     algorist::geom::{angle::polar_cmp, point::Point2},
+///     std::cmp::Ordering,
+/// };
+///
+/// // Directions differing by ~1e-18 radians.
+/// let m = 1_000_000_000i64;
+/// let (a, b) = (Point2::new(m, m - 1), Point2::new(m + 1, m));
+/// assert_eq!(polar_cmp(a, b), Ordering::Less);
+/// assert_eq!(
+///     polar_cmp(Point2::new(0, -1), Point2::new(1, -1)),
+///     Ordering::Less
+/// );
+/// ```
+pub fn polar_cmp<T: Copy + Into<i128>>(a: Point2<T>, b: Point2<T>) -> Ordering {
+    let (ax, ay) = (a.x.into(), a.y.into());
+    let (bx, by) = (b.x.into(), b.y.into());
+    half(ax, ay)
+        .cmp(&half(bx, by))
+        .then_with(|| (ay * bx).cmp(&(ax * by)))
+        .then_with(|| (ax * ax + ay * ay).cmp(&(bx * bx + by * by)))
+}
+
+/// Extension trait for iterators over points, to sort them by polar angle
+/// around a pivot.
+pub trait SortAroundExt<T>: Iterator<Item = Point2<T>> + Sized {
+    /// Returns the points sorted by polar angle around the `pivot` (and by the
+    /// distance from it, for points in the same direction), see [`polar_cmp`].
+    ///
+    /// Points coinciding with the pivot go first. Exact, as long as the
+    /// coordinates of points relative to the pivot are up to `2^62` in
+    /// absolute value.
+    fn sort_around(self, pivot: Point2<T>) -> std::vec::IntoIter<Point2<T>>;
+}
+
+impl<T, I> SortAroundExt<T> for I
+where
+    T: Copy + Into<i128>,
+    I: Iterator<Item = Point2<T>>,
+{
+    fn sort_around(self, pivot: Point2<T>) -> std::vec::IntoIter<Point2<T>> {
+        let (px, py) = (pivot.x.into(), pivot.y.into());
+        let mut pts: Vec<(Point2<i128>, Point2<T>)> = self
+            .map(|p| (Point2::new(p.x.into() - px, p.y.into() - py), p))
+            .collect();
+        pts.sort_by(|a, b| polar_cmp(a.0, b.0));
+        pts.into_iter()
+            .map(|(_, p)| p)
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::misc::rng::Rng};
+
+    #[test]
+    fn test_against_atan2() {
+        let mut rng = Rng::new(5);
+        let mut pts: Vec<Point2<i32>> = (0..500)
+            .map(|_| Point2::new(rng.gen_range(-50..=50), rng.gen_range(-50..=50)))
+            .collect();
+        pts.sort_by(|&a, &b| polar_cmp(a, b));
+        let key = |p: &Point2<i32>| {
+            let t = f64::from(p.y).atan2(f64::from(p.x));
+            (
+                if t < 0.0 {
+                    t + std::f64::consts::TAU
+                } else {
+                    t
+                },
+                p.len2(),
+            )
+        };
+        for w in pts.windows(2) {
+            let (a, b) = (key(&w[0]), key(&w[1]));
+            assert!(a.0 < b.0 + 1e-12, "{} {}", w[0], w[1]);
+            if polar_cmp(w[0], w[1]) != Ordering::Equal && (a.0 - b.0).abs() < 1e-12 {
+                assert!(a.1 < b.1);
+            }
+        }
+    }
+
+    #[test]
+    fn test_total_order() {
+        let pts = [
+            (0, 0),
+            (3, 0),
+            (1, 0),
+            (-2, 0),
+            (0, -5),
+            (4, -4),
+            (0, 2),
+            (-1, -1),
+        ];
+        let pts = pts.map(Point2::<i64>::from);
+        for &a in &pts {
+            assert_eq!(polar_cmp(a, a), Ordering::Equal);
+            for &b in &pts {
+                assert_eq!(polar_cmp(a, b), polar_cmp(b, a).reverse());
+                for &c in &pts {
+                    if polar_cmp(a, b).is_lt() && polar_cmp(b, c).is_lt() {
+                        assert!(polar_cmp(a, c).is_lt());
+                    }
+                }
+            }
+        }
+        let m = i64::MAX;
+        let sorted: Vec<_> = [(m, -m), (-m, m), (m, m), (0, 0)]
+            .map(Point2::from)
+            .into_iter()
+            .sort_around(Point2::new(0, 0))
+            .collect();
+        assert_eq!(sorted, [(0, 0), (m, m), (-m, m), (m, -m)].map(Point2::from));
+    }
+}
@@ -0,0 +1,210 @@
+//! Rotating calipers over a convex polygon.
+//!
+//! For each edge of a convex polygon, the vertex farthest from it moves
+//! monotonically around the polygon, as the edge does. This allows to find
+//! the farthest pair of vertices ([`diameter`]) and the [`width`] of the
+//! polygon in `O(n)`, and the largest triangle with vertices on the polygon
+//! ([`max_area_triangle`]) in `O(n^2)`.
+//!
+//! All functions expect a strictly convex polygon in counter-clockwise order,
+//! as returned by [`convex_hull`](super::hull::convex_hull) with
+//! `keep_collinear` unset.
+//!
+//! ```
+//! use algorist::geom::{calipers, hull::convex_hull, point::Point2};
+//!
+//! let pts = [(0, 0), (4, 0), (4, 1), (0, 1), (2, 0), (1, 1)].map(Point2::from);
+//! let hull = convex_hull(&pts, false);
+//!
+//! let (d2, i, j) = calipers::diameter(&hull).unwrap();
+//! assert_eq!(d2, 17);
+//! assert_eq!(hull[i].dist2(hull[j]), 17);
+//!
+//! assert_eq!(calipers::width(&hull), 1.0);
+//!
+//! let (area2, _) = calipers::max_area_triangle(&hull).unwrap();
+//! assert_eq!(area2, 4);
+//! ```
+
+use crate::geom::point::Point2;
+
+/// Returns the cross product of vectors `o -> a` and `o -> b`, exactly.
+fn cross3<T: Copy + Into<i128>>(o: Point2<T>, a: Point2<T>, b: Point2<T>) -> i128 {
+    let (ox, oy) = (o.x.into(), o.y.into());
+    let (ax, ay) = (a.x.into() - ox, a.y.into() - oy);
+    let (bx, by) = (b.x.into() - ox, b.y.into() - oy);
+    ax * by - ay * bx
+}
+
+fn dist2<T: Copy + Into<i128>>(a: Point2<T>, b: Point2<T>) -> i128 {
+    let (dx, dy) = (b.x.into() - a.x.into(), b.y.into() - a.y.into());
+    dx * dx + dy * dy
+}
+
+/// Calls `f(i, j)` for every edge `i -> i + 1` of the polygon, and the vertex
+/// `j` farthest from it.
+fn antipodal<T, F>(poly: &[Point2<T>], mut f: F)
+where
+    T: Copy + Into<i128>,
+    F: FnMut(usize, usize),
+{
+    let n = poly.len();
+    let mut j = 1;
+    for i in 0..n {
+        let ni = (i + 1) % n;
+        while cross3(poly[i], poly[ni], poly[(j + 1) % n]) > cross3(poly[i], poly[ni], poly[j]) {
+            j = (j + 1) % n;
+        }
+        f(i, j);
+    }
+}
+
+/// Returns the squared diameter of the convex polygon (the largest squared
+/// distance between its vertices), with the indices of the farthest vertices,
+/// or `None` if the polygon is empty.
+///
+/// # Example
+///
+/// ```
+/// use algorist::geom::{calipers::diameter, point::Point2};
+///
+/// let tri = [(0, 0), (5, 1), (1, 3)].map(Point2::from);
+/// assert_eq!(diameter(&tri), Some((26, 0, 1)));
+/// assert_eq!(diameter::<i32>(&[]), None);
+/// ```
+pub fn diameter<T: Copy + Into<i128>>(poly: &[Point2<T>]) -> Option<(i128, usize, usize)> {
+    match poly.len() {
+        0 => return None,
+        1 => return Some((0, 0, 0)),
+        2 => return Some((dist2(poly[0], poly[1]), 0, 1)),
+        _ => {}
+    }
+    let mut best = (0, 0, 0);
+    antipodal(poly, |i, j| {
+        for k in [i, (i + 1) % poly.len()] {
+            let d = dist2(poly[k], poly[j]);
+            if d > best.0 {
+                best = (d, k.min(j), k.max(j));
+            }
+        }
+    });
+    Some(best)
+}
+
+/// Returns the width of the convex polygon: the minimum distance between two
+/// parallel lines enclosing it. Polygons with fewer than 3 vertices have zero
+/// width.
+///
+/// # Example
+///
+/// ```
+/// use algorist::geom::{calipers::width, point::Point2};
+///
+/// let tri = [(0, 0), (4, 0), (0, 3)].map(Point2::from);
+/// assert_eq!(width(&tri), 2.4); // the height onto the hypotenuse
+/// ```
+pub fn width<T: Copy + Into<i128>>(poly: &[Point2<T>]) -> f64 {
+    if poly.len() < 3 {
+        return 0.0;
+    }
+    let mut best = f64::INFINITY;
+    antipodal(poly, |i, j| {
+        let (a, b) = (poly[i], poly[(i + 1) % poly.len()]);
+        let h = cross3(a, b, poly[j]) as f64 / (dist2(a, b) as f64).sqrt();
+        best = best.min(h);
+    });
+    best
+}
+
+/// Returns twice the maximum area of a triangle with vertices on the convex
+/// polygon, with the (increasing) indices of its vertices, or `None` if the
+/// polygon has fewer than 3 vertices.
+///
+/// # Example
+///
+/// ```
+/// use algorist::geom::{calipers::max_area_triangle, point::Point2};
+///
+/// let hexagon = [(2, 0), (4, 0), (5, 2), (4, 4), (2, 4), (1, 2)].map(Point2::from);
+/// assert_eq!(max_area_triangle(&hexagon), Some((12, [0, 2, 4])));
+/// ```
+pub fn max_area_triangle<T: Copy + Into<i128>>(poly: &[Point2<T>]) -> Option<(i128, [usize; 3])> {
+    let n = poly.len();
+    if n < 3 {
+        return None;
+    }
+    let area = |i: usize, j: usize, k: usize| cross3(poly[i], poly[j], poly[k]);
+    let mut best = (0, [0, 1, 2]);
+    for i in 0..n {
+        // For the fixed `i`, the best `k` only moves forward as `j` does.
+        let mut k = i + 2;
+        for j in i + 1..n - 1 {
+            k = k.max(j + 1);
+            while k + 1 < n && area(i, j, k + 1) >= area(i, j, k) {
+                k += 1;
+            }
+            if area(i, j, k) > best.0 {
+                best = (area(i, j, k), [i, j, k]);
+            }
+        }
+    }
+    Some(best)
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{geom::hull::convex_hull, misc::rng::Rng},
+    };
+
+    #[test]
+    fn test_random() {
+        let mut rng = Rng::new(11);
+        for _ in 0..300 {
+            let n = rng.gen_range(1..25);
+            let pts: Vec<Point2<i64>> = (0..n)
+                .map(|_| Point2::new(rng.gen_range(-20..=20), rng.gen_range(-20..=20)))
+                .collect();
+            let hull = convex_hull(&pts, false);
+            let m = hull.len();
+
+            let (d, i, j) = diameter(&hull).unwrap();
+            assert_eq!(dist2(hull[i], hull[j]), d);
+            let want = pts
+                .iter()
+                .flat_map(|&a| pts.iter().map(move |&b| dist2(a, b)))
+                .max()
+                .unwrap();
+            assert_eq!(d, want);
+
+            if m < 3 {
+                assert_eq!(width(&hull), 0.0);
+                assert_eq!(max_area_triangle(&hull), None);
+                continue;
+            }
+            // Width is attained at one of the edges.
+            let want = (0..m)
+                .map(|i| {
+                    let (a, b) = (hull[i], hull[(i + 1) % m]);
+                    let far = hull.iter().map(|&p| cross3(a, b, p)).max().unwrap();
+                    far as f64 / (dist2(a, b) as f64).sqrt()
+                })
+                .fold(f64::INFINITY, f64::min);
+            assert!((width(&hull) - want).abs() < 1e-9);
+
+            let (area, [i, j, k]) = max_area_triangle(&hull).unwrap();
+            assert!(i < j && j < k);
+            assert_eq!(cross3(hull[i], hull[j], hull[k]), area);
+            let mut want = 0;
+            for a in &pts {
+                for b in &pts {
+                    for c in &pts {
+                        want = want.max(cross3(*a, *b, *c));
+                    }
+                }
+            }
+            assert_eq!(area, want);
+        }
+    }
+}
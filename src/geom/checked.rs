@@ -0,0 +1,216 @@
+//! Cross and dot products, and orientation test, guarded against overflow.
+//!
+//! Products of coordinates overflow easily: with coordinates up to `1e9`, the
+//! cross product doesn't fit into `i32`, and with coordinates up to `4e9` not
+//! even into `i64`. Such overflow is silent in release builds, and results in
+//! wrong hulls and intersections.
+//!
+//! Functions in this module return the type of coordinates, but in debug
+//! builds (i.e. during local testing) compute the result in `i128` and assert
+//! that it fits into that type. In release builds, they compute directly in
+//! the type of coordinates, so there is no overhead.
+//!
+//! The [`orientation`] test only needs the sign of the cross product, so it is
+//! always computed exactly in `i128`.
+//!
+//! # Example
+//!
+//! ```
+//! use {
+//!     algorist::geom::checked::{cross, dot, orientation},
+//!     std::cmp::Ordering,
+//! };
+//!
+//! assert_eq!(cross((1, 0), (0, 1)), 1);
+//! assert_eq!(dot((1, 2), (3, 4)), 11);
+//!
+//! // Coordinates fit into `i32`, but their products don't: still exact.
+//! let (a, b, c) = ((0, 0), (2_000_000_000, 1), (1, 2_000_000_000));
+//! assert_eq!(orientation(a, b, c), Ordering::Greater);
+//! ```
+//!
+//! In debug builds, the overflow is reported:
+//!
+//! ``` should_panic
+//! use algorist::geom::checked::cross;
+//!
+//! # if !cfg!(debug_assertions) { panic!("Overflow") }
+//! let _ = cross((2_000_000_000i32, 0), (0, 2));
+//! ```
+
+use {crate::math::Number, std::cmp::Ordering};
+
+/// Converts the exact `i128` result into `T`, asserting that it fits.
+fn fit<T: TryFrom<i128>>(r: i128) -> T {
+    T::try_from(r).unwrap_or_else(|_| panic!("Overflow: {r} doesn't fit into the coordinate type"))
+}
+
+/// Returns the cross product of vectors `a` and `b`, i.e. `a.0 * b.1 - a.1 *
+/// b.0`.
+///
+/// Positive if `b` is counter-clockwise from `a`, negative if clockwise, and
+/// zero if vectors are collinear.
+///
+/// # Panics
+///
+/// In debug builds, panics if the result doesn't fit into `T`.
+///
+/// # Example
+///
+/// ```
+/// use algorist::geom::checked::cross;
+///
+/// assert_eq!(cross((2, 0), (0, 3)), 6);
+/// assert_eq!(cross((0, 3), (2, 0)), -6);
+/// assert_eq!(cross((1, 2), (2, 4)), 0);
+/// ```
+pub fn cross<T>(a: (T, T), b: (T, T)) -> T
+where
+    T: Number + Into<i128> + TryFrom<i128>,
+{
+    if cfg!(debug_assertions) {
+        fit(a.0.into() * b.1.into() - a.1.into() * b.0.into())
+    } else {
+        a.0 * b.1 - a.1 * b.0
+    }
+}
+
+/// Returns the cross product of vectors `o -> a` and `o -> b`.
+///
+/// Positive if `o, a, b` make a counter-clockwise turn, negative if clockwise,
+/// and zero if points are collinear. Equals twice the signed area of the
+/// triangle `o, a, b`.
+///
+/// # Panics
+///
+/// In debug builds, panics if the result (or some intermediate difference)
+/// doesn't fit into `T`.
+///
+/// # Example
+///
+/// ```
+/// use algorist::geom::checked::cross3;
+///
+/// assert_eq!(cross3((1, 1), (3, 1), (1, 4)), 6);
+/// assert_eq!(cross3((0, 0), (1, 1), (2, 2)), 0);
+/// ```
+pub fn cross3<T>(o: (T, T), a: (T, T), b: (T, T)) -> T
+where
+    T: Number + Into<i128> + TryFrom<i128>,
+{
+    if cfg!(debug_assertions) {
+        let (o0, o1) = (o.0.into(), o.1.into());
+        let (a0, a1) = (a.0.into() - o0, a.1.into() - o1);
+        let (b0, b1) = (b.0.into() - o0, b.1.into() - o1);
+        let _: (T, T, T, T) = (fit(a0), fit(a1), fit(b0), fit(b1));
+        fit(a0 * b1 - a1 * b0)
+    } else {
+        cross((a.0 - o.0, a.1 - o.1), (b.0 - o.0, b.1 - o.1))
+    }
+}
+
+/// Returns the dot product of vectors `a` and `b`.
+///
+/// # Panics
+///
+/// In debug builds, panics if the result doesn't fit into `T`.
+///
+/// # Example
+///
+/// ```
+/// use algorist::geom::checked::dot;
+///
+/// assert_eq!(dot((1, 2), (3, 4)), 11);
+/// assert_eq!(dot((1, 0), (0, 5)), 0);
+/// ```
+pub fn dot<T>(a: (T, T), b: (T, T)) -> T
+where
+    T: Number + Into<i128> + TryFrom<i128>,
+{
+    if cfg!(debug_assertions) {
+        fit(a.0.into() * b.0.into() + a.1.into() * b.1.into())
+    } else {
+        a.0 * b.0 + a.1 * b.1
+    }
+}
+
+/// Returns the orientation of the triangle `a, b, c`: [`Ordering::Greater`]
+/// for a counter-clockwise turn, [`Ordering::Less`] for a clockwise turn, and
+/// [`Ordering::Equal`] if points are collinear.
+///
+/// Always computed exactly in `i128`, so it never overflows for coordinates up
+/// to `2^62` in absolute value.
+///
+/// # Example
+///
+/// ```
+/// use {algorist::geom::checked::orientation, std::cmp::Ordering};
+///
+/// assert_eq!(orientation((0, 0), (1, 0), (0, 1)), Ordering::Greater);
+/// assert_eq!(orientation((0, 0), (0, 1), (1, 0)), Ordering::Less);
+/// assert_eq!(orientation((0, 0), (1, 1), (5, 5)), Ordering::Equal);
+///
+/// let m = i64::MAX / 2;
+/// assert_eq!(orientation((-m, -m), (m, -m), (m, m)), Ordering::Greater);
+/// ```
+pub fn orientation<T: Into<i128>>(a: (T, T), b: (T, T), c: (T, T)) -> Ordering {
+    let (a0, a1) = (a.0.into(), a.1.into());
+    let (b0, b1) = (b.0.into() - a0, b.1.into() - a1);
+    let (c0, c1) = (c.0.into() - a0, c.1.into() - a1);
+    (b0 * c1 - b1 * c0).cmp(&0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_products() {
+        let pts = [(0i64, 0i64), (3, -4), (-7, 2), (5, 5), (-1, -9)];
+        for &a in &pts {
+            for &b in &pts {
+                assert_eq!(cross(a, b), a.0 * b.1 - a.1 * b.0);
+                assert_eq!(cross(a, b), -cross(b, a));
+                assert_eq!(dot(a, b), a.0 * b.0 + a.1 * b.1);
+                for &o in &pts {
+                    assert_eq!(
+                        cross3(o, a, b),
+                        cross((a.0 - o.0, a.1 - o.1), (b.0 - o.0, b.1 - o.1))
+                    );
+                    assert_eq!(orientation(o, a, b), cross3(o, a, b).cmp(&0));
+                }
+            }
+        }
+
+        // Products fitting into the type are fine, even for narrow types.
+        assert_eq!(cross((100i16, 0), (0, 300)), 30_000);
+        assert_eq!(dot((i32::MAX, 0), (1, 5)), i32::MAX);
+    }
+
+    #[test]
+    fn test_orientation_large() {
+        let m = 4_000_000_000_000_000_000i64;
+        assert_eq!(orientation((-m, -m), (m, -m), (m, m)), Ordering::Greater);
+        assert_eq!(orientation((-m, -m), (m, m), (m, -m)), Ordering::Less);
+        assert_eq!(orientation((-m, -m), (0, 0), (m, m)), Ordering::Equal);
+        // Off by one from collinear.
+        assert_eq!(orientation((-m, -m), (0, 0), (m, m - 1)), Ordering::Less);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Overflow")]
+    fn test_cross_overflow() {
+        let _ = cross(
+            (3_000_000_000i64, 3_000_000_000),
+            (-3_000_000_000, 3_000_000_000_000),
+        );
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Overflow")]
+    fn test_cross3_difference_overflow() {
+        let _ = cross3((i32::MIN, 0), (i32::MAX, 0), (0, 0));
+    }
+}
@@ -0,0 +1,330 @@
+//! Circles: intersections, tangents, and the minimal enclosing circle.
+//!
+//! Unlike the rest of the [`geom`](super) module, circle geometry can't be
+//! done in integers (intersection points are irrational in general), so
+//! everything here works with `f64` coordinates, and comparisons are done up
+//! to [`EPS`].
+//!
+//! ```
+//! use algorist::geom::{circle::Circle, point::Point2};
+//!
+//! let c = Circle::new(Point2::new(0.0, 0.0), 5.0);
+//!
+//! // Line `y = 3` crosses the circle at `x = ±4`.
+//! let pts = c.intersect_line(Point2::new(-10.0, 3.0), Point2::new(10.0, 3.0));
+//! assert_eq!(pts, vec![Point2::new(-4.0, 3.0), Point2::new(4.0, 3.0)]);
+//!
+//! let other = Circle::new(Point2::new(8.0, 0.0), 5.0);
+//! assert_eq!(c.intersect_circle(&other), vec![
+//!     Point2::new(4.0, 3.0),
+//!     Point2::new(4.0, -3.0)
+//! ]);
+//! ```
+//!
+//! # Minimal enclosing circle
+//!
+//! [`min_enclosing_circle`] finds the smallest circle containing all the
+//! given points in expected `O(n)` (Welzl's algorithm):
+//!
+//! ```
+//! use algorist::geom::{circle::min_enclosing_circle, point::Point2};
+//!
+//! let pts = [(0.0, 0.0), (4.0, 0.0), (2.0, 1.0), (1.0, -1.0)].map(Point2::from);
+//! let c = min_enclosing_circle(&pts);
+//! assert_eq!((c.center, c.r), (Point2::new(2.0, 0.0), 2.0));
+//! ```
+
+use crate::{geom::point::Point2, misc::rng::Rng};
+
+/// Absolute tolerance of comparisons (relative, for values larger than `1`).
+pub const EPS: f64 = 1e-9;
+
+fn dot(a: Point2<f64>, b: Point2<f64>) -> f64 {
+    a.x * b.x + a.y * b.y
+}
+
+fn cross(a: Point2<f64>, b: Point2<f64>) -> f64 {
+    a.x * b.y - a.y * b.x
+}
+
+fn norm(a: Point2<f64>) -> f64 {
+    a.x.hypot(a.y)
+}
+
+/// Rotates the vector by 90 degrees counter-clockwise.
+fn perp(a: Point2<f64>) -> Point2<f64> {
+    Point2::new(-a.y, a.x)
+}
+
+/// Circle on the plane.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Circle {
+    pub center: Point2<f64>,
+    pub r: f64,
+}
+
+impl Circle {
+    /// Creates a circle with the given center and radius.
+    pub fn new(center: Point2<f64>, r: f64) -> Self {
+        Self { center, r }
+    }
+
+    /// Returns the circle with the segment `a, b` as its diameter.
+    pub fn from_diameter(a: Point2<f64>, b: Point2<f64>) -> Self {
+        let center = (a + b) * 0.5;
+        Self::new(center, norm(a - center))
+    }
+
+    /// Returns the circle passing through the three points, or `None` if they
+    /// are collinear.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::geom::{circle::Circle, point::Point2};
+    ///
+    /// let (a, b, c) = (
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(6.0, 0.0),
+    ///     Point2::new(0.0, 8.0),
+    /// );
+    /// let circ = Circle::circumcircle(a, b, c).unwrap();
+    /// assert_eq!((circ.center, circ.r), (Point2::new(3.0, 4.0), 5.0));
+    ///
+    /// assert_eq!(Circle::circumcircle(a, b, Point2::new(3.0, 0.0)), None);
+    /// ```
+    pub fn circumcircle(a: Point2<f64>, b: Point2<f64>, c: Point2<f64>) -> Option<Self> {
+        let (b, c) = (b - a, c - a);
+        let d = 2.0 * cross(b, c);
+        if d.abs() <= EPS * dot(b, b).max(dot(c, c)).max(1.0) {
+            return None;
+        }
+        let (b2, c2) = (dot(b, b), dot(c, c));
+        let u = Point2::new((c.y * b2 - b.y * c2) / d, (b.x * c2 - c.x * b2) / d);
+        Some(Self::new(a + u, norm(u)))
+    }
+
+    fn eps(&self) -> f64 {
+        EPS * self.r.max(1.0)
+    }
+
+    /// Checks whether the point lies inside the circle, or on its boundary.
+    pub fn contains(&self, p: Point2<f64>) -> bool {
+        norm(p - self.center) <= self.r + self.eps()
+    }
+
+    /// Returns the intersection points of the circle and the line through `a`
+    /// and `b` (zero, one, or two of them, ordered along `a -> b`).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` and `b` coincide.
+    pub fn intersect_line(&self, a: Point2<f64>, b: Point2<f64>) -> Vec<Point2<f64>> {
+        let d = b - a;
+        let len2 = dot(d, d);
+        assert!(len2 > 0.0, "Line must be given by two distinct points");
+        // Projection of the center onto the line.
+        let h = a + d * (dot(self.center - a, d) / len2);
+        let dist = norm(self.center - h);
+        if dist > self.r + self.eps() {
+            vec![]
+        } else if dist >= self.r - self.eps() {
+            vec![h]
+        } else {
+            let off = d * ((self.r * self.r - dist * dist).sqrt() / len2.sqrt());
+            vec![h - off, h + off]
+        }
+    }
+
+    /// Returns the intersection points of two circles (zero, one, or two of
+    /// them). Concentric circles have no intersection points, even if they
+    /// coincide.
+    pub fn intersect_circle(&self, other: &Self) -> Vec<Point2<f64>> {
+        let v = other.center - self.center;
+        let d = norm(v);
+        let eps = self.eps().max(other.eps());
+        if d <= eps || d > self.r + other.r + eps || d < (self.r - other.r).abs() - eps {
+            return vec![];
+        }
+        // Distance from the center to the radical line, along `v`.
+        let a = (d * d + self.r * self.r - other.r * other.r) / (2.0 * d);
+        let base = self.center + v * (a / d);
+        let h2 = self.r * self.r - a * a;
+        if h2 <= eps * eps.max(self.r) {
+            return vec![base];
+        }
+        let off = perp(v) * (h2.sqrt() / d);
+        vec![base + off, base - off]
+    }
+
+    /// Returns the points, where the lines through `p` touch the circle: none
+    /// if `p` is inside, `p` itself if it lies on the circle, and two points
+    /// otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::geom::{circle::Circle, point::Point2};
+    ///
+    /// let c = Circle::new(Point2::new(0.0, 0.0), 3.0);
+    /// let t = c.tangents(Point2::new(5.0, 0.0));
+    /// let want = [Point2::new(1.8, 2.4), Point2::new(1.8, -2.4)];
+    /// for (p, q) in t.into_iter().zip(want) {
+    ///     assert!((p.x - q.x).abs() < 1e-9 && (p.y - q.y).abs() < 1e-9);
+    /// }
+    /// assert!(c.tangents(Point2::new(1.0, 1.0)).is_empty());
+    /// ```
+    pub fn tangents(&self, p: Point2<f64>) -> Vec<Point2<f64>> {
+        let v = p - self.center;
+        let d = norm(v);
+        if d < self.r - self.eps() {
+            vec![]
+        } else if d <= self.r + self.eps() {
+            vec![p]
+        } else {
+            let base = self.center + v * (self.r * self.r / (d * d));
+            let off = perp(v) * (self.r * (d * d - self.r * self.r).sqrt() / (d * d));
+            vec![base + off, base - off]
+        }
+    }
+}
+
+/// Returns the smallest circle containing all the points, in expected `O(n)`
+/// (Welzl's algorithm, over randomly shuffled points).
+///
+/// # Panics
+///
+/// Panics if there are no points.
+pub fn min_enclosing_circle(points: &[Point2<f64>]) -> Circle {
+    assert!(!points.is_empty(), "Points must not be empty");
+    let mut pts = points.to_vec();
+    Rng::from_time().shuffle(&mut pts);
+
+    let mut c = Circle::new(pts[0], 0.0);
+    for i in 1..pts.len() {
+        if c.contains(pts[i]) {
+            continue;
+        }
+        c = Circle::new(pts[i], 0.0);
+        for j in 0..i {
+            if c.contains(pts[j]) {
+                continue;
+            }
+            c = Circle::from_diameter(pts[i], pts[j]);
+            for k in 0..j {
+                if c.contains(pts[k]) {
+                    continue;
+                }
+                c = Circle::circumcircle(pts[i], pts[j], pts[k]).unwrap_or_else(|| {
+                    // Collinear (up to precision): the farthest pair is the
+                    // diameter.
+                    let (a, b) = [(i, j), (i, k), (j, k)]
+                        .into_iter()
+                        .max_by(|&(a, b), &(c, d)| {
+                            norm(pts[a] - pts[b]).total_cmp(&norm(pts[c] - pts[d]))
+                        })
+                        .unwrap();
+                    Circle::from_diameter(pts[a], pts[b])
+                });
+            }
+        }
+    }
+    c
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: Point2<f64>, b: Point2<f64>) -> bool {
+        norm(a - b) < 1e-6
+    }
+
+    #[test]
+    fn test_intersections() {
+        let c = Circle::new(Point2::new(1.0, 1.0), 2.0);
+        // Tangent line.
+        let pts = c.intersect_line(Point2::new(0.0, 3.0), Point2::new(5.0, 3.0));
+        assert_eq!(pts.len(), 1);
+        assert!(close(pts[0], Point2::new(1.0, 3.0)));
+        assert!(
+            c.intersect_line(Point2::new(0.0, 4.0), Point2::new(1.0, 4.0))
+                .is_empty()
+        );
+        for p in c.intersect_line(Point2::new(-3.0, -2.0), Point2::new(4.0, 5.0)) {
+            assert!((norm(p - c.center) - c.r).abs() < 1e-9);
+            assert!(cross(Point2::new(7.0, 7.0), p - Point2::new(-3.0, -2.0)).abs() < 1e-9);
+        }
+
+        let touching = Circle::new(Point2::new(4.0, 1.0), 1.0);
+        let pts = c.intersect_circle(&touching);
+        assert_eq!(pts.len(), 1);
+        assert!(close(pts[0], Point2::new(3.0, 1.0)));
+        let inside = Circle::new(Point2::new(1.5, 1.0), 0.5);
+        assert!(c.intersect_circle(&inside).is_empty());
+        assert!(c.intersect_circle(&c).is_empty());
+        let other = Circle::new(Point2::new(2.0, 3.0), 1.5);
+        let pts = c.intersect_circle(&other);
+        assert_eq!(pts.len(), 2);
+        for p in pts {
+            assert!((norm(p - c.center) - c.r).abs() < 1e-9);
+            assert!((norm(p - other.center) - other.r).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_tangents() {
+        let c = Circle::new(Point2::new(-1.0, 2.0), 1.5);
+        let p = Point2::new(3.0, -1.0);
+        let t = c.tangents(p);
+        assert_eq!(t.len(), 2);
+        for q in t {
+            assert!((norm(q - c.center) - c.r).abs() < 1e-9);
+            assert!(dot(q - c.center, q - p).abs() < 1e-9);
+        }
+        assert_eq!(c.tangents(Point2::new(0.5, 2.0)), vec![Point2::new(
+            0.5, 2.0
+        )]);
+    }
+
+    #[test]
+    fn test_min_enclosing_circle() {
+        let mut rng = Rng::new(17);
+        for _ in 0..200 {
+            let n = rng.gen_range(1..9);
+            let pts: Vec<Point2<f64>> = (0..n)
+                .map(|_| {
+                    Point2::new(
+                        rng.gen_range(-10..=10) as f64,
+                        rng.gen_range(-10..=10) as f64,
+                    )
+                })
+                .collect();
+            let c = min_enclosing_circle(&pts);
+            assert!(pts.iter().all(|&p| c.contains(p)));
+
+            // Smallest of the circles through 2 or 3 points, enclosing all.
+            let mut want = f64::INFINITY;
+            for i in 0..n {
+                for j in i..n {
+                    let mut cands = vec![Circle::from_diameter(pts[i], pts[j])];
+                    cands.extend(
+                        (j..n).filter_map(|k| Circle::circumcircle(pts[i], pts[j], pts[k])),
+                    );
+                    for cand in cands {
+                        if pts.iter().all(|&p| cand.contains(p)) {
+                            want = want.min(cand.r);
+                        }
+                    }
+                }
+            }
+            assert!((c.r - want).abs() < 1e-6, "{} != {want}", c.r);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Points must not be empty")]
+    fn test_min_enclosing_circle_empty() {
+        min_enclosing_circle(&[]);
+    }
+}
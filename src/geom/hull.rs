@@ -0,0 +1,227 @@
+//! Convex hull of a set of points.
+//!
+//! [`convex_hull`] builds the hull with Andrew's monotone chain algorithm in
+//! `O(n log n)`, and [`hull_contains`] checks whether a point lies inside the
+//! hull in `O(log n)`. All predicates are exact, see
+//! [`Point2::orientation`].
+//!
+//! ```
+//! use algorist::geom::{
+//!     hull::{convex_hull, hull_contains},
+//!     point::Point2,
+//! };
+//!
+//! let pts: Vec<_> = [(0, 0), (2, 0), (1, 1), (2, 2), (0, 2), (1, 0)]
+//!     .into_iter()
+//!     .map(Point2::from)
+//!     .collect();
+//!
+//! let hull = convex_hull(&pts, false);
+//! assert_eq!(hull, [(0, 0), (2, 0), (2, 2), (0, 2)].map(Point2::from));
+//!
+//! // Point `(1, 0)` lies on the boundary.
+//! let hull_with_collinear = convex_hull(&pts, true);
+//! assert_eq!(hull_with_collinear.len(), 5);
+//!
+//! assert!(hull_contains(&hull, Point2::new(1, 1)));
+//! assert!(hull_contains(&hull, Point2::new(2, 1))); // boundary
+//! assert!(!hull_contains(&hull, Point2::new(3, 1)));
+//! ```
+
+use {crate::geom::point::Point2, std::cmp::Ordering};
+
+/// Returns the convex hull of the points, in counter-clockwise order, starting
+/// from the lowest (by `x`, then by `y`) point.
+///
+/// If `keep_collinear` is set, points lying on the hull edges are included,
+/// otherwise only the vertices are. Duplicate points are always removed. If
+/// all points are collinear, the hull is the segment between the extreme
+/// points (with or without the points in between).
+///
+/// # Example
+///
+/// ```
+/// use algorist::geom::{hull::convex_hull, point::Point2};
+///
+/// let pts = [(0, 0), (3, 3), (1, 1), (2, 2), (1, 1)].map(Point2::from);
+/// assert_eq!(convex_hull(&pts, false), [(0, 0), (3, 3)].map(Point2::from));
+/// assert_eq!(convex_hull(&pts, true).len(), 4);
+/// ```
+pub fn convex_hull<T>(points: &[Point2<T>], keep_collinear: bool) -> Vec<Point2<T>>
+where
+    T: Copy + Ord + Into<i128>,
+{
+    let mut pts = points.to_vec();
+    pts.sort_unstable();
+    pts.dedup();
+    if pts.len() <= 2 {
+        return pts;
+    }
+    let (first, last) = (pts[0], pts[pts.len() - 1]);
+    if pts
+        .iter()
+        .all(|&p| Point2::orientation(first, last, p) == Ordering::Equal)
+    {
+        return if keep_collinear {
+            pts
+        } else {
+            vec![first, last]
+        };
+    }
+
+    // Pops the last point while it doesn't make a proper turn.
+    let bad = |hull: &[Point2<T>], p: Point2<T>| {
+        let n = hull.len();
+        n >= 2
+            && match Point2::orientation(hull[n - 2], hull[n - 1], p) {
+                Ordering::Less => true,
+                Ordering::Equal => !keep_collinear,
+                Ordering::Greater => false,
+            }
+    };
+    let mut hull: Vec<Point2<T>> = Vec::with_capacity(pts.len() + 1);
+    for &p in &pts {
+        while bad(&hull, p) {
+            hull.pop();
+        }
+        hull.push(p);
+    }
+    let lower = hull.len();
+    for &p in pts.iter().rev().skip(1) {
+        while hull.len() > lower && bad(&hull, p) {
+            hull.pop();
+        }
+        hull.push(p);
+    }
+    // The first point is pushed again at the end of the upper chain.
+    hull.pop();
+    hull
+}
+
+/// Checks whether the point lies inside the convex polygon, or on its
+/// boundary, in `O(log n)`.
+///
+/// The polygon must be given in counter-clockwise order, starting from its
+/// lowest point, and must have no collinear points on its edges, i.e. as
+/// returned by [`convex_hull`] with `keep_collinear` unset.
+///
+/// # Example
+///
+/// ```
+/// use algorist::geom::{hull::hull_contains, point::Point2};
+///
+/// let tri = [(0, 0), (4, 0), (0, 4)].map(Point2::from);
+/// assert!(hull_contains(&tri, Point2::new(1, 1)));
+/// assert!(hull_contains(&tri, Point2::new(2, 2)));
+/// assert!(!hull_contains(&tri, Point2::new(3, 2)));
+/// ```
+pub fn hull_contains<T>(hull: &[Point2<T>], p: Point2<T>) -> bool
+where
+    T: Copy + Ord + Into<i128>,
+{
+    let n = hull.len();
+    match n {
+        0 => return false,
+        1 => return hull[0] == p,
+        _ => {}
+    }
+    let o = hull[0];
+    if n == 2 {
+        let (a, b) = (hull[0], hull[1]);
+        return Point2::orientation(a, b, p) == Ordering::Equal
+            && (a.x.min(b.x)..=a.x.max(b.x)).contains(&p.x)
+            && (a.y.min(b.y)..=a.y.max(b.y)).contains(&p.y);
+    }
+    if Point2::orientation(o, hull[1], p) == Ordering::Less
+        || Point2::orientation(o, hull[n - 1], p) == Ordering::Greater
+    {
+        return false;
+    }
+    // The last vertex `i`, such that `p` is not clockwise from `o -> hull[i]`.
+    let (mut lo, mut hi) = (1, n - 1);
+    while hi - lo > 1 {
+        let mid = (lo + hi) / 2;
+        if Point2::orientation(o, hull[mid], p) == Ordering::Less {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+    Point2::orientation(hull[lo], hull[lo + 1], p) != Ordering::Less
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::misc::rng::Rng};
+
+    /// Minimum orientation of `p` over all hull edges: `Greater` if strictly
+    /// inside, `Equal` if on the boundary, and `Less` if outside.
+    fn side(hull: &[Point2<i64>], p: Point2<i64>) -> Ordering {
+        (0..hull.len())
+            .map(|i| Point2::orientation(hull[i], hull[(i + 1) % hull.len()], p))
+            .min()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_random() {
+        let mut rng = Rng::new(7);
+        for _ in 0..300 {
+            let n = rng.gen_range(3..30);
+            let pts: Vec<Point2<i64>> = (0..n)
+                .map(|_| Point2::new(rng.gen_range(-5..=5), rng.gen_range(-5..=5)))
+                .collect();
+            let hull = convex_hull(&pts, false);
+            if hull.len() < 3 {
+                continue;
+            }
+            assert_eq!(hull[0], *pts.iter().min().unwrap());
+            for i in 0..hull.len() {
+                let (a, b, c) = (
+                    hull[i],
+                    hull[(i + 1) % hull.len()],
+                    hull[(i + 2) % hull.len()],
+                );
+                assert_eq!(Point2::orientation(a, b, c), Ordering::Greater);
+            }
+            for &p in &pts {
+                assert_ne!(side(&hull, p), Ordering::Less);
+            }
+
+            let mut with_collinear = convex_hull(&pts, true);
+            assert_eq!(with_collinear[0], hull[0]);
+            let mut boundary: Vec<_> = pts
+                .iter()
+                .copied()
+                .filter(|&p| side(&hull, p) == Ordering::Equal)
+                .collect();
+            boundary.sort();
+            boundary.dedup();
+            with_collinear.sort();
+            assert_eq!(with_collinear, boundary);
+
+            for x in -6..=6 {
+                for y in -6..=6 {
+                    let p = Point2::new(x, y);
+                    assert_eq!(hull_contains(&hull, p), side(&hull, p) != Ordering::Less);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_degenerate() {
+        assert!(convex_hull::<i32>(&[], true).is_empty());
+        let single = convex_hull(&[Point2::new(1, 1); 3], false);
+        assert_eq!(single, vec![Point2::new(1, 1)]);
+        assert!(hull_contains(&single, Point2::new(1, 1)));
+        assert!(!hull_contains(&single, Point2::new(1, 2)));
+
+        let seg = convex_hull(&[(2, 2), (0, 0), (1, 1)].map(Point2::from), false);
+        assert_eq!(seg, [(0, 0), (2, 2)].map(Point2::from));
+        assert!(hull_contains(&seg, Point2::new(1, 1)));
+        assert!(!hull_contains(&seg, Point2::new(3, 3)));
+        assert!(!hull_contains(&seg, Point2::new(1, 0)));
+        assert!(!hull_contains(&[], Point2::new(0, 0)));
+    }
+}
@@ -0,0 +1,142 @@
+//! Counting lattice points on segments and in polygons.
+//!
+//! A segment between lattice points `a` and `b` contains `gcd(|dx|, |dy|) +
+//! 1` lattice points (endpoints included). For a simple polygon with lattice
+//! vertices, Pick's theorem relates its area `A` to the numbers of lattice
+//! points strictly inside (`I`) and on the boundary (`B`):
+//!
+//! ```text
+//! A = I + B / 2 - 1
+//! ```
+//!
+//! All counts are exact, and computed in `i128`.
+//!
+//! ```
+//! use algorist::geom::{lattice, point::Point2};
+//!
+//! assert_eq!(
+//!     lattice::segment_points(Point2::new(0, 0), Point2::new(6, 4)),
+//!     3
+//! );
+//!
+//! let tri = [(0, 0), (4, 0), (0, 4)].map(Point2::from);
+//! assert_eq!(lattice::boundary_points(&tri), 12);
+//! assert_eq!(lattice::interior_points(&tri), 3);
+//! ```
+
+use crate::{
+    geom::{point::Point2, polygon::area2},
+    math::gcd::gcd,
+};
+
+/// Returns the number of lattice points on the segment `a, b`, including the
+/// endpoints.
+///
+/// # Example
+///
+/// ```
+/// use algorist::geom::{lattice::segment_points, point::Point2};
+///
+/// assert_eq!(segment_points(Point2::new(1, 1), Point2::new(1, 1)), 1);
+/// assert_eq!(segment_points(Point2::new(-3, 2), Point2::new(3, -7)), 4);
+/// ```
+pub fn segment_points<T: Copy + Into<i128>>(a: Point2<T>, b: Point2<T>) -> i128 {
+    edge_points(a, b) + 1
+}
+
+/// Number of lattice points on the segment, excluding one of the endpoints.
+fn edge_points<T: Copy + Into<i128>>(a: Point2<T>, b: Point2<T>) -> i128 {
+    let dx = (b.x.into() - a.x.into()).abs();
+    let dy = (b.y.into() - a.y.into()).abs();
+    gcd(dx, dy)
+}
+
+/// Returns the number of lattice points on the boundary of the polygon.
+///
+/// # Example
+///
+/// ```
+/// use algorist::geom::{lattice::boundary_points, point::Point2};
+///
+/// let square = [(0, 0), (3, 0), (3, 3), (0, 3)].map(Point2::from);
+/// assert_eq!(boundary_points(&square), 12);
+/// ```
+pub fn boundary_points<T: Copy + Into<i128>>(poly: &[Point2<T>]) -> i128 {
+    (0..poly.len())
+        .map(|i| edge_points(poly[i], poly[(i + 1) % poly.len()]))
+        .sum()
+}
+
+/// Returns the number of lattice points strictly inside the simple polygon
+/// (given in either orientation), by Pick's theorem.
+///
+/// # Example
+///
+/// ```
+/// use algorist::geom::{lattice::interior_points, point::Point2};
+///
+/// let square = [(0, 0), (3, 0), (3, 3), (0, 3)].map(Point2::from);
+/// assert_eq!(interior_points(&square), 4);
+/// ```
+pub fn interior_points<T: Copy + Into<i128>>(poly: &[Point2<T>]) -> i128 {
+    if poly.len() < 3 {
+        return 0;
+    }
+    // 2A = 2I + B - 2
+    (area2(poly).abs() - boundary_points(poly) + 2) / 2
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::geom::polygon::{Location, point_in_polygon},
+    };
+
+    #[test]
+    fn test_against_brute_force() {
+        let polys = [
+            vec![
+                (0, 0),
+                (5, 0),
+                (5, 3),
+                (4, 3),
+                (4, 1),
+                (1, 1),
+                (1, 3),
+                (0, 3),
+            ],
+            vec![(0, 0), (7, 2), (3, 9)],
+            vec![(-4, -4), (4, -3), (1, 0), (5, 6), (-2, 5)],
+            vec![(6, 0), (0, 6), (-6, 0), (0, -6)],
+        ];
+        for poly in polys {
+            let poly: Vec<Point2<i64>> = poly.into_iter().map(Point2::from).collect();
+            let (mut inside, mut boundary) = (0, 0);
+            for x in -10..=10 {
+                for y in -10..=10 {
+                    match point_in_polygon(&poly, Point2::new(x, y)) {
+                        Location::Inside => inside += 1,
+                        Location::Boundary => boundary += 1,
+                        Location::Outside => {}
+                    }
+                }
+            }
+            assert_eq!(interior_points(&poly), inside, "{poly:?}");
+            assert_eq!(boundary_points(&poly), boundary, "{poly:?}");
+        }
+    }
+
+    #[test]
+    fn test_large() {
+        let m = i64::MAX;
+        assert_eq!(
+            segment_points(Point2::new(-m, -m), Point2::new(m, m)),
+            2 * i128::from(m) + 1
+        );
+        let square = [(0, 0), (m, 0), (m, m), (0, m)].map(Point2::from);
+        let side = i128::from(m);
+        assert_eq!(interior_points(&square), (side - 1) * (side - 1));
+        assert_eq!(interior_points(&[Point2::new(0, 0), Point2::new(5, 5)]), 0);
+    }
+}
@@ -0,0 +1,26 @@
+//! Computational geometry.
+//!
+//! Points are integer coordinates, and predicates are exact, so that no
+//! floating point precision issues arise.
+//!
+//! Currently, this module contains:
+//!
+//! | Module | Description
+//! | --- | ---
+//! | [`angle`] | Exact comparison of vectors by polar angle, and sorting points around a pivot.
+//! | [`calipers`] | Rotating calipers: diameter, width and the largest triangle of a convex polygon.
+//! | [`checked`] | Cross/dot products and orientation test, guarded against overflow.
+//! | [`circle`] | Circle intersections and tangents, and the minimal enclosing circle (floating point).
+//! | [`hull`] | Convex hull (monotone chain), and point-in-hull test.
+//! | [`lattice`] | Lattice point counts on segments and polygons (Pick's theorem).
+//! | [`point`] | `Point2` type with vector arithmetic, and reading points with `Scanner`.
+//! | [`polygon`] | Polygon area, convexity, and point-in-polygon tests.
+
+pub mod angle;
+pub mod calipers;
+pub mod checked;
+pub mod circle;
+pub mod hull;
+pub mod lattice;
+pub mod point;
+pub mod polygon;
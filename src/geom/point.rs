@@ -0,0 +1,312 @@
+//! Points and vectors on the plane.
+//!
+//! [`Point2`] is a thin wrapper over a pair of coordinates, which adds vector
+//! arithmetic, and exposes the overflow-guarded predicates of the
+//! [`checked`](super::checked) module as methods.
+//!
+//! ```
+//! use {algorist::geom::point::Point2, std::cmp::Ordering};
+//!
+//! let (a, b) = (Point2::new(1, 2), Point2::new(4, 6));
+//! assert_eq!(b - a, Point2::new(3, 4));
+//! assert_eq!((b - a).len2(), 25);
+//! assert_eq!(a + b * 2, Point2::new(9, 14));
+//! assert_eq!(a.dot(b), 16);
+//! assert_eq!(a.cross(b), -2);
+//!
+//! let o = Point2::new(0, 0);
+//! assert_eq!(Point2::orientation(o, a, b), Ordering::Less);
+//! ```
+//!
+//! # Reading points
+//!
+//! Points are usually given as pairs of whitespace-separated coordinates,
+//! which are read with [`Scanner::point`] and [`Scanner::points`]. Single
+//! tokens, like `3,4` or `(3,4)`, are parsed with [`FromStr`]:
+//!
+//! ```
+//! use algorist::{geom::point::Point2, io::Scanner};
+//!
+//! let input = b"2\n0 0\n-3 4\n(5,-6)\n";
+//! let mut scan = Scanner::new(input.as_ref());
+//! let n = scan.u();
+//! let pts: Vec<Point2<i64>> = scan.points(n);
+//! assert_eq!(pts, vec![Point2::new(0, 0), Point2::new(-3, 4)]);
+//! assert_eq!(scan.next::<Point2<i64>>(), Point2::new(5, -6));
+//! ```
+
+use {
+    crate::{geom::checked, io::Scanner, math::Number},
+    std::{
+        cmp::Ordering,
+        fmt,
+        io::BufRead,
+        ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+        str::FromStr,
+    },
+};
+
+/// Point (or vector) on the plane.
+///
+/// Points are ordered lexicographically, by `x` and then by `y`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point2<T> {
+    /// Creates a point with the given coordinates.
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: Number + Into<i128> + TryFrom<i128>> Point2<T> {
+    /// Returns the dot product of two vectors.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if the result doesn't fit into `T`, see
+    /// [`checked::dot`].
+    pub fn dot(self, other: Self) -> T {
+        checked::dot(self.into(), other.into())
+    }
+
+    /// Returns the cross product of two vectors: positive if `other` is
+    /// counter-clockwise from `self`, negative if clockwise, and zero if they
+    /// are collinear.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if the result doesn't fit into `T`, see
+    /// [`checked::cross`].
+    pub fn cross(self, other: Self) -> T {
+        checked::cross(self.into(), other.into())
+    }
+
+    /// Returns the cross product of vectors `self -> a` and `self -> b`, i.e.
+    /// twice the signed area of the triangle `self, a, b`.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if the result doesn't fit into `T`, see
+    /// [`checked::cross3`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::geom::point::Point2;
+    ///
+    /// let o = Point2::new(1, 1);
+    /// assert_eq!(o.cross3(Point2::new(3, 1), Point2::new(1, 4)), 6);
+    /// ```
+    pub fn cross3(self, a: Self, b: Self) -> T {
+        checked::cross3(self.into(), a.into(), b.into())
+    }
+
+    /// Returns the squared length of the vector.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if the result doesn't fit into `T`.
+    pub fn len2(self) -> T {
+        self.dot(self)
+    }
+
+    /// Returns the squared distance between two points.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if the result doesn't fit into `T`.
+    pub fn dist2(self, other: Self) -> T {
+        (other - self).len2()
+    }
+}
+
+impl<T: Into<i128>> Point2<T> {
+    /// Returns the orientation of the triangle `a, b, c`, see
+    /// [`checked::orientation`]. Computed exactly, so it never overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use {algorist::geom::point::Point2, std::cmp::Ordering};
+    ///
+    /// let m = i64::MAX / 2;
+    /// let (a, b, c) = (Point2::new(-m, -m), Point2::new(m, -m), Point2::new(m, m));
+    /// assert_eq!(Point2::orientation(a, b, c), Ordering::Greater);
+    /// assert_eq!(Point2::orientation(a, c, b), Ordering::Less);
+    /// ```
+    pub fn orientation(a: Self, b: Self, c: Self) -> Ordering {
+        checked::orientation(a.into(), b.into(), c.into())
+    }
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T> From<Point2<T>> for (T, T) {
+    fn from(p: Point2<T>) -> Self {
+        (p.x, p.y)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point2<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point2<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point2<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+/// Scales the vector by a factor.
+impl<T: Copy + Mul<Output = T>> Mul<T> for Point2<T> {
+    type Output = Self;
+
+    fn mul(self, k: T) -> Self {
+        Self::new(self.x * k, self.y * k)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point2<T> {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point2<T> {
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point2<T> {
+    /// Formats the point as `x y`, as usually expected in the output.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.x, self.y)
+    }
+}
+
+/// Error of parsing a [`Point2`] from a string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsePointError(String);
+
+impl fmt::Display for ParsePointError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Invalid point: {}", self.0)
+    }
+}
+
+impl<T: FromStr> FromStr for Point2<T> {
+    type Err = ParsePointError;
+
+    /// Parses a point given as `x,y` or `(x,y)`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParsePointError(s.to_string());
+        let inner = s
+            .strip_prefix('(')
+            .and_then(|t| t.strip_suffix(')'))
+            .unwrap_or(s);
+        let (x, y) = inner.split_once(',').ok_or_else(err)?;
+        match (x.trim().parse(), y.trim().parse()) {
+            (Ok(x), Ok(y)) => Ok(Self::new(x, y)),
+            _ => Err(err()),
+        }
+    }
+}
+
+impl<R: BufRead> Scanner<R> {
+    /// Reads a point, given as two whitespace-separated coordinates.
+    pub fn point<T: FromStr>(&mut self) -> Point2<T> {
+        Point2::new(self.next(), self.next())
+    }
+
+    /// Reads `n` points, each given as two whitespace-separated coordinates.
+    pub fn points<T: FromStr>(&mut self, n: usize) -> Vec<Point2<T>> {
+        (0..n).map(|_| self.point()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let (a, b) = (Point2::new(3i64, -4), Point2::new(-1, 2));
+        assert_eq!(a + b, Point2::new(2, -2));
+        assert_eq!(a - b, Point2::new(4, -6));
+        assert_eq!(-a, Point2::new(-3, 4));
+        assert_eq!(a * 3, Point2::new(9, -12));
+        let mut c = a;
+        c += b;
+        c -= b * 2;
+        assert_eq!(c, a - b);
+        assert_eq!(a.len2(), 25);
+        assert_eq!(a.dist2(b), 52);
+        assert_eq!(a.dot(b), -11);
+        assert_eq!(a.cross(b), 2);
+        assert_eq!(a.cross(b), -b.cross(a));
+        assert_eq!(Point2::new(0, 0).cross3(a, b), a.cross(b));
+        assert_eq!(<(i64, i64)>::from(a), (3, -4));
+        assert_eq!(Point2::from((3, -4)), a);
+        assert_eq!(a.to_string(), "3 -4");
+    }
+
+    #[test]
+    fn test_ordering() {
+        let mut pts = vec![Point2::new(1, 2), Point2::new(0, 5), Point2::new(1, -1)];
+        pts.sort();
+        assert_eq!(pts, vec![
+            Point2::new(0, 5),
+            Point2::new(1, -1),
+            Point2::new(1, 2)
+        ]);
+        let m = 1i64 << 61;
+        let (a, b) = (Point2::new(-m, -m), Point2::new(m, m));
+        assert_eq!(
+            Point2::orientation(a, b, Point2::new(0, 0)),
+            Ordering::Equal
+        );
+        assert_eq!(
+            Point2::orientation(a, b, Point2::new(0, 1)),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("3,4".parse(), Ok(Point2::new(3, 4)));
+        assert_eq!("(-3, 4)".parse(), Ok(Point2::new(-3, 4)));
+        assert!("3".parse::<Point2<i32>>().is_err());
+        assert!("(3,x)".parse::<Point2<i32>>().is_err());
+
+        let mut scan = Scanner::new(b"1 2 3\n4".as_ref());
+        assert_eq!(scan.points::<u8>(2), vec![
+            Point2::new(1, 2),
+            Point2::new(3, 4)
+        ]);
+    }
+}
@@ -0,0 +1,349 @@
+//! Simple polygons: area, convexity, and point location.
+//!
+//! A polygon is a slice of its vertices, in order (either clockwise or
+//! counter-clockwise), with the last vertex connected to the first one. All
+//! computations, except for the [`perimeter`], are exact: intermediate values
+//! are computed in `i128`.
+//!
+//! ```
+//! use algorist::geom::{
+//!     point::Point2,
+//!     polygon::{Location, area2, is_convex, point_in_polygon},
+//! };
+//!
+//! // An "L"-shaped polygon, counter-clockwise.
+//! let poly = [(0, 0), (2, 0), (2, 1), (1, 1), (1, 2), (0, 2)].map(Point2::from);
+//! assert_eq!(area2(&poly), 6); // area is 3
+//! assert!(!is_convex(&poly));
+//!
+//! assert_eq!(
+//!     point_in_polygon(&poly, Point2::new(0, 1)),
+//!     Location::Boundary
+//! );
+//! assert_eq!(
+//!     point_in_polygon(&poly, Point2::new(2, 2)),
+//!     Location::Outside
+//! );
+//! ```
+//!
+//! For self-intersecting polygons, [`point_in_polygon`] uses the even-odd rule,
+//! while [`winding_number`] tells how many times the polygon winds around the
+//! point.
+
+use {crate::geom::point::Point2, std::cmp::Ordering};
+
+/// Location of a point relative to a polygon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Location {
+    Inside,
+    Boundary,
+    Outside,
+}
+
+fn to_i128<T: Into<i128>>(p: Point2<T>) -> (i128, i128) {
+    (p.x.into(), p.y.into())
+}
+
+/// Iterates over the edges of the polygon, as pairs of endpoints.
+fn edges<T: Copy>(poly: &[Point2<T>]) -> impl Iterator<Item = (Point2<T>, Point2<T>)> + '_ {
+    poly.iter()
+        .zip(poly.iter().cycle().skip(1))
+        .map(|(&a, &b)| (a, b))
+}
+
+/// Checks whether the point lies on the segment `a, b`.
+fn on_segment<T: Copy + Into<i128>>(a: Point2<T>, b: Point2<T>, p: Point2<T>) -> bool {
+    let ((ax, ay), (bx, by), (px, py)) = (to_i128(a), to_i128(b), to_i128(p));
+    Point2::orientation(a, b, p) == Ordering::Equal
+        && ax.min(bx) <= px
+        && px <= ax.max(bx)
+        && ay.min(by) <= py
+        && py <= ay.max(by)
+}
+
+/// Returns twice the signed area of the polygon: positive if vertices are
+/// given counter-clockwise, and negative if clockwise.
+///
+/// The result is exact, since the area of a polygon with integer vertices is
+/// a multiple of `1/2`.
+///
+/// # Example
+///
+/// ```
+/// use algorist::geom::{point::Point2, polygon::area2};
+///
+/// let tri = [(0, 0), (3, 0), (0, 3)].map(Point2::from);
+/// assert_eq!(area2(&tri), 9);
+/// assert_eq!(area2(&[tri[0], tri[2], tri[1]]), -9);
+/// ```
+pub fn area2<T: Copy + Into<i128>>(poly: &[Point2<T>]) -> i128 {
+    edges(poly)
+        .map(|(a, b)| {
+            let ((ax, ay), (bx, by)) = (to_i128(a), to_i128(b));
+            ax * by - ay * bx
+        })
+        .sum()
+}
+
+/// Returns the perimeter of the polygon.
+///
+/// # Example
+///
+/// ```
+/// use algorist::geom::{point::Point2, polygon::perimeter};
+///
+/// let tri = [(0, 0), (3, 0), (3, 4)].map(Point2::from);
+/// assert_eq!(perimeter(&tri), 12.0);
+/// ```
+pub fn perimeter<T: Copy + Into<i128>>(poly: &[Point2<T>]) -> f64 {
+    edges(poly)
+        .map(|(a, b)| {
+            let ((ax, ay), (bx, by)) = (to_i128(a), to_i128(b));
+            ((bx - ax) as f64).hypot((by - ay) as f64)
+        })
+        .sum()
+}
+
+/// Checks whether the polygon is convex (in either orientation).
+///
+/// Collinear consecutive vertices are allowed. Polygons with no area (e.g.
+/// with fewer than 3 vertices) are not considered convex, neither are
+/// self-intersecting ones.
+///
+/// # Example
+///
+/// ```
+/// use algorist::geom::{point::Point2, polygon::is_convex};
+///
+/// let square = [(0, 0), (1, 0), (2, 0), (2, 2), (0, 2)].map(Point2::from);
+/// assert!(is_convex(&square));
+///
+/// let pentagram = [(0, 4), (2, -3), (-4, 1), (4, 1), (-2, -3)].map(Point2::from);
+/// assert!(!is_convex(&pentagram));
+/// ```
+pub fn is_convex<T: Copy + Into<i128>>(poly: &[Point2<T>]) -> bool {
+    let n = poly.len();
+    if n < 3 {
+        return false;
+    }
+    let (mut ccw, mut cw) = (false, false);
+    for i in 0..n {
+        match Point2::orientation(poly[i], poly[(i + 1) % n], poly[(i + 2) % n]) {
+            Ordering::Greater => ccw = true,
+            Ordering::Less => cw = true,
+            Ordering::Equal => {}
+        }
+    }
+    if ccw == cw {
+        return false;
+    }
+    // Turning consistently, the polygon winds around once iff the direction of
+    // its edges along `x` changes exactly twice.
+    let dirs: Vec<Ordering> = edges(poly)
+        .map(|(a, b)| to_i128(b).0.cmp(&to_i128(a).0))
+        .filter(|&d| d != Ordering::Equal)
+        .collect();
+    let changes = (0..dirs.len())
+        .filter(|&i| dirs[i] != dirs[(i + 1) % dirs.len()])
+        .count();
+    changes == 2
+}
+
+/// Returns how many times the polygon winds counter-clockwise around the
+/// point (negative for clockwise), or `None` if the point lies on the
+/// boundary.
+///
+/// The winding number is `0` for points outside of the polygon, and `±1` for
+/// points inside of a simple polygon.
+///
+/// # Example
+///
+/// ```
+/// use algorist::geom::{point::Point2, polygon::winding_number};
+///
+/// let pentagram = [(0, 4), (2, -3), (-4, 1), (4, 1), (-2, -3)].map(Point2::from);
+/// assert_eq!(winding_number(&pentagram, Point2::new(0, 0)), Some(-2));
+/// assert_eq!(winding_number(&pentagram, Point2::new(0, 3)), Some(-1));
+/// assert_eq!(winding_number(&pentagram, Point2::new(5, 5)), Some(0));
+/// assert_eq!(winding_number(&pentagram, Point2::new(0, 4)), None);
+/// ```
+pub fn winding_number<T: Copy + Into<i128>>(poly: &[Point2<T>], p: Point2<T>) -> Option<i32> {
+    let py = p.y.into();
+    let mut wn = 0;
+    for (a, b) in edges(poly) {
+        if on_segment(a, b, p) {
+            return None;
+        }
+        let (ay, by) = (a.y.into(), b.y.into());
+        let side = Point2::orientation(a, b, p);
+        if ay <= py && by > py && side == Ordering::Greater {
+            wn += 1;
+        } else if ay > py && by <= py && side == Ordering::Less {
+            wn -= 1;
+        }
+    }
+    Some(wn)
+}
+
+/// Locates the point relative to the polygon, by casting a ray from it, and
+/// counting crossings with the polygon edges (the even-odd rule).
+///
+/// # Example
+///
+/// ```
+/// use algorist::geom::{
+///     point::Point2,
+///     polygon::{Location, point_in_polygon},
+/// };
+///
+/// let pentagram = [(0, 4), (2, -3), (-4, 1), (4, 1), (-2, -3)].map(Point2::from);
+/// assert_eq!(
+///     point_in_polygon(&pentagram, Point2::new(0, 3)),
+///     Location::Inside
+/// );
+/// // Wound around twice, so the center is outside by the even-odd rule.
+/// assert_eq!(
+///     point_in_polygon(&pentagram, Point2::new(0, 0)),
+///     Location::Outside
+/// );
+/// ```
+pub fn point_in_polygon<T: Copy + Into<i128>>(poly: &[Point2<T>], p: Point2<T>) -> Location {
+    let py = p.y.into();
+    let mut inside = false;
+    for (a, b) in edges(poly) {
+        if on_segment(a, b, p) {
+            return Location::Boundary;
+        }
+        let (ay, by) = (a.y.into(), b.y.into());
+        // The edge crosses the horizontal line through `p`, to the right of it.
+        if (ay > py) != (by > py) {
+            let side = Point2::orientation(a, b, p);
+            if (by > ay) == (side == Ordering::Greater) {
+                inside = !inside;
+            }
+        }
+    }
+    if inside {
+        Location::Inside
+    } else {
+        Location::Outside
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            geom::hull::{convex_hull, hull_contains},
+            misc::rng::Rng,
+        },
+    };
+
+    fn poly(pts: &[(i64, i64)]) -> Vec<Point2<i64>> {
+        pts.iter().map(|&p| Point2::from(p)).collect()
+    }
+
+    #[test]
+    fn test_area_and_perimeter() {
+        let square = poly(&[(0, 0), (4, 0), (4, 4), (0, 4)]);
+        assert_eq!(area2(&square), 32);
+        assert_eq!(perimeter(&square), 16.0);
+        let rev: Vec<_> = square.iter().rev().copied().collect();
+        assert_eq!(area2(&rev), -32);
+        assert_eq!(area2::<i64>(&[]), 0);
+
+        // Exact, even if the products overflow `i64`.
+        let m = i64::MAX / 2;
+        let big = poly(&[(-m, -m), (m, -m), (m, m), (-m, m)]);
+        assert_eq!(area2(&big), 8 * i128::from(m) * i128::from(m));
+    }
+
+    #[test]
+    fn test_is_convex() {
+        assert!(is_convex(&poly(&[(0, 0), (1, 0), (0, 1)])));
+        assert!(is_convex(&poly(&[(0, 0), (0, 1), (1, 0)])));
+        assert!(!is_convex(&poly(&[(0, 0), (1, 1), (2, 2)])));
+        assert!(!is_convex(&poly(&[(0, 0), (1, 1)])));
+        assert!(!is_convex(&poly(&[(0, 0), (2, 0), (1, 1), (2, 2), (0, 2)])));
+    }
+
+    #[test]
+    fn test_point_location() {
+        // Comb with two teeth.
+        let comb = poly(&[
+            (0, 0),
+            (5, 0),
+            (5, 3),
+            (4, 3),
+            (4, 1),
+            (1, 1),
+            (1, 3),
+            (0, 3),
+        ]);
+        let cases = [
+            ((2, 2), Location::Outside),
+            ((2, 1), Location::Boundary),
+            ((4, 3), Location::Boundary),
+            ((0, 2), Location::Boundary),
+            ((3, 0), Location::Boundary),
+            ((2, 0), Location::Boundary),
+            ((6, 0), Location::Outside),
+            ((-1, 3), Location::Outside),
+            ((6, 3), Location::Outside),
+        ];
+        for (p, want) in cases {
+            let p = Point2::from(p);
+            assert_eq!(point_in_polygon(&comb, p), want, "{p}");
+        }
+        // Points with half-integer coordinates, scaled by 2.
+        let comb2: Vec<_> = comb.iter().map(|&p| p * 2).collect();
+        for x in -1..=11 {
+            for y in -1..=7 {
+                let p = Point2::new(x, y);
+                let want = match winding_number(&comb2, p) {
+                    None => Location::Boundary,
+                    Some(0) => Location::Outside,
+                    Some(wn) => {
+                        assert_eq!(wn, 1);
+                        Location::Inside
+                    }
+                };
+                assert_eq!(point_in_polygon(&comb2, p), want, "{p}");
+            }
+        }
+        assert_eq!(
+            point_in_polygon(&comb2, Point2::new(1, 5)),
+            Location::Inside
+        );
+    }
+
+    #[test]
+    fn test_random_convex() {
+        let mut rng = Rng::new(3);
+        for _ in 0..100 {
+            let pts: Vec<Point2<i64>> = (0..10)
+                .map(|_| Point2::new(rng.gen_range(-5..=5), rng.gen_range(-5..=5)))
+                .collect();
+            let hull = convex_hull(&pts, false);
+            if hull.len() < 3 {
+                continue;
+            }
+            assert!(is_convex(&hull));
+            assert!(area2(&hull) > 0);
+            for x in -6..=6 {
+                for y in -6..=6 {
+                    let p = Point2::new(x, y);
+                    let loc = point_in_polygon(&hull, p);
+                    assert_eq!(hull_contains(&hull, p), loc != Location::Outside);
+                    let want = match loc {
+                        Location::Inside => Some(1),
+                        Location::Boundary => None,
+                        Location::Outside => Some(0),
+                    };
+                    assert_eq!(winding_number(&hull, p), want);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,163 @@
+//! Graph export for local debugging.
+//!
+//! Functions in this module produce [Graphviz](https://graphviz.org) DOT text,
+//! which can be rendered with `dot -Tpng graph.dot -o graph.png` (or pasted
+//! into any online viewer), to eyeball a graph on which a stress test failed.
+//!
+//! The module is only available with the `local` feature, so that debugging
+//! helpers never end up in a submission.
+//!
+//! ```
+//! use algorist::graph::debug::{to_dot, tree_to_dot};
+//!
+//! let adj = vec![vec![1, 2], vec![0], vec![0]];
+//! assert_eq!(
+//!     to_dot(&adj, false),
+//!     "graph {\n  0;\n  1;\n  2;\n  0 -- 1;\n  0 -- 2;\n}\n"
+//! );
+//!
+//! let parent = vec![None, Some(0), Some(0)];
+//! assert_eq!(tree_to_dot(&parent), to_dot(&adj, false));
+//! ```
+
+use std::fmt::{Display, Write};
+
+/// Returns DOT text of a graph, given as adjacency lists.
+///
+/// For an undirected graph, every edge `{u, v}` is expected in both `adj[u]`
+/// and `adj[v]`, and is printed once. All the vertices are printed, so that
+/// isolated ones are visible too.
+///
+/// # Example
+///
+/// ```
+/// use algorist::graph::debug::to_dot;
+///
+/// let adj = vec![vec![1], vec![2], vec![0], vec![]];
+/// assert_eq!(
+///     to_dot(&adj, true),
+///     "digraph {\n  0;\n  1;\n  2;\n  3;\n  0 -> 1;\n  1 -> 2;\n  2 -> 0;\n}\n"
+/// );
+/// ```
+pub fn to_dot(adj: &[Vec<usize>], directed: bool) -> String {
+    render(adj.len(), directed, |u| {
+        adj[u]
+            .iter()
+            .filter(move |&&v| directed || u <= v)
+            .map(|&v| (v, None::<u8>))
+    })
+}
+
+/// Returns DOT text of a weighted graph, given as adjacency lists of `(vertex,
+/// weight)` pairs. Weights are shown as edge labels.
+///
+/// # Example
+///
+/// ```
+/// use algorist::graph::debug::weighted_to_dot;
+///
+/// let adj = vec![vec![(1, 5)], vec![(0, 5)]];
+/// assert_eq!(
+///     weighted_to_dot(&adj, false),
+///     "graph {\n  0;\n  1;\n  0 -- 1 [label=\"5\"];\n}\n"
+/// );
+/// ```
+pub fn weighted_to_dot<W: Display>(adj: &[Vec<(usize, W)>], directed: bool) -> String {
+    render(adj.len(), directed, |u| {
+        adj[u]
+            .iter()
+            .filter(move |(v, _)| directed || u <= *v)
+            .map(|(v, w)| (*v, Some(w)))
+    })
+}
+
+/// Returns DOT text of a rooted forest, given as a parent array (`None` for
+/// roots).
+///
+/// # Panics
+///
+/// Panics if some parent is out of bounds.
+///
+/// # Example
+///
+/// ```
+/// use algorist::graph::debug::tree_to_dot;
+///
+/// let parent = vec![Some(2), Some(2), None];
+/// assert_eq!(
+///     tree_to_dot(&parent),
+///     "graph {\n  0;\n  1;\n  2;\n  2 -- 0;\n  2 -- 1;\n}\n"
+/// );
+/// ```
+pub fn tree_to_dot(parent: &[Option<usize>]) -> String {
+    let mut children = vec![vec![]; parent.len()];
+    for (v, p) in parent.iter().enumerate() {
+        if let Some(p) = *p {
+            assert!(
+                p < parent.len(),
+                "Parent {p} of vertex {v} is out of bounds"
+            );
+            children[p].push(v);
+        }
+    }
+    // Edges are printed parent first, so `dot` places parents above children.
+    render(parent.len(), false, |u| {
+        children[u].iter().map(|&v| (v, None::<u8>))
+    })
+}
+
+/// Renders `n` vertices, and edges `edges(u)` from every vertex `u`.
+fn render<W, I, F>(n: usize, directed: bool, edges: F) -> String
+where
+    W: Display,
+    I: Iterator<Item = (usize, Option<W>)>,
+    F: Fn(usize) -> I,
+{
+    let (kind, arrow) = if directed {
+        ("digraph", "->")
+    } else {
+        ("graph", "--")
+    };
+    let mut out = format!("{kind} {{\n");
+    for u in 0..n {
+        writeln!(out, "  {u};").unwrap();
+    }
+    for u in 0..n {
+        for (v, w) in edges(u) {
+            write!(out, "  {u} {arrow} {v}").unwrap();
+            if let Some(w) = w {
+                write!(out, " [label=\"{w}\"]").unwrap();
+            }
+            out.push_str(";\n");
+        }
+    }
+    out.push_str("}\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_dot() {
+        assert_eq!(to_dot(&[], false), "graph {\n}\n");
+
+        // Self-loop and a multi-edge, undirected edges are printed once.
+        let adj = vec![vec![0, 1, 1], vec![0, 0]];
+        assert_eq!(
+            to_dot(&adj, false),
+            "graph {\n  0;\n  1;\n  0 -- 0;\n  0 -- 1;\n  0 -- 1;\n}\n"
+        );
+        assert_eq!(to_dot(&adj, true).matches("->").count(), 5);
+
+        let adj = vec![vec![(1, 2.5)], vec![]];
+        assert!(weighted_to_dot(&adj, true).contains("0 -> 1 [label=\"2.5\"];"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Parent 3 of vertex 1 is out of bounds")]
+    fn test_tree_invalid_parent() {
+        tree_to_dot(&[None, Some(3)]);
+    }
+}
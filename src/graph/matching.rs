@@ -0,0 +1,178 @@
+//! Matching problems on bipartite graphs.
+//!
+//! # Stable marriage
+//!
+//! Given `n` men and `n` women, each having a strict preference list over the
+//! members of the other group, find a matching such that there is no man and
+//! woman who would both prefer each other over their current partners.
+//!
+//! Use [`stable_marriage`] to find such a matching (Gale–Shapley algorithm):
+//!
+//! ```
+//! use algorist::graph::matching::stable_marriage;
+//!
+//! let men_prefs = vec![vec![0, 1], vec![0, 1]];
+//! let women_prefs = vec![vec![1, 0], vec![0, 1]];
+//!
+//! // Both men prefer woman `0`, but she prefers man `1`.
+//! assert_eq!(stable_marriage(&men_prefs, &women_prefs), vec![1, 0]);
+//! ```
+
+use std::collections::VecDeque;
+
+/// Finds a stable matching using the Gale–Shapley algorithm, in `O(n^2)`.
+///
+/// The `men_prefs[m]` is the list of women in order of man `m`'s preference
+/// (most preferred first), and `women_prefs[w]` is the list of men in order of
+/// woman `w`'s preference.
+///
+/// Returns a vector where element at index `m` is the woman matched with man
+/// `m`. The matching is man-optimal: every man gets the best partner he can
+/// have in any stable matching. To get a woman-optimal matching, swap the
+/// arguments.
+///
+/// # Panics
+///
+/// Panics if the number of men and women differ, or if any preference list is
+/// not a permutation of the other group's members.
+///
+/// # Example
+///
+/// ```
+/// use algorist::graph::matching::stable_marriage;
+///
+/// let men_prefs = vec![vec![0, 1, 2], vec![1, 0, 2], vec![0, 1, 2]];
+/// let women_prefs = vec![vec![1, 0, 2], vec![0, 1, 2], vec![0, 1, 2]];
+///
+/// let wife = stable_marriage(&men_prefs, &women_prefs);
+/// assert_eq!(wife, vec![0, 1, 2]);
+/// ```
+pub fn stable_marriage(men_prefs: &[Vec<usize>], women_prefs: &[Vec<usize>]) -> Vec<usize> {
+    let n = men_prefs.len();
+    assert_eq!(
+        n,
+        women_prefs.len(),
+        "Number of men and women must be the same"
+    );
+    validate_prefs(men_prefs, "man");
+    validate_prefs(women_prefs, "woman");
+
+    // rank[w][m] is the position of man `m` in the preference list of woman `w`.
+    let mut rank = vec![vec![0; n]; n];
+    for (w, prefs) in women_prefs.iter().enumerate() {
+        for (i, &m) in prefs.iter().enumerate() {
+            rank[w][m] = i;
+        }
+    }
+
+    let mut next_proposal = vec![0; n];
+    let mut husband: Vec<Option<usize>> = vec![None; n];
+    let mut free: VecDeque<usize> = (0..n).collect();
+
+    while let Some(m) = free.pop_front() {
+        let w = men_prefs[m][next_proposal[m]];
+        next_proposal[m] += 1;
+        match husband[w] {
+            None => husband[w] = Some(m),
+            Some(cur) if rank[w][m] < rank[w][cur] => {
+                husband[w] = Some(m);
+                free.push_back(cur);
+            }
+            Some(_) => free.push_back(m),
+        }
+    }
+
+    let mut wife = vec![0; n];
+    for (w, m) in husband.into_iter().enumerate() {
+        wife[m.expect("Every woman is matched")] = w;
+    }
+    wife
+}
+
+fn validate_prefs(prefs: &[Vec<usize>], who: &str) {
+    let n = prefs.len();
+    for (i, list) in prefs.iter().enumerate() {
+        let mut seen = vec![false; n];
+        assert_eq!(
+            list.len(),
+            n,
+            "Preference list of {who} {i} must contain {n} entries"
+        );
+        for &x in list {
+            assert!(
+                x < n && !seen[x],
+                "Preference list of {who} {i} is not a permutation: {list:?}"
+            );
+            seen[x] = true;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_stable(men_prefs: &[Vec<usize>], women_prefs: &[Vec<usize>], wife: &[usize]) -> bool {
+        let n = wife.len();
+        let mut husband = vec![0; n];
+        for (m, &w) in wife.iter().enumerate() {
+            husband[w] = m;
+        }
+        let pos = |list: &[usize], x: usize| list.iter().position(|&y| y == x).unwrap();
+        for m in 0..n {
+            for w in 0..n {
+                let m_prefers = pos(&men_prefs[m], w) < pos(&men_prefs[m], wife[m]);
+                let w_prefers = pos(&women_prefs[w], m) < pos(&women_prefs[w], husband[w]);
+                if m_prefers && w_prefers {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    #[test]
+    fn test_stable_marriage() {
+        assert_eq!(stable_marriage(&[], &[]), Vec::<usize>::new());
+        assert_eq!(stable_marriage(&[vec![0]], &[vec![0]]), vec![0]);
+
+        let same = vec![0, 1, 2, 3];
+        let men_prefs = vec![
+            vec![3, 1, 2, 0],
+            vec![1, 0, 2, 3],
+            same.clone(),
+            same.clone(),
+        ];
+        let women_prefs = vec![same; 4];
+        let wife = stable_marriage(&men_prefs, &women_prefs);
+        assert_eq!(wife, vec![3, 1, 0, 2]);
+        assert!(is_stable(&men_prefs, &women_prefs, &wife));
+
+        // Woman-optimal matching is obtained by swapping the arguments.
+        let husband = stable_marriage(&women_prefs, &men_prefs);
+        assert!(is_stable(&women_prefs, &men_prefs, &husband));
+    }
+
+    #[test]
+    fn test_stable_marriage_opposite_prefs() {
+        let men_prefs = vec![vec![0, 1, 2], vec![1, 2, 0], vec![2, 0, 1]];
+        let women_prefs = vec![vec![1, 2, 0], vec![2, 0, 1], vec![0, 1, 2]];
+
+        // Every man gets his first choice.
+        assert_eq!(stable_marriage(&men_prefs, &women_prefs), vec![0, 1, 2]);
+        // Every woman gets her first choice.
+        assert_eq!(stable_marriage(&women_prefs, &men_prefs), vec![1, 2, 0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Number of men and women must be the same")]
+    fn test_stable_marriage_size_mismatch() {
+        stable_marriage(&[vec![0]], &[vec![0, 1], vec![1, 0]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Preference list of woman 1 is not a permutation")]
+    fn test_stable_marriage_invalid_prefs() {
+        stable_marriage(&[vec![0, 1], vec![1, 0]], &[vec![0, 1], vec![1, 1]]);
+    }
+}
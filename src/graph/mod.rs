@@ -0,0 +1,12 @@
+//! Graph algorithms.
+//!
+//! Currently, this module contains:
+//!
+//! | Module | Description
+//! | --- | ---
+//! | [`matching`] | Matching problems, like the stable marriage problem.
+//! | `debug` | Graphviz export of graphs and trees (requires the `local` feature).
+
+#[cfg(feature = "local")]
+pub mod debug;
+pub mod matching;
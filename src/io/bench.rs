@@ -0,0 +1,222 @@
+//! Throughput measurements for [`Scanner`] and [`Writer`].
+//!
+//! Available with the `bench` feature only. These helpers provide baselines
+//! for changes to the io code (parsing fast paths, buffer sizing etc.), and are
+//! driven by `benches/io.rs`:
+//!
+//! ``` bash
+//! cargo bench --features bench
+//! ```
+//!
+//! # Example
+//!
+//! ```
+//! use algorist::io::bench;
+//!
+//! let input = bench::input(1000, 10);
+//! let t = bench::scanner::<u64>(&input, 1000);
+//! assert_eq!(t.tokens, 1000);
+//! assert_eq!(t.bytes, input.len());
+//!
+//! let t = bench::writer(1000, 1 << 16);
+//! assert_eq!(t.tokens, 1000);
+//! println!("{t}");
+//! ```
+
+use {
+    super::{ParseInt, Scanner, Writer},
+    std::{
+        fmt,
+        hint::black_box,
+        io::{self, Write},
+        str::FromStr,
+        time::{Duration, Instant},
+    },
+};
+
+/// Result of a single measurement.
+#[derive(Debug, Clone, Copy)]
+pub struct Throughput {
+    /// Number of processed tokens.
+    pub tokens: usize,
+    /// Number of processed bytes.
+    pub bytes: usize,
+    /// Time it took to process all tokens.
+    pub elapsed: Duration,
+}
+
+impl Throughput {
+    /// Returns the number of tokens processed per second.
+    pub fn tokens_per_sec(&self) -> f64 {
+        self.tokens as f64 / self.elapsed.as_secs_f64().max(f64::MIN_POSITIVE)
+    }
+
+    /// Returns the number of megabytes (MiB) processed per second.
+    pub fn mib_per_sec(&self) -> f64 {
+        self.bytes as f64 / (1 << 20) as f64 / self.elapsed.as_secs_f64().max(f64::MIN_POSITIVE)
+    }
+}
+
+impl fmt::Display for Throughput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} tokens in {:?}: {:.2} Mtokens/s, {:.2} MiB/s",
+            self.tokens,
+            self.elapsed,
+            self.tokens_per_sec() / 1e6,
+            self.mib_per_sec()
+        )
+    }
+}
+
+/// Generates input of `tokens` pseudo-random non-negative integers, with
+/// `per_line` integers on each line. The input always ends with a newline.
+///
+/// The output is deterministic, so that measurements are comparable between
+/// runs.
+pub fn input(tokens: usize, per_line: usize) -> Vec<u8> {
+    assert!(per_line > 0, "At least one token per line is required");
+    let mut out = Vec::with_capacity(tokens * 11);
+    let mut x = 0x2545_f491_4f6c_dd1d_u64;
+    for i in 0..tokens {
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        write!(out, "{}", x % 1_000_000_007).unwrap();
+        out.push(if (i + 1) % per_line == 0 || i + 1 == tokens {
+            b'\n'
+        } else {
+            b' '
+        });
+    }
+    out
+}
+
+/// Measures reading `tokens` values of type `T` from the `input` with
+/// [`Scanner`].
+///
+/// # Panics
+///
+/// Panics if the input contains fewer than `tokens` tokens, or if some of them
+/// can't be parsed as `T`.
+pub fn scanner<T: FromStr>(input: &[u8], tokens: usize) -> Throughput {
+    let start = Instant::now();
+    let mut scan = Scanner::new(input);
+    for _ in 0..tokens {
+        black_box(scan.next::<T>());
+    }
+    Throughput {
+        tokens,
+        bytes: input.len(),
+        elapsed: start.elapsed(),
+    }
+}
+
+/// Measures reading `tokens` integers of type `T` from the `input` with
+/// [`Scanner::int`], after reading the whole input at once.
+///
+/// # Panics
+///
+/// Panics if the input contains fewer than `tokens` tokens, or if some of them
+/// can't be parsed as `T`.
+pub fn scanner_int<T: ParseInt>(input: &[u8], tokens: usize) -> Throughput {
+    let start = Instant::now();
+    let mut scan = Scanner::new(input);
+    scan.read_all();
+    for _ in 0..tokens {
+        black_box(scan.int::<T>());
+    }
+    Throughput {
+        tokens,
+        bytes: input.len(),
+        elapsed: start.elapsed(),
+    }
+}
+
+/// Measures writing `tokens` integers, one per line, with [`Writer`] whose
+/// buffer has the given `capacity`.
+///
+/// The output is discarded, so only formatting and buffering costs are
+/// measured.
+pub fn writer(tokens: usize, capacity: usize) -> Throughput {
+    let mut bytes = 0;
+    let start = Instant::now();
+    {
+        let mut w = Writer::with_capacity(capacity, Counter(io::sink(), &mut bytes));
+        for i in 0..tokens {
+            w.write_fmt(format_args!("{}\n", black_box(i)));
+        }
+        w.flush();
+    }
+    Throughput {
+        tokens,
+        bytes,
+        elapsed: start.elapsed(),
+    }
+}
+
+/// Measures writing `tokens` integers, one per line, with [`Writer::int`],
+/// see [`writer`].
+pub fn writer_int(tokens: usize, capacity: usize) -> Throughput {
+    let mut bytes = 0;
+    let start = Instant::now();
+    {
+        let mut w = Writer::with_capacity(capacity, Counter(io::sink(), &mut bytes));
+        for i in 0..tokens {
+            w.int(black_box(i));
+            let _ = w.write_all(b"\n");
+        }
+        w.flush();
+    }
+    Throughput {
+        tokens,
+        bytes,
+        elapsed: start.elapsed(),
+    }
+}
+
+/// Writer wrapper, counting the number of written bytes.
+struct Counter<'a, W>(W, &'a mut usize);
+
+impl<W: Write> Write for Counter<'_, W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = self.0.write(buf)?;
+        *self.1 += n;
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.0.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input() {
+        let input = input(10, 3);
+        let text = std::str::from_utf8(&input).unwrap();
+        assert_eq!(text.lines().count(), 4);
+        assert_eq!(text.split_whitespace().count(), 10);
+        assert!(text.ends_with('\n'));
+    }
+
+    #[test]
+    fn test_measurements() {
+        let data = input(100, 7);
+        let t = scanner::<u32>(&data, 100);
+        assert_eq!((t.tokens, t.bytes), (100, data.len()));
+        let t = scanner_int::<u32>(&data, 100);
+        assert_eq!((t.tokens, t.bytes), (100, data.len()));
+
+        // 10 one-digit numbers, and 90 two-digit ones, each followed by "\n".
+        let t = writer(100, 16);
+        assert_eq!((t.tokens, t.bytes), (100, 10 * 2 + 90 * 3));
+        let t = writer_int(100, 16);
+        assert_eq!((t.tokens, t.bytes), (100, 10 * 2 + 90 * 3));
+        assert!(t.tokens_per_sec() > 0.);
+    }
+}
@@ -65,10 +65,28 @@
 //! As you can see, the difference between reading a single test case and
 //! reading multiple test cases is minimal -- you just need to call different
 //! function, with the same closure.
+//!
+//! If the solution recurses deeply (e.g. DFS over a long path), use
+//! [`test_cases_with_stack()`] to run it in a thread with a larger stack.
+//!
+//! For interactive problems, use [`interactive()`], which flushes every line
+//! written, and the [`macro@query`] macro.
 
-use std::{
-    collections::VecDeque,
-    io::{self, BufWriter, StdinLock, StdoutLock, Write, prelude::*},
+#[cfg(feature = "bench")]
+pub mod bench;
+pub mod readable;
+
+use {
+    crate::{
+        collections::arr_2d::Arr,
+        io::readable::Readable,
+        math::{ConstValue, Downcast, Number, modulo::Modulo},
+        misc::thread::with_stack_size,
+    },
+    std::{
+        collections::VecDeque,
+        io::{self, BufWriter, StdinLock, StdoutLock, Write, prelude::*},
+    },
 };
 
 /// A helper function to read multiple test cases from standard input, and write
@@ -103,17 +121,174 @@ use std::{
 ///
 /// In case you want to read a single test case, use the [`test_case()`],
 /// instead.
+///
+/// To read from a file locally, and from standard input on the judge, use
+/// [`test_cases_from()`] with [`input()`].
 pub fn test_cases<F: FnMut(&mut Scanner<StdinLock>, &mut Writer<BufWriter<StdoutLock>>)>(
     f: &mut F,
 ) {
-    let mut scan = Scanner::new(io::stdin().lock());
-    let mut w = Writer::new(io::BufWriter::new(io::stdout().lock()));
+    test_cases_from(
+        io::stdin().lock(),
+        io::BufWriter::new(io::stdout().lock()),
+        f,
+    );
+}
+
+/// Same as [`test_cases()`], but reads from the given `reader`, and writes to
+/// the given `writer`, e.g. files or in-memory buffers.
+///
+/// # Example
+///
+/// ```
+/// use algorist::io::{test_cases_from, wln};
+///
+/// let mut out = Vec::new();
+/// test_cases_from(b"2\n3 2\n2 1\n".as_ref(), &mut out, &mut |scan, w| {
+///     let (a, b) = scan.u2();
+///     wln!(w, "Sum: {}", a + b);
+/// });
+/// assert_eq!(out, b"Sum: 5\nSum: 3\n");
+/// ```
+pub fn test_cases_from<R, W, F>(reader: R, writer: W, f: &mut F)
+where
+    R: BufRead,
+    W: Write,
+    F: FnMut(&mut Scanner<R>, &mut Writer<W>),
+{
+    let mut scan = Scanner::new(reader);
+    let mut w = Writer::new(writer);
 
     scan.test_cases(&mut |scan| {
         f(scan, &mut w);
     });
 }
 
+/// Same as [`test_cases()`], but the closure returns the answer for the test
+/// case, which is written on its own line.
+///
+/// This way, the solution is a function from input to answer, which is easy
+/// to call from unit tests.
+///
+/// # Example
+///
+/// ``` no_run
+/// use {
+///     algorist::io::{Scanner, test_cases_collect},
+///     std::io::StdinLock,
+/// };
+///
+/// fn solve(scan: &mut Scanner<StdinLock>) -> usize {
+///     let (a, b) = scan.u2();
+///     a + b
+/// }
+///
+/// test_cases_collect(&mut solve);
+/// ```
+pub fn test_cases_collect<T, F>(f: &mut F)
+where
+    T: std::fmt::Display,
+    F: FnMut(&mut Scanner<StdinLock>) -> T,
+{
+    test_cases_collect_from(
+        io::stdin().lock(),
+        io::BufWriter::new(io::stdout().lock()),
+        f,
+    );
+}
+
+/// Same as [`test_cases_collect()`], but reads from the given `reader`, and
+/// writes to the given `writer`.
+///
+/// # Example
+///
+/// ```
+/// use algorist::io::test_cases_collect_from;
+///
+/// let mut out = Vec::new();
+/// test_cases_collect_from(b"3\n1 2\n2 2\n5 -5\n".as_ref(), &mut out, &mut |scan| {
+///     let (a, b) = scan.i2();
+///     if a + b > 0 { "YES" } else { "NO" }
+/// });
+/// assert_eq!(out, b"YES\nYES\nNO\n");
+/// ```
+pub fn test_cases_collect_from<R, W, T, F>(reader: R, writer: W, f: &mut F)
+where
+    R: BufRead,
+    W: Write,
+    T: std::fmt::Display,
+    F: FnMut(&mut Scanner<R>) -> T,
+{
+    test_cases_from(reader, writer, &mut |scan, w| {
+        let ans = f(scan);
+        wln!(w, "{}", ans);
+    });
+}
+
+/// Same as [`test_cases()`], but also passes the 1-based index of the test case
+/// to the closure, e.g. for Google-style `Case #t: ...` outputs (see
+/// [`macro@wln_case`]).
+///
+/// # Example
+///
+/// ``` no_run
+/// use algorist::io::{test_cases_indexed, wln_case};
+///
+/// test_cases_indexed(&mut |t, scan, w| {
+///     let (a, b) = scan.u2();
+///     wln_case!(w, t, "{}", a + b);
+/// });
+/// ```
+///
+/// ``` bash
+/// # Input:
+/// 2
+/// 3 2
+/// 2 1
+///
+/// # Output:
+/// Case #1: 5
+/// Case #2: 3
+/// ```
+pub fn test_cases_indexed<F>(f: &mut F)
+where
+    F: FnMut(usize, &mut Scanner<StdinLock>, &mut Writer<BufWriter<StdoutLock>>),
+{
+    let mut scan = Scanner::new(io::stdin().lock());
+    let mut w = Writer::new(io::BufWriter::new(io::stdout().lock()));
+
+    scan.test_cases_indexed(&mut |t, scan| {
+        f(t, scan, &mut w);
+    });
+}
+
+/// Same as [`test_cases()`], but runs in a thread with a stack of `stack_size`
+/// bytes (see [`misc::thread`](crate::misc::thread)), which prevents stack
+/// overflows in deep recursion.
+///
+/// # Example
+///
+/// ``` no_run
+/// use algorist::{
+///     io::{test_cases_with_stack, wln},
+///     misc::thread::DEFAULT_STACK_SIZE,
+/// };
+///
+/// fn depth(n: usize) -> usize {
+///     if n == 0 { 0 } else { 1 + depth(n - 1) }
+/// }
+///
+/// test_cases_with_stack(DEFAULT_STACK_SIZE, &mut |scan, w| {
+///     let n = scan.u();
+///     wln!(w, "{}", depth(n));
+/// });
+/// ```
+pub fn test_cases_with_stack<F>(stack_size: usize, f: &mut F)
+where
+    F: FnMut(&mut Scanner<StdinLock>, &mut Writer<BufWriter<StdoutLock>>) + Send,
+{
+    with_stack_size(stack_size, || test_cases(f));
+}
+
 /// A helper function to read a single test case from standard input, and write
 /// to standard output.
 ///
@@ -138,10 +313,140 @@ pub fn test_cases<F: FnMut(&mut Scanner<StdinLock>, &mut Writer<BufWriter<Stdout
 /// # Output:
 /// Sum: 5
 /// ```
+///
+/// To read from a file locally, and from standard input on the judge, use
+/// [`test_case_from()`] with [`input()`].
 pub fn test_case<F: FnMut(&mut Scanner<StdinLock>, &mut Writer<BufWriter<StdoutLock>>)>(f: &mut F) {
+    test_case_from(
+        io::stdin().lock(),
+        io::BufWriter::new(io::stdout().lock()),
+        f,
+    );
+}
+
+/// Same as [`test_case()`], but reads from the given `reader`, and writes to
+/// the given `writer`.
+///
+/// See also [`test_cases_from()`].
+pub fn test_case_from<R, W, F>(reader: R, writer: W, f: &mut F)
+where
+    R: BufRead,
+    W: Write,
+    F: FnMut(&mut Scanner<R>, &mut Writer<W>),
+{
+    let mut scan = Scanner::new(reader);
+    let mut w = Writer::new(writer);
+    f(&mut scan, &mut w);
+}
+
+/// Environment variable with the path of the file, which [`input()`] reads
+/// instead of the standard input, e.g. `ALGORIST_INPUT=in.txt cargo run`.
+pub const INPUT_VAR: &str = "ALGORIST_INPUT";
+
+/// Input of the solution: either the standard input, or a file, see
+/// [`input()`].
+pub enum Input {
+    /// Standard input.
+    Stdin(StdinLock<'static>),
+    /// File, given by the [`INPUT_VAR`] environment variable.
+    File(io::BufReader<std::fs::File>),
+}
+
+/// Returns the input of the solution: the file, given by the [`INPUT_VAR`]
+/// environment variable, if it is set, and the standard input otherwise.
+///
+/// Passed to [`test_cases_from()`] or [`test_case_from()`], it makes the same
+/// binary read from a file locally, and from the standard input on the judge.
+/// With the `judge` feature (or the `ONLINE_JUDGE` cfg), the variable is
+/// ignored.
+///
+/// # Panics
+///
+/// Panics if the file can't be opened.
+///
+/// # Example
+///
+/// ``` no_run
+/// use {
+///     algorist::io::{Input, Scanner, Writer, input, test_cases_from, wln},
+///     std::io::{self, BufWriter, StdoutLock},
+/// };
+///
+/// fn solve(scan: &mut Scanner<Input>, w: &mut Writer<BufWriter<StdoutLock>>) {
+///     let (a, b) = scan.u2();
+///     wln!(w, "{}", a + b);
+/// }
+///
+/// // Reads `in.txt` with `ALGORIST_INPUT=in.txt cargo run`.
+/// test_cases_from(input(), BufWriter::new(io::stdout().lock()), &mut solve);
+/// ```
+pub fn input() -> Input {
+    #[cfg(not(any(feature = "judge", ONLINE_JUDGE)))]
+    {
+        if let Some(path) = std::env::var_os(INPUT_VAR) {
+            let file = std::fs::File::open(&path)
+                .unwrap_or_else(|e| panic!("Failed to open {}: {e}", path.to_string_lossy()));
+            return Input::File(io::BufReader::new(file));
+        }
+    }
+    Input::Stdin(io::stdin().lock())
+}
+
+impl Read for Input {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match self {
+            Input::Stdin(r) => r.read(buf),
+            Input::File(r) => r.read(buf),
+        }
+    }
+}
+
+impl BufRead for Input {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        match self {
+            Input::Stdin(r) => r.fill_buf(),
+            Input::File(r) => r.fill_buf(),
+        }
+    }
+
+    fn consume(&mut self, amt: usize) {
+        match self {
+            Input::Stdin(r) => r.consume(amt),
+            Input::File(r) => r.consume(amt),
+        }
+    }
+}
+
+/// A helper function for interactive problems: provides a [`Scanner`] over
+/// standard input, and a [`Writer`], which flushes standard output after every
+/// line, so that the judge sees each query as soon as it is written.
+///
+/// Use [`macro@query`] to write a query, and read the response in one step.
+///
+/// # Example
+///
+/// ``` no_run
+/// use algorist::io::{interactive, query, wln};
+///
+/// // Guess the number in `[1, n]`, by asking whether it is at least `x`.
+/// interactive(&mut |scan, w| {
+///     let n = scan.u();
+///     let (mut lo, mut hi) = (1, n);
+///     while lo < hi {
+///         let mid = (lo + hi + 1) / 2;
+///         let ans: String = query!(w, scan, "? {}", mid);
+///         if ans == "yes" { lo = mid } else { hi = mid - 1 }
+///     }
+///     wln!(w, "! {}", lo);
+/// });
+/// ```
+pub fn interactive<F: FnMut(&mut Scanner<StdinLock>, &mut Writer<StdoutLock>)>(f: &mut F) {
     let mut scan = Scanner::new(io::stdin().lock());
-    let mut w = Writer::new(io::BufWriter::new(io::stdout().lock()));
+    // Standard output is line buffered, so without an extra buffer every line
+    // is flushed as soon as it is written.
+    let mut w = Writer::with_capacity(0, io::stdout().lock());
     f(&mut scan, &mut w);
+    w.flush();
 }
 
 /// A `Writer` is a wrapper around `BufWriter<W>` that provides a convenient
@@ -161,11 +466,50 @@ pub fn test_case<F: FnMut(&mut Scanner<StdinLock>, &mut Writer<BufWriter<StdoutL
 /// wln!(w, "Hello, {}!", "world");
 /// writeln!(w, "This is a test."); // `wln!` is shorter and more ergonomic
 /// ```
-pub struct Writer<W: Write>(BufWriter<W>);
+pub struct Writer<W: Write>(BufWriter<W>, Case);
+
+/// Letter case of the `YES`/`NO` answers, see [`Writer::yesno`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Case {
+    /// `YES` and `NO`.
+    #[default]
+    Upper,
+    /// `yes` and `no`.
+    Lower,
+    /// `Yes` and `No`.
+    Title,
+}
 
 impl<W: Write> Writer<W> {
     pub fn new(inner: W) -> Self {
-        Self(BufWriter::new(inner))
+        Self(BufWriter::new(inner), Case::default())
+    }
+
+    /// Creates a new `Writer` with a buffer of at least the specified
+    /// `capacity` (in bytes).
+    ///
+    /// The default capacity (8 KiB) is fine for most problems, but when the
+    /// output is huge, bigger buffer means fewer system calls.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::io::{Writer, wln};
+    ///
+    /// let mut out = Vec::new();
+    /// let mut w = Writer::with_capacity(1 << 16, &mut out);
+    /// wln!(w, "{} {}", 1, 2);
+    /// w.flush();
+    /// drop(w);
+    /// assert_eq!(out, b"1 2\n");
+    /// ```
+    pub fn with_capacity(capacity: usize, inner: W) -> Self {
+        Self(BufWriter::with_capacity(capacity, inner), Case::default())
+    }
+
+    /// Returns the capacity of the internal buffer.
+    pub fn capacity(&self) -> usize {
+        self.0.capacity()
     }
 
     /// Writes a formatted string to the underlying writer.
@@ -178,8 +522,332 @@ impl<W: Write> Writer<W> {
     pub fn flush(&mut self) {
         let _ = self.0.flush();
     }
+
+    /// Sets the letter case of answers, written by [`Writer::yesno`] (upper
+    /// case by default).
+    pub fn set_case(&mut self, case: Case) {
+        self.1 = case;
+    }
+
+    /// Writes `YES` or `NO` (in the case set by [`Writer::set_case`]),
+    /// followed by a newline.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::io::{Case, Writer};
+    ///
+    /// let mut out = Vec::new();
+    /// let mut w = Writer::new(&mut out);
+    /// w.yesno(1 + 1 == 2);
+    /// w.set_case(Case::Title);
+    /// w.no();
+    /// w.set_case(Case::Lower);
+    /// w.yes();
+    /// drop(w);
+    /// assert_eq!(out, b"YES\nNo\nyes\n");
+    /// ```
+    pub fn yesno(&mut self, yes: bool) {
+        let answer: &[u8] = match (self.1, yes) {
+            (Case::Upper, true) => b"YES\n",
+            (Case::Upper, false) => b"NO\n",
+            (Case::Lower, true) => b"yes\n",
+            (Case::Lower, false) => b"no\n",
+            (Case::Title, true) => b"Yes\n",
+            (Case::Title, false) => b"No\n",
+        };
+        let _ = self.0.write_all(answer);
+    }
+
+    /// Writes `YES`, see [`Writer::yesno`].
+    pub fn yes(&mut self) {
+        self.yesno(true);
+    }
+
+    /// Writes `NO`, see [`Writer::yesno`].
+    pub fn no(&mut self) {
+        self.yesno(false);
+    }
+
+    /// Writes the floating point number with exactly `digits` digits after the
+    /// decimal point (without a newline), see [`Fixed`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::io::{Writer, wln};
+    ///
+    /// let mut out = Vec::new();
+    /// let mut w = Writer::new(&mut out);
+    /// w.float(2.0f64.sqrt(), 9);
+    /// wln!(w);
+    /// w.float(1e20, 2);
+    /// drop(w);
+    /// assert_eq!(out, b"1.414213562\n100000000000000000000.00");
+    /// ```
+    pub fn float(&mut self, x: f64, digits: usize) {
+        let _ = write!(self.0, "{}", Fixed(x, digits));
+    }
+
+    /// Writes the 2D array row by row, with values separated by spaces.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::{collections::arr_2d::Arr, io::Writer};
+    ///
+    /// let mut out = Vec::new();
+    /// let mut w = Writer::new(&mut out);
+    /// w.grid(&Arr::with_generator(2, 3, |i, j| i * 3 + j));
+    /// drop(w);
+    /// assert_eq!(out, b"0 1 2\n3 4 5\n");
+    /// ```
+    pub fn grid<T: std::fmt::Debug + std::fmt::Display>(&mut self, arr: &Arr<T>) {
+        for i in 0..arr.rows() {
+            wvln(self, &arr[i]);
+        }
+    }
+
+    /// Writes the 2D array of characters (as bytes) row by row, without
+    /// separators, e.g. a grid read by [`Scanner::char_grid`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::io::{Scanner, Writer};
+    ///
+    /// let mut grid = Scanner::new(b"#..\n.#.\n".as_ref()).char_grid(2, 3);
+    /// grid[(0, 2)] = b'*';
+    /// let mut out = Vec::new();
+    /// let mut w = Writer::new(&mut out);
+    /// w.char_grid(&grid);
+    /// drop(w);
+    /// assert_eq!(out, b"#.*\n.#.\n");
+    /// ```
+    pub fn char_grid(&mut self, arr: &Arr<u8>) {
+        for i in 0..arr.rows() {
+            let _ = self.0.write_all(&arr[i]);
+            let _ = self.0.write_all(b"\n");
+        }
+    }
+
+    /// Writes the integer (without a newline), formatting it directly into
+    /// bytes, which is faster than going through `fmt::Display`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::io::{Writer, wln};
+    ///
+    /// let mut out = Vec::new();
+    /// let mut w = Writer::new(&mut out);
+    /// w.int(-42);
+    /// wln!(w, " {}", 7);
+    /// w.int(u128::MAX);
+    /// drop(w);
+    /// assert_eq!(out, b"-42 7\n340282366920938463463374607431768211455");
+    /// ```
+    pub fn int<T: FormatInt>(&mut self, x: T) {
+        let mut buf = [0; 40];
+        let _ = self.0.write_all(x.format_int(&mut buf));
+    }
+
+    /// Writes the integers, separated by `sep` (without a newline), see
+    /// [`Writer::int`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::io::{Writer, wln};
+    ///
+    /// let mut out = Vec::new();
+    /// let mut w = Writer::new(&mut out);
+    /// let v = vec![3, 1, 2];
+    /// w.ints(&v, " ");
+    /// wln!(w);
+    /// w.ints((1..=3).map(|x| x * x), "\n");
+    /// drop(w);
+    /// assert_eq!(out, b"3 1 2\n1\n4\n9");
+    /// ```
+    pub fn ints<I>(&mut self, iter: I, sep: &str)
+    where
+        I: IntoIterator,
+        I::Item: FormatInt,
+    {
+        for (i, x) in iter.into_iter().enumerate() {
+            if i > 0 {
+                let _ = self.0.write_all(sep.as_bytes());
+            }
+            self.int(x);
+        }
+    }
+}
+
+/// Floating point number, displayed with a fixed number of digits after the
+/// decimal point: `Fixed(x, digits)`.
+///
+/// Scientific notation is never used, and negative numbers, which round to
+/// zero, are displayed without the minus sign (e.g. `0.00`, not `-0.00`).
+///
+/// # Example
+///
+/// ```
+/// use algorist::io::Fixed;
+///
+/// assert_eq!(Fixed(1.0 / 3.0, 4).to_string(), "0.3333");
+/// assert_eq!(Fixed(2.5e-10, 3).to_string(), "0.000");
+/// assert_eq!(Fixed(-1e-9, 2).to_string(), "0.00");
+/// assert_eq!(Fixed(-1.005, 0).to_string(), "-1");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Fixed(pub f64, pub usize);
+
+impl std::fmt::Display for Fixed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = format!("{:.*}", self.1, self.0);
+        match s.strip_prefix('-') {
+            Some(abs) if abs.bytes().all(|b| b == b'0' || b == b'.') => f.write_str(abs),
+            _ => f.write_str(&s),
+        }
+    }
+}
+
+/// Integer types, which can be formatted as decimal ASCII digits directly,
+/// bypassing `fmt::Display`.
+///
+/// See [`Writer::int`].
+pub trait FormatInt {
+    /// Formats the number into the end of the buffer (which fits any
+    /// primitive integer), and returns the written part.
+    fn format_int(self, buf: &mut [u8; 40]) -> &[u8];
+}
+
+macro_rules! format_int_impl {
+    (@abs signed $x: expr) => {
+        $x.unsigned_abs()
+    };
+    (@abs unsigned $x: expr) => {
+        $x
+    };
+    ($kind: ident: $($t: ident)+) => {$(
+        impl FormatInt for $t {
+            fn format_int(self, buf: &mut [u8; 40]) -> &[u8] {
+                #[allow(unused_comparisons)]
+                let neg = self < 0;
+                let mut n = format_int_impl!(@abs $kind self);
+                let mut i = buf.len();
+                loop {
+                    i -= 1;
+                    buf[i] = b'0' + (n % 10) as u8;
+                    n /= 10;
+                    if n == 0 {
+                        break;
+                    }
+                }
+                if neg {
+                    i -= 1;
+                    buf[i] = b'-';
+                }
+                &buf[i..]
+            }
+        }
+    )+};
+}
+
+format_int_impl!(signed: i8 i16 i32 i64 i128 isize);
+format_int_impl!(unsigned: u8 u16 u32 u64 u128 usize);
+
+impl<T: FormatInt + Copy> FormatInt for &T {
+    fn format_int(self, buf: &mut [u8; 40]) -> &[u8] {
+        (*self).format_int(buf)
+    }
+}
+
+/// Allows using `Writer` wherever `std::io::Write` is expected, e.g. with
+/// [`wvln`].
+impl<W: Write> Write for Writer<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.0.flush()
+    }
+}
+
+/// Integer types, which can be parsed from a string in a given radix.
+///
+/// See [`Scanner::next_radix`].
+pub trait FromStrRadix: Sized {
+    /// Parses the string in the given radix, returning `None` if it is not a
+    /// valid number, or doesn't fit into the type.
+    fn from_str_radix(s: &str, radix: u32) -> Option<Self>;
+}
+
+macro_rules! from_str_radix_impl {
+    ($($t: ident)+) => {$(
+        impl FromStrRadix for $t {
+            fn from_str_radix(s: &str, radix: u32) -> Option<Self> {
+                <$t>::from_str_radix(s, radix).ok()
+            }
+        }
+    )+};
 }
 
+from_str_radix_impl!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+
+/// Integer types, which can be parsed from decimal ASCII digits directly,
+/// bypassing UTF-8 validation and `FromStr`.
+///
+/// See [`Scanner::int`].
+pub trait ParseInt: Sized {
+    /// Parses the optionally signed decimal number, returning `None` if it is
+    /// not a valid number, or doesn't fit into the type.
+    fn parse_int(bytes: &[u8]) -> Option<Self>;
+}
+
+macro_rules! parse_int_impl {
+    ($($t: ident)+) => {$(
+        impl ParseInt for $t {
+            fn parse_int(bytes: &[u8]) -> Option<Self> {
+                #[allow(unused_comparisons)]
+                let (neg, digits) = match bytes {
+                    [b'-', rest @ ..] if <$t>::MIN < 0 => (true, rest),
+                    [b'+', rest @ ..] => (false, rest),
+                    _ => (false, bytes),
+                };
+                if digits.is_empty() {
+                    return None;
+                }
+                let mut val: $t = 0;
+                for &b in digits {
+                    let d = b.wrapping_sub(b'0');
+                    if d > 9 {
+                        return None;
+                    }
+                    // Negative numbers are accumulated as such, so that `MIN`
+                    // doesn't overflow.
+                    val = val.checked_mul(10)?;
+                    val = if neg {
+                        val.checked_sub(d as $t)?
+                    } else {
+                        val.checked_add(d as $t)?
+                    };
+                }
+                Some(val)
+            }
+        }
+    )+};
+}
+
+parse_int_impl!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+
+/// Size of chunks, in which the input is read.
+const CHUNK_SIZE: usize = 1 << 16;
+
+/// Bytes, separating tokens by default: ASCII whitespace.
+const WHITESPACE: &[u8] = b" \t\n\r\x0b\x0c";
+
 /// Scanner reads buffered input and parses it into tokens.
 ///
 /// The `Scanner` is designed to simplify reading input in competitive
@@ -187,6 +855,11 @@ impl<W: Write> Writer<W> {
 /// efficiently. Moreover, it supports several most common operations like
 /// reading several test cases, strings, and vectors.
 ///
+/// Input is read in large chunks, and split into tokens byte by byte, so
+/// that no UTF-8 validation is done, and integers (see [`Scanner::int`]) are
+/// parsed without `FromStr`. For the largest inputs, the whole input can be
+/// read at once, with [`Scanner::read_all`].
+///
 /// ## Reading input of a single test
 ///
 /// It is less common nowadays, as most contest will provide you with several
@@ -252,10 +925,26 @@ impl<W: Write> Writer<W> {
 /// the closure.
 pub struct Scanner<R> {
     reader: R,
+    /// Read, but not yet consumed input is `buffer[pos..end]`, and
+    /// `buffer[end..]` is free space for the next reads.
     buffer: Vec<u8>,
-    iter: std::str::SplitWhitespace<'static>,
+    pos: usize,
+    end: usize,
+    /// Whether the reader is exhausted.
+    eof: bool,
+    /// Whether the last consumed byte is a line end (or nothing is consumed
+    /// yet), see [`Scanner::line`].
+    line_start: bool,
+    /// Bytes, separating tokens.
+    separators: [bool; 256],
 }
 
+/// Delimiters of the most common non-whitespace separated inputs: `x,y` pairs,
+/// `hh:mm:ss` times, and `;`-separated lists.
+///
+/// See [`Scanner::with_delimiters`].
+pub const CSV_DELIMITERS: &[u8] = b",:;";
+
 impl<R: BufRead> Scanner<R> {
     /// Creates a new `Scanner` instance with the given reader.
     ///
@@ -280,50 +969,485 @@ impl<R: BufRead> Scanner<R> {
         Self {
             reader,
             buffer: Vec::new(),
-            iter: "".split_whitespace(),
+            pos: 0,
+            end: 0,
+            eof: false,
+            line_start: true,
+            separators: Self::separators(&[]),
         }
     }
 
-    /// Reads the next token from the input, parsing it into the specified `T`.
-    ///
-    /// This method will read until a newline character is encountered, then
-    /// split the line into whitespace-separated tokens, and traverse the
-    /// iterator.
+    /// Reads the rest of the input at once, so that no more reads are needed
+    /// while parsing it.
     ///
-    /// It will return the next token as type `T`.
+    /// This is the fastest way to process huge inputs (e.g. `10^6` tokens),
+    /// but it must not be used with interactive problems.
     ///
     /// # Example
     ///
     /// ```
-    /// use {algorist::io::Scanner, std::io::BufReader};
+    /// use algorist::io::Scanner;
     ///
-    /// let input = b"42 3.14 hello\n";
-    /// let mut scan = Scanner::new(BufReader::new(input.as_ref()));
-    /// let x: i32 = scan.next();
-    /// let y: f64 = scan.next();
-    /// let s: String = scan.next();
-    /// assert_eq!(x, 42);
-    /// assert_eq!(y, 3.14);
-    /// assert_eq!(s, "hello");
+    /// let mut scan = Scanner::new(b"3\n1 2 3\n".as_ref());
+    /// scan.read_all();
+    /// let n = scan.u();
+    /// assert_eq!(scan.vec::<u32>(n), vec![1, 2, 3]);
     /// ```
-    #[allow(clippy::should_implement_trait)]
-    pub fn next<T: std::str::FromStr>(&mut self) -> T {
-        loop {
-            if let Some(token) = self.iter.next() {
-                return token.parse().ok().expect("Failed parse");
-            }
-            self.buffer.clear();
-            self.reader
-                .read_until(0xA, &mut self.buffer)
-                .expect("Failed read");
-
-            self.iter = unsafe {
-                let slice = std::str::from_utf8_unchecked(&self.buffer);
-                std::mem::transmute::<std::str::SplitWhitespace<'_>, std::str::SplitWhitespace<'_>>(
-                    slice.split_whitespace(),
-                )
-            };
-        }
+    pub fn read_all(&mut self) {
+        self.buffer.copy_within(self.pos..self.end, 0);
+        self.buffer.truncate(self.end - self.pos);
+        self.pos = 0;
+        self.reader
+            .read_to_end(&mut self.buffer)
+            .expect("Failed read");
+        self.end = self.buffer.len();
+        self.eof = true;
+    }
+
+    /// Creates a new `Scanner`, which, in addition to whitespace, treats the
+    /// given ASCII characters as token delimiters.
+    ///
+    /// This is useful for odd input formats, like `x,y` pairs or `hh:mm:ss`
+    /// times. See [`CSV_DELIMITERS`] for the most common set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if some delimiter is not an ASCII character.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::io::{CSV_DELIMITERS, Scanner};
+    ///
+    /// let input = b"1,2;3\n12:34:56\n";
+    /// let mut scan = Scanner::with_delimiters(input.as_ref(), CSV_DELIMITERS);
+    /// assert_eq!(scan.u3(), (1, 2, 3));
+    /// assert_eq!(scan.u3(), (12, 34, 56));
+    /// ```
+    pub fn with_delimiters(reader: R, delimiters: &[u8]) -> Self {
+        let mut scan = Self::new(reader);
+        scan.set_delimiters(delimiters);
+        scan
+    }
+
+    /// Sets additional (to whitespace) token delimiters. Empty set restores
+    /// the default, whitespace only, behavior.
+    ///
+    /// Delimiters are applied to the input, which is not consumed yet,
+    /// including the rest of the current line.
+    ///
+    /// # Panics
+    ///
+    /// Panics if some delimiter is not an ASCII character.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::io::Scanner;
+    ///
+    /// let input = b"2\n1/2\n3/4\n";
+    /// let mut scan = Scanner::new(input.as_ref());
+    /// let n = scan.u();
+    ///
+    /// scan.set_delimiters(b"/");
+    /// let fractions: Vec<(u32, u32)> = (0..n).map(|_| scan.pair()).collect();
+    /// assert_eq!(fractions, vec![(1, 2), (3, 4)]);
+    /// ```
+    pub fn set_delimiters(&mut self, delimiters: &[u8]) {
+        assert!(delimiters.is_ascii(), "Delimiters must be ASCII characters");
+        self.separators = Self::separators(delimiters);
+    }
+
+    /// Returns the table of separators: whitespace and the given delimiters.
+    fn separators(delimiters: &[u8]) -> [bool; 256] {
+        let mut separators = [false; 256];
+        for &b in WHITESPACE.iter().chain(delimiters) {
+            separators[usize::from(b)] = true;
+        }
+        separators
+    }
+
+    /// Reads the next token from the input, parsing it into the specified `T`.
+    ///
+    /// Tokens are separated by whitespace (and delimiters, if any), and may
+    /// span several lines.
+    ///
+    /// For integers, [`Scanner::int`] is faster, as it doesn't rely on
+    /// `FromStr`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use {algorist::io::Scanner, std::io::BufReader};
+    ///
+    /// let input = b"42 3.14 hello\n";
+    /// let mut scan = Scanner::new(BufReader::new(input.as_ref()));
+    /// let x: i32 = scan.next();
+    /// let y: f64 = scan.next();
+    /// let s: String = scan.next();
+    /// assert_eq!(x, 42);
+    /// assert_eq!(y, 3.14);
+    /// assert_eq!(s, "hello");
+    /// ```
+    #[allow(clippy::should_implement_trait)]
+    pub fn next<T: std::str::FromStr>(&mut self) -> T {
+        self.token().parse().ok().expect("Failed parse")
+    }
+
+    /// Reads the next token as a decimal integer, parsing its bytes directly.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the token is not a valid integer, or doesn't fit into `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::io::Scanner;
+    ///
+    /// let mut scan = Scanner::new(b"-9223372036854775808 +42 255\n".as_ref());
+    /// assert_eq!(scan.int::<i64>(), i64::MIN);
+    /// assert_eq!(scan.int::<u32>(), 42);
+    /// assert_eq!(scan.int::<u8>(), 255);
+    /// ```
+    pub fn int<T: ParseInt>(&mut self) -> T {
+        let token = self.token_bytes();
+        T::parse_int(token)
+            .unwrap_or_else(|| panic!("Failed parse: {}", String::from_utf8_lossy(token)))
+    }
+
+    /// Reads the next token as an integer in the given `radix` (base), e.g.
+    /// a binary mask or a hexadecimal color.
+    ///
+    /// Digits above 9 are letters (case-insensitive), and the `0b`, `0o` or
+    /// `0x` prefix, matching the radix, is allowed, as well as `#` for
+    /// hexadecimal numbers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in `[2, 36]`, or if the token is not a valid
+    /// number in that radix (or doesn't fit into `T`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::io::Scanner;
+    ///
+    /// let input = b"1011 ff 0x1F #00ff7f -z\n";
+    /// let mut scan = Scanner::new(input.as_ref());
+    /// assert_eq!(scan.next_radix::<u8>(2), 0b1011);
+    /// assert_eq!(scan.next_radix::<u8>(16), 255);
+    /// assert_eq!(scan.next_radix::<u64>(16), 31);
+    /// assert_eq!(scan.next_radix::<u32>(16), 0x00ff7f);
+    /// assert_eq!(scan.next_radix::<i32>(36), -35);
+    /// ```
+    pub fn next_radix<T: FromStrRadix>(&mut self, radix: u32) -> T {
+        assert!(
+            (2..=36).contains(&radix),
+            "Radix must be in [2, 36], got {radix}"
+        );
+        let token = self.token();
+        let (sign, digits) = match token.strip_prefix('-') {
+            Some(rest) => ("-", rest),
+            None => ("", token.strip_prefix('+').unwrap_or(token)),
+        };
+        let prefixes: &[&str] = match radix {
+            2 => &["0b", "0B"],
+            8 => &["0o", "0O"],
+            16 => &["0x", "0X", "#"],
+            _ => &[],
+        };
+        let digits = prefixes
+            .iter()
+            .find_map(|p| digits.strip_prefix(p))
+            .unwrap_or(digits);
+        // `from_str_radix` accepts a sign of its own, which must not follow
+        // the sign or the prefix of the token.
+        let valid = digits
+            .bytes()
+            .next()
+            .is_some_and(|b| b != b'+' && b != b'-');
+        assert!(valid, "Failed parse: {token} in radix {radix}");
+        let parsed = if sign.is_empty() {
+            T::from_str_radix(digits, radix)
+        } else {
+            T::from_str_radix(&format!("-{digits}"), radix)
+        };
+        parsed.unwrap_or_else(|| panic!("Failed parse: {token} in radix {radix}"))
+    }
+
+    /// Reads the next token, parsing it into `T`, or returns `None` if the
+    /// input is exhausted (or the token can't be parsed, in which case it is
+    /// still consumed).
+    ///
+    /// Allows to read inputs, which end at EOF, without a count of items (or
+    /// test cases) given upfront.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::io::Scanner;
+    ///
+    /// let mut scan = Scanner::new(b"3 1\n4\n\n1 5\n".as_ref());
+    /// let mut sum = 0;
+    /// while let Some(x) = scan.try_next::<i32>() {
+    ///     sum += x;
+    /// }
+    /// assert_eq!(sum, 14);
+    /// ```
+    pub fn try_next<T: std::str::FromStr>(&mut self) -> Option<T> {
+        let token = self.try_token_bytes()?;
+        std::str::from_utf8(token).ok()?.parse().ok()
+    }
+
+    /// Checks whether there are more tokens in the input, without consuming
+    /// them (blank lines are skipped).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::io::Scanner;
+    ///
+    /// let mut scan = Scanner::new(b"1 2\n3\n   \n".as_ref());
+    /// let mut rows = vec![];
+    /// while scan.has_next() {
+    ///     rows.push(scan.u());
+    /// }
+    /// assert_eq!(rows, vec![1, 2, 3]);
+    /// ```
+    pub fn has_next(&mut self) -> bool {
+        self.seek(0, |sep, b| !sep[usize::from(b)]).is_some()
+    }
+
+    /// Reads the remainder of the current line, as a `String`, without the
+    /// line terminator (`\n` or `\r\n`).
+    ///
+    /// The line is returned starting from its next token, as is (including
+    /// spaces and delimiters). If no tokens are left on the current line (e.g.
+    /// the number of lines has just been read), the whole next line is
+    /// returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the input is exhausted, or the line is not valid UTF-8.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::io::Scanner;
+    ///
+    /// let mut scan = Scanner::new(b"2\nhello,  world\n7 Jane Doe \r\n".as_ref());
+    /// let n = scan.u();
+    /// assert_eq!(n, 2);
+    /// assert_eq!(scan.line(), "hello,  world");
+    /// assert_eq!(scan.u(), 7);
+    /// assert_eq!(scan.line(), "Jane Doe ");
+    /// ```
+    pub fn line(&mut self) -> String {
+        String::from_utf8(self.line_bytes()).expect("Line is not valid UTF-8")
+    }
+
+    /// Reads the remainder of the current line as bytes, see [`Scanner::line`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the input is exhausted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::io::Scanner;
+    ///
+    /// let mut scan = Scanner::new(b"3 #.#\n..#\n".as_ref());
+    /// scan.u();
+    /// assert_eq!(scan.line_bytes(), b"#.#");
+    /// assert_eq!(scan.line_bytes(), b"..#");
+    /// ```
+    pub fn line_bytes(&mut self) -> Vec<u8> {
+        self.try_line_bytes().expect("Unexpected end of input")
+    }
+
+    /// Returns an iterator over the remaining lines of the input, as
+    /// `String`s, until the end of input. The first line is the remainder of
+    /// the current one, see [`Scanner::line`].
+    ///
+    /// # Panics
+    ///
+    /// Iterator panics if some line is not valid UTF-8.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::io::Scanner;
+    ///
+    /// let mut scan = Scanner::new(b"2\nfirst line\n\n  last one".as_ref());
+    /// assert_eq!(scan.u(), 2);
+    /// let lines: Vec<String> = scan.lines().collect();
+    /// assert_eq!(lines, vec!["first line", "", "  last one"]);
+    /// ```
+    pub fn lines(&mut self) -> impl Iterator<Item = String> + '_ {
+        std::iter::from_fn(move || {
+            self.try_line_bytes()
+                .map(|line| String::from_utf8(line).expect("Line is not valid UTF-8"))
+        })
+    }
+
+    /// Reads all the remaining tokens, parsing them into `T`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if some token can't be parsed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::io::Scanner;
+    ///
+    /// let mut scan = Scanner::new(b"words: a b\nc\n\n".as_ref());
+    /// scan.string();
+    /// let rest: Vec<String> = scan.remaining_tokens();
+    /// assert_eq!(rest, vec!["a", "b", "c"]);
+    /// assert!(scan.remaining_tokens::<u8>().is_empty());
+    /// ```
+    pub fn remaining_tokens<T: std::str::FromStr>(&mut self) -> Vec<T> {
+        let mut res = Vec::new();
+        while let Some(token) = self.try_token() {
+            res.push(token.parse().ok().expect("Failed parse"));
+        }
+        res
+    }
+
+    /// Reads the remainder of the current line, see [`Scanner::line`], or
+    /// returns `None` if the input is exhausted.
+    fn try_line_bytes(&mut self) -> Option<Vec<u8>> {
+        let mut start = 0;
+        if !self.line_start {
+            // Skip to the next token, or to the next line, if there is none.
+            start = match self.seek(0, |sep, b| b == b'\n' || !sep[usize::from(b)]) {
+                Some(i) if self.buffer[self.pos + i] != b'\n' => i,
+                Some(i) => i + 1,
+                None => self.end - self.pos,
+            };
+        }
+        let (end, next) = match self.seek(start, |_, b| b == b'\n') {
+            Some(i) => (i, i + 1),
+            None => {
+                let len = self.end - self.pos;
+                if start >= len {
+                    return None;
+                }
+                (len, len)
+            }
+        };
+        let mut line = &self.buffer[self.pos + start..self.pos + end];
+        line = line.strip_suffix(b"\r").unwrap_or(line);
+        let line = line.to_vec();
+        self.pos += next;
+        self.line_start = true;
+        Some(line)
+    }
+
+    /// Returns the next whitespace (or delimiter) separated token, reading
+    /// more input if necessary.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the input is exhausted.
+    fn token(&mut self) -> &str {
+        self.try_token().expect("Unexpected end of input")
+    }
+
+    /// Returns the next token, or `None` if the input is exhausted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the token is not valid UTF-8.
+    fn try_token(&mut self) -> Option<&str> {
+        self.try_token_bytes().map(|token| {
+            std::str::from_utf8(token).unwrap_or_else(|_| {
+                panic!(
+                    "Failed parse: {} is not valid UTF-8",
+                    String::from_utf8_lossy(token)
+                )
+            })
+        })
+    }
+
+    /// Returns the bytes of the next token.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the input is exhausted.
+    fn token_bytes(&mut self) -> &[u8] {
+        self.try_token_bytes().expect("Unexpected end of input")
+    }
+
+    /// Returns the bytes of the next token, or `None` if the input is
+    /// exhausted.
+    fn try_token_bytes(&mut self) -> Option<&[u8]> {
+        let start = self.seek(0, |sep, b| !sep[usize::from(b)])?;
+        let end = self
+            .seek(start, |sep, b| sep[usize::from(b)])
+            .unwrap_or(self.end - self.pos);
+        let token = self.pos + start..self.pos + end;
+        self.pos += end;
+        self.line_start = false;
+        Some(&self.buffer[token])
+    }
+
+    /// Returns the offset (relative to the unconsumed input) of the first byte
+    /// at or after `from`, satisfying the predicate, reading more input if
+    /// necessary. Returns `None` if there is no such byte, in which case the
+    /// whole rest of the input is read.
+    fn seek<F: Fn(&[bool; 256], u8) -> bool>(&mut self, from: usize, f: F) -> Option<usize> {
+        let mut i = self.pos + from;
+        loop {
+            while i < self.end {
+                if f(&self.separators, self.buffer[i]) {
+                    return Some(i - self.pos);
+                }
+                i += 1;
+            }
+            i -= self.pos;
+            if !self.fill() {
+                return None;
+            }
+            // `fill` may move the unconsumed input to the front of the buffer.
+            i += self.pos;
+        }
+    }
+
+    /// Reads the next chunk into the free space of the buffer, dropping the
+    /// consumed input only when there is no free space left. Returns `false`
+    /// at the end of input.
+    fn fill(&mut self) -> bool {
+        if self.eof {
+            return false;
+        }
+        if self.pos == self.end {
+            self.pos = 0;
+            self.end = 0;
+        }
+        if self.end == self.buffer.len() {
+            self.buffer.copy_within(self.pos..self.end, 0);
+            self.end -= self.pos;
+            self.pos = 0;
+            // The buffer is full of unconsumed input, e.g. of a long token.
+            if self.end == self.buffer.len() {
+                let len = (2 * self.buffer.len()).max(CHUNK_SIZE);
+                self.buffer.resize(len, 0);
+            }
+        }
+        let n = loop {
+            match self.reader.read(&mut self.buffer[self.end..]) {
+                Ok(n) => break n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => panic!("Failed read: {e}"),
+            }
+        };
+        self.end += n;
+        self.eof = n == 0;
+        n > 0
     }
 
     /// Reads multiple test cases from the input, applying the provided function
@@ -355,6 +1479,31 @@ impl<R: BufRead> Scanner<R> {
         }
     }
 
+    /// Same as [`test_cases`](Scanner::test_cases), but also passes the
+    /// 1-based index of the test case to `f`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::io::{Scanner, Writer, wln_case};
+    ///
+    /// let mut scan = Scanner::new(b"2\n1 2\n3 4\n".as_ref());
+    /// let mut out = Vec::new();
+    /// let mut w = Writer::new(&mut out);
+    /// scan.test_cases_indexed(&mut |t, scan| {
+    ///     let (x, y) = scan.u2();
+    ///     wln_case!(w, t, "{}", x * y);
+    /// });
+    /// drop(w);
+    /// assert_eq!(out, b"Case #1: 2\nCase #2: 12\n");
+    /// ```
+    pub fn test_cases_indexed<F: FnMut(usize, &mut Self)>(&mut self, f: &mut F) {
+        let t = self.u();
+        for i in 1..=t {
+            f(i, self);
+        }
+    }
+
     /// Reads the next token as a `usize`.
     ///
     /// # Example
@@ -368,7 +1517,7 @@ impl<R: BufRead> Scanner<R> {
     /// assert_eq!(x, 42);
     /// ```
     pub fn u(&mut self) -> usize {
-        self.next()
+        self.int()
     }
 
     /// Reads pair of `usize` values.
@@ -402,9 +1551,61 @@ impl<R: BufRead> Scanner<R> {
         (self.u(), self.u(), self.u(), self.u())
     }
 
+    /// Reads the next token as a 1-based `usize` index, and converts it to a
+    /// 0-based one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is `0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::io::Scanner;
+    ///
+    /// let mut scan = Scanner::new(b"3 1\n".as_ref());
+    /// assert_eq!(scan.u1(), 2);
+    /// assert_eq!(scan.u1(), 0);
+    /// ```
+    pub fn u1(&mut self) -> usize {
+        self.u()
+            .checked_sub(1)
+            .expect("Index 0 is not a valid 1-based index")
+    }
+
+    /// Reads `n` 1-based indexes, converting them to 0-based ones, e.g. a
+    /// permutation or parents of nodes.
+    ///
+    /// See also [`u1`](Scanner::u1).
+    pub fn vec_u1(&mut self, n: usize) -> Vec<usize> {
+        (0..n).map(|_| self.u1()).collect()
+    }
+
+    /// Reads an edge, given by two 1-based vertex indexes, as a pair of
+    /// 0-based indexes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::io::Scanner;
+    ///
+    /// let mut scan = Scanner::new(b"3 2\n1 2\n2 3\n".as_ref());
+    /// let (n, m) = scan.u2();
+    /// let mut adj = vec![vec![]; n];
+    /// for _ in 0..m {
+    ///     let (u, v) = scan.edge1();
+    ///     adj[u].push(v);
+    ///     adj[v].push(u);
+    /// }
+    /// assert_eq!(adj, vec![vec![1], vec![0, 2], vec![1]]);
+    /// ```
+    pub fn edge1(&mut self) -> (usize, usize) {
+        (self.u1(), self.u1())
+    }
+
     /// Reads the next token as an `i32`.
     pub fn i(&mut self) -> i32 {
-        self.next()
+        self.int()
     }
 
     /// Reads pair of `i32` values.
@@ -422,6 +1623,58 @@ impl<R: BufRead> Scanner<R> {
         (self.i(), self.i(), self.i(), self.i())
     }
 
+    /// Reads the next token as an `i64`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::io::Scanner;
+    ///
+    /// let mut scan = Scanner::new(b"-5000000000 1 2 3 4 5\n".as_ref());
+    /// assert_eq!(scan.l(), -5_000_000_000);
+    /// assert_eq!(scan.l2(), (1, 2));
+    /// assert_eq!(scan.l3(), (3, 4, 5));
+    /// ```
+    pub fn l(&mut self) -> i64 {
+        self.int()
+    }
+
+    /// Reads pair of `i64` values.
+    pub fn l2(&mut self) -> (i64, i64) {
+        (self.l(), self.l())
+    }
+
+    /// Reads triplet of `i64` values.
+    pub fn l3(&mut self) -> (i64, i64, i64) {
+        (self.l(), self.l(), self.l())
+    }
+
+    /// Reads the next token as a `u64`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::io::Scanner;
+    ///
+    /// let mut scan = Scanner::new(b"18446744073709551615 1 2 3 4 5\n".as_ref());
+    /// assert_eq!(scan.ul(), u64::MAX);
+    /// assert_eq!(scan.ul2(), (1, 2));
+    /// assert_eq!(scan.ul3(), (3, 4, 5));
+    /// ```
+    pub fn ul(&mut self) -> u64 {
+        self.int()
+    }
+
+    /// Reads pair of `u64` values.
+    pub fn ul2(&mut self) -> (u64, u64) {
+        (self.ul(), self.ul())
+    }
+
+    /// Reads triplet of `u64` values.
+    pub fn ul3(&mut self) -> (u64, u64, u64) {
+        (self.ul(), self.ul(), self.ul())
+    }
+
     /// Reads pair of values of type `T`.
     ///
     /// # Example
@@ -445,11 +1698,30 @@ impl<R: BufRead> Scanner<R> {
 
     /// Reads triplet of values of type `T`.
     ///
-    /// See also [`pair`](Scanner::pair).
+    /// See also [`pair`](Scanner::pair), and [`read`](Scanner::read) for values
+    /// of different types.
     pub fn triplet<T: std::str::FromStr>(&mut self) -> (T, T, T) {
         (self.next(), self.next(), self.next())
     }
 
+    /// Reads a value of any [`Readable`] type, e.g. a tuple of different
+    /// types, or a vector prefixed by its length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::io::Scanner;
+    ///
+    /// let mut scan = Scanner::new(b"3 -7 abc\n2 10 20\n".as_ref());
+    /// let (n, x, s) = scan.read::<(usize, i64, String)>();
+    /// assert_eq!((n, x, s.as_str()), (3, -7, "abc"));
+    /// let v: Vec<u32> = scan.read();
+    /// assert_eq!(v, vec![10, 20]);
+    /// ```
+    pub fn read<T: Readable>(&mut self) -> T {
+        T::read(self)
+    }
+
     /// Gets the next token as a `String`.
     pub fn string(&mut self) -> String {
         self.next()
@@ -483,42 +1755,377 @@ impl<R: BufRead> Scanner<R> {
         result
     }
 
-    /// Reads a vector of `T` from the input, where `n` is the number of
-    /// elements, and the first element is a default value for `T`.
+    /// Reads `n` pairs of values, e.g. edges or points, given one pair after
+    /// another.
     ///
     /// # Example
+    ///
     /// ```
-    /// use {algorist::io::Scanner, std::io::BufReader};
+    /// use algorist::io::Scanner;
     ///
-    /// let input = b"1 2 3\n";
-    /// let mut scan = Scanner::new(BufReader::new(input.as_ref()));
-    /// let v: Vec<i32> = scan.vec_padded(3);
-    /// assert_eq!(v, vec![0, 1, 2, 3]);
+    /// let mut scan = Scanner::new(b"1 a\n2 b\n".as_ref());
+    /// let v = scan.vec_pairs::<u32, char>(2);
+    /// assert_eq!(v, vec![(1, 'a'), (2, 'b')]);
     /// ```
-    pub fn vec_padded<T: std::str::FromStr + Default>(&mut self, n: usize) -> Vec<T> {
-        let mut result = Vec::with_capacity(n + 1);
-        result.push(T::default());
-        (0..n).for_each(|_| result.push(self.next()));
-        result
+    pub fn vec_pairs<T: std::str::FromStr, U: std::str::FromStr>(
+        &mut self,
+        n: usize,
+    ) -> Vec<(T, U)> {
+        (0..n).map(|_| (self.next(), self.next())).collect()
     }
 
-    /// Reads a `VecDeque<T>` from the input, where `n` is the number of
-    /// elements to read.
+    /// Reads `n` pairs of values, given one pair after another, and returns
+    /// them as two vectors: of the first and of the second components.
     ///
     /// # Example
+    ///
     /// ```
-    /// use {
-    ///     algorist::io::Scanner,
-    ///     std::{collections::VecDeque, io::BufReader},
-    /// };
+    /// use algorist::io::Scanner;
     ///
-    /// let input = b"1 2 3\n";
-    /// let mut scan = Scanner::new(BufReader::new(input.as_ref()));
-    /// let v: VecDeque<i32> = scan.vec_deque(3);
-    /// assert_eq!(v, VecDeque::from(vec![1, 2, 3]));
+    /// // Positions and speeds of 3 cars.
+    /// let mut scan = Scanner::new(b"0 5\n10 2\n3 4\n".as_ref());
+    /// let (x, v) = scan.unzip2::<i64>(3);
+    /// assert_eq!(x, vec![0, 10, 3]);
+    /// assert_eq!(v, vec![5, 2, 4]);
     /// ```
-    pub fn vec_deque<T: std::str::FromStr>(&mut self, n: usize) -> VecDeque<T> {
-        let mut result = VecDeque::with_capacity(n);
+    pub fn unzip2<T: std::str::FromStr>(&mut self, n: usize) -> (Vec<T>, Vec<T>) {
+        let (mut a, mut b) = (Vec::with_capacity(n), Vec::with_capacity(n));
+        for _ in 0..n {
+            a.push(self.next());
+            b.push(self.next());
+        }
+        (a, b)
+    }
+
+    /// Reads `n` triplets of values, given one triplet after another, and
+    /// returns them as three vectors, one per component.
+    ///
+    /// See also [`unzip2`](Scanner::unzip2).
+    pub fn unzip3<T: std::str::FromStr>(&mut self, n: usize) -> (Vec<T>, Vec<T>, Vec<T>) {
+        let (mut a, mut b, mut c) = (
+            Vec::with_capacity(n),
+            Vec::with_capacity(n),
+            Vec::with_capacity(n),
+        );
+        for _ in 0..n {
+            a.push(self.next());
+            b.push(self.next());
+            c.push(self.next());
+        }
+        (a, b, c)
+    }
+
+    /// Reads an undirected graph of `n` vertices and `m` edges, given as pairs
+    /// of 1-based vertices, into adjacency lists (0-based), the format used by
+    /// the [`graph`](crate::graph) module.
+    ///
+    /// Every edge `{u, v}` is added to both `adj[u]` and `adj[v]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if some vertex is not in `1..=n`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::io::Scanner;
+    ///
+    /// let mut scan = Scanner::new(b"3 2\n1 2\n3 2\n".as_ref());
+    /// let (n, m) = scan.u2();
+    /// let adj = scan.graph(n, m);
+    /// assert_eq!(adj, vec![vec![1], vec![0, 2], vec![1]]);
+    /// ```
+    #[cfg(feature = "graph")]
+    pub fn graph(&mut self, n: usize, m: usize) -> Vec<Vec<usize>> {
+        let mut adj = vec![vec![]; n];
+        for _ in 0..m {
+            let (u, v) = self.vertex_pair(n);
+            adj[u].push(v);
+            adj[v].push(u);
+        }
+        adj
+    }
+
+    /// Reads a tree of `n` vertices, given as `n - 1` edges, see
+    /// [`Scanner::graph`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::io::Scanner;
+    ///
+    /// let mut scan = Scanner::new(b"3\n1 2\n1 3\n".as_ref());
+    /// let n = scan.u();
+    /// let adj = scan.tree(n);
+    /// assert_eq!(adj, vec![vec![1, 2], vec![0], vec![0]]);
+    /// ```
+    #[cfg(feature = "graph")]
+    pub fn tree(&mut self, n: usize) -> Vec<Vec<usize>> {
+        self.graph(n, n.saturating_sub(1))
+    }
+
+    /// Reads an undirected weighted graph of `n` vertices and `m` edges, given
+    /// as triplets `u v w` (with 1-based vertices), into adjacency lists of
+    /// `(vertex, weight)` pairs (0-based), see [`Scanner::graph`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if some vertex is not in `1..=n`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::io::Scanner;
+    ///
+    /// let mut scan = Scanner::new(b"1 2 5\n2 3 -1\n".as_ref());
+    /// let adj = scan.weighted_graph::<i64>(3, 2);
+    /// assert_eq!(adj, vec![vec![(1, 5)], vec![(0, 5), (2, -1)], vec![(
+    ///     1, -1
+    /// )]]);
+    /// ```
+    #[cfg(feature = "graph")]
+    pub fn weighted_graph<W: Clone + std::str::FromStr>(
+        &mut self,
+        n: usize,
+        m: usize,
+    ) -> Vec<Vec<(usize, W)>> {
+        let mut adj = vec![vec![]; n];
+        for _ in 0..m {
+            let (u, v) = self.vertex_pair(n);
+            let w: W = self.next();
+            adj[u].push((v, w.clone()));
+            adj[v].push((u, w));
+        }
+        adj
+    }
+
+    /// Reads an edge of a graph of `n` vertices, see [`Scanner::edge1`].
+    #[cfg(feature = "graph")]
+    fn vertex_pair(&mut self, n: usize) -> (usize, usize) {
+        let (u, v) = self.edge1();
+        assert!(u < n && v < n, "Edge {} {} is out of bounds", u + 1, v + 1);
+        (u, v)
+    }
+
+    /// Reads a grid of `rows * cols` values of type `T`, separated by
+    /// whitespace, see [`Arr::from_scan`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::io::Scanner;
+    ///
+    /// let mut scan = Scanner::new(b"1 2 3\n4 5 6\n".as_ref());
+    /// let grid = scan.grid::<i32>(2, 3);
+    /// assert_eq!(grid[1], [4, 5, 6]);
+    /// ```
+    pub fn grid<T: Clone + std::fmt::Debug + std::str::FromStr>(
+        &mut self,
+        rows: usize,
+        cols: usize,
+    ) -> Arr<T> {
+        Arr::from_scan(self, rows, cols)
+    }
+
+    /// Reads a grid of characters (as bytes), given as `rows` tokens of
+    /// length `cols`, e.g. a maze of `.` and `#`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if some row is not of length `cols`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::io::Scanner;
+    ///
+    /// let mut scan = Scanner::new(b"#..\n.#.\n".as_ref());
+    /// let grid = scan.char_grid(2, 3);
+    /// assert_eq!(grid[0], *b"#..");
+    /// assert_eq!(grid[(1, 1)], b'#');
+    /// ```
+    pub fn char_grid(&mut self, rows: usize, cols: usize) -> Arr<u8> {
+        self.char_grid_map(rows, cols, (0, 0), |c| c)
+    }
+
+    /// Reads a grid of characters, like [`Scanner::char_grid`], surrounded by
+    /// a border of `pad` characters, so that the grid is of size
+    /// `(rows + 2) * (cols + 2)`, and neighbours of the original cells never
+    /// need bounds checks.
+    ///
+    /// # Panics
+    ///
+    /// Panics if some row is not of length `cols`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::io::Scanner;
+    ///
+    /// let mut scan = Scanner::new(b"..\n.#\n".as_ref());
+    /// let grid = scan.char_grid_padded(2, 2, b'#');
+    /// assert_eq!(grid.rows(), 4);
+    /// assert_eq!(grid[0], *b"####");
+    /// assert_eq!(grid[1], *b"#..#");
+    /// assert_eq!(grid[2], *b"#.##");
+    /// ```
+    pub fn char_grid_padded(&mut self, rows: usize, cols: usize, pad: u8) -> Arr<u8> {
+        self.char_grid_map(rows, cols, (1, pad), |c| c)
+    }
+
+    /// Reads a grid of digits, given as `rows` tokens of length `cols`, and
+    /// maps `'0'..='9'` to `0..=9`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if some row is not of length `cols`, or contains a non-digit.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::io::Scanner;
+    ///
+    /// let mut scan = Scanner::new(b"019\n250\n".as_ref());
+    /// let grid = scan.digit_grid(2, 3);
+    /// assert_eq!(grid[0], [0, 1, 9]);
+    /// assert_eq!(grid[1], [2, 5, 0]);
+    /// ```
+    pub fn digit_grid(&mut self, rows: usize, cols: usize) -> Arr<u8> {
+        self.char_grid_map(rows, cols, (0, 0), |c| {
+            assert!(c.is_ascii_digit(), "Not a digit: {}", char::from(c));
+            c - b'0'
+        })
+    }
+
+    /// Reads `rows` tokens of length `cols`, mapping their bytes with `f`,
+    /// into a grid with a border of the given width, filled with the given
+    /// value.
+    fn char_grid_map<F: Fn(u8) -> u8>(
+        &mut self,
+        rows: usize,
+        cols: usize,
+        (border, pad): (usize, u8),
+        f: F,
+    ) -> Arr<u8> {
+        let width = cols + 2 * border;
+        let mut data = vec![pad; (rows + 2 * border) * width];
+        for i in 0..rows {
+            let row = self.token_bytes();
+            assert_eq!(
+                row.len(),
+                cols,
+                "Row {i} has length {}, expected {cols}",
+                row.len()
+            );
+            let start = (i + border) * width + border;
+            for (cell, &c) in data[start..start + cols].iter_mut().zip(row) {
+                *cell = f(c);
+            }
+        }
+        Arr::from_vec(data, rows + 2 * border, width)
+    }
+
+    /// Reads the next token as a [`Modulo`] number, reducing it digit by digit,
+    /// so that literals of any length (e.g. with thousands of digits) are
+    /// supported.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the token is not a (possibly negative) decimal integer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::{io::Scanner, math::modulo::Mod7};
+    ///
+    /// let input = b"123456789012345678901234567890 -1\n";
+    /// let mut scan = Scanner::new(input.as_ref());
+    /// assert_eq!(scan.next_mod::<i64, _>(), Mod7::new(197_434_842));
+    /// let x: Mod7 = scan.next_mod();
+    /// assert_eq!(x.val(), 1_000_000_006);
+    /// ```
+    pub fn next_mod<T, M>(&mut self) -> Modulo<T, M>
+    where
+        T: Number + Downcast,
+        T::Source: Number,
+        M: ConstValue<T>,
+    {
+        let token = self.token();
+        let (neg, digits) = match token.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, token),
+        };
+        assert!(
+            !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()),
+            "Failed parse: {token}"
+        );
+        let ten = Modulo::new(T::new(10));
+        let val = digits.bytes().fold(Modulo::new(T::zero()), |acc, b| {
+            acc * ten + Modulo::new(T::new(usize::from(b - b'0')))
+        });
+        if neg { -val } else { val }
+    }
+
+    /// Reads a vector of `n` [`Modulo`] numbers, see [`Scanner::next_mod`].
+    ///
+    /// Reading with `scan.vec::<Mod7>(n)` works too, but the numbers must fit
+    /// into the underlying type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::{io::Scanner, math::modulo::Mod7};
+    ///
+    /// let input = b"1000000007 1000000008 99999999999999999999\n";
+    /// let mut scan = Scanner::new(input.as_ref());
+    /// let v: Vec<Mod7> = scan.mod_vec(3);
+    /// assert_eq!(v, vec![Mod7::new(0), Mod7::new(1), Mod7::new(4_899)]);
+    /// ```
+    pub fn mod_vec<T, M>(&mut self, n: usize) -> Vec<Modulo<T, M>>
+    where
+        T: Number + Downcast,
+        T::Source: Number,
+        M: ConstValue<T>,
+    {
+        (0..n).map(|_| self.next_mod()).collect()
+    }
+
+    /// Reads a vector of `T` from the input, where `n` is the number of
+    /// elements, and the first element is a default value for `T`.
+    ///
+    /// # Example
+    /// ```
+    /// use {algorist::io::Scanner, std::io::BufReader};
+    ///
+    /// let input = b"1 2 3\n";
+    /// let mut scan = Scanner::new(BufReader::new(input.as_ref()));
+    /// let v: Vec<i32> = scan.vec_padded(3);
+    /// assert_eq!(v, vec![0, 1, 2, 3]);
+    /// ```
+    pub fn vec_padded<T: std::str::FromStr + Default>(&mut self, n: usize) -> Vec<T> {
+        let mut result = Vec::with_capacity(n + 1);
+        result.push(T::default());
+        (0..n).for_each(|_| result.push(self.next()));
+        result
+    }
+
+    /// Reads a `VecDeque<T>` from the input, where `n` is the number of
+    /// elements to read.
+    ///
+    /// # Example
+    /// ```
+    /// use {
+    ///     algorist::io::Scanner,
+    ///     std::{collections::VecDeque, io::BufReader},
+    /// };
+    ///
+    /// let input = b"1 2 3\n";
+    /// let mut scan = Scanner::new(BufReader::new(input.as_ref()));
+    /// let v: VecDeque<i32> = scan.vec_deque(3);
+    /// assert_eq!(v, VecDeque::from(vec![1, 2, 3]));
+    /// ```
+    pub fn vec_deque<T: std::str::FromStr>(&mut self, n: usize) -> VecDeque<T> {
+        let mut result = VecDeque::with_capacity(n);
         (0..n).for_each(|_| result.push_back(self.next()));
         result
     }
@@ -550,16 +2157,67 @@ impl<R: BufRead> Scanner<R> {
     }
 }
 
-fn wv<W: Write, T: std::fmt::Display>(w: &mut W, v: &[T]) {
-    write!(
-        w,
-        "{}",
-        v.iter()
-            .map(ToString::to_string)
-            .collect::<Vec<_>>()
-            .join(" ")
-    )
-    .unwrap();
+fn wv<W, I>(w: &mut W, v: I)
+where
+    W: Write,
+    I: IntoIterator,
+    I::Item: std::fmt::Display,
+{
+    wv_sep(w, v, " ");
+}
+
+/// Writes values of the slice (or any other iterable), separated by `sep`,
+/// without a newline.
+///
+/// # Example
+///
+/// ```
+/// use algorist::io::{Writer, wln, wv_sep};
+///
+/// let mut out = Vec::new();
+/// let mut w = Writer::new(&mut out);
+/// wv_sep(&mut w, &[1, 2, 3], ", ");
+/// wln!(w);
+/// wv_sep(&mut w, ["ab", "cd"], "");
+/// drop(w);
+/// assert_eq!(out, b"1, 2, 3\nabcd");
+/// ```
+pub fn wv_sep<W, I>(w: &mut W, v: I, sep: &str)
+where
+    W: Write,
+    I: IntoIterator,
+    I::Item: std::fmt::Display,
+{
+    for (i, x) in v.into_iter().enumerate() {
+        if i > 0 {
+            write!(w, "{sep}").unwrap();
+        }
+        write!(w, "{x}").unwrap();
+    }
+}
+
+/// Writes values of the slice (or any other iterable), each on its own line.
+///
+/// # Example
+///
+/// ```
+/// use algorist::io::{Writer, wvln_each};
+///
+/// let mut out = Vec::new();
+/// let mut w = Writer::new(&mut out);
+/// wvln_each(&mut w, (1..=3).map(|x| x * 10));
+/// drop(w);
+/// assert_eq!(out, b"10\n20\n30\n");
+/// ```
+pub fn wvln_each<W, I>(w: &mut W, v: I)
+where
+    W: Write,
+    I: IntoIterator,
+    I::Item: std::fmt::Display,
+{
+    for x in v {
+        writeln!(w, "{x}").unwrap();
+    }
 }
 
 /// A macro for writing a line with formatted output.
@@ -590,15 +2248,406 @@ macro_rules! wln_impl {
 }
 pub use wln_impl as wln;
 
-pub fn wvln<W: Write, T: std::fmt::Display>(w: &mut W, v: &[T]) {
+/// A macro for writing a line in the `Case #t: ...` format, used by Google
+/// contests.
+///
+/// Without the format string, writes `Case #t:` only, e.g. to be followed by
+/// a multi-line answer.
+///
+/// # Example
+///
+/// ```
+/// use algorist::io::{Writer, wln, wln_case};
+///
+/// let mut out = Vec::new();
+/// let mut w = Writer::new(&mut out);
+/// wln_case!(w, 1, "{} {}", 2, 3);
+/// wln_case!(w, 2);
+/// wln!(w, "..#");
+/// drop(w);
+/// assert_eq!(out, b"Case #1: 2 3\nCase #2:\n..#\n");
+/// ```
+#[macro_export]
+macro_rules! wln_case_impl {
+    ($w:expr, $t:expr $(,)?) => {{
+        let _ = writeln!($w, "Case #{}:", $t);
+    }};
+    ($w:expr, $t:expr, $($arg:tt)+) => {{
+        let _ = writeln!($w, "Case #{}: {}", $t, format_args!($($arg)+));
+    }};
+}
+pub use wln_case_impl as wln_case;
+
+/// A macro for writing a line of space separated floating point numbers, each
+/// with exactly `digits` digits after the decimal point (see [`Fixed`]):
+/// `wfix!(w, digits, x, y, ...)`.
+///
+/// # Example
+///
+/// ```
+/// use algorist::io::{Writer, wfix};
+///
+/// let mut out = Vec::new();
+/// let mut w = Writer::new(&mut out);
+/// let (x, y) = (0.5, -1.0 / 3.0);
+/// wfix!(w, 3, x, y, 2);
+/// wfix!(w, 1, 1e-7);
+/// drop(w);
+/// assert_eq!(out, b"0.500 -0.333 2.000\n0.0\n");
+/// ```
+#[macro_export]
+macro_rules! wfix_impl {
+    ($w:expr, $digits:expr, $($x:expr),+ $(,)?) => {{
+        let digits: usize = $digits;
+        for (i, x) in [$($x as f64),+].into_iter().enumerate() {
+            let sep = if i == 0 { "" } else { " " };
+            let _ = write!($w, "{}{}", sep, $crate::io::Fixed(x, digits));
+        }
+        let _ = writeln!($w);
+    }};
+}
+pub use wfix_impl as wfix;
+
+/// A macro for interactive problems: writes a line with formatted output,
+/// flushes the writer, and reads the response with [`Scanner::read`] (so the
+/// type of the response is usually inferred).
+///
+/// # Example
+///
+/// ```
+/// use algorist::io::{Scanner, Writer, query};
+///
+/// // The judge's responses.
+/// let mut scan = Scanner::new(b"5\n1 0\n".as_ref());
+/// let mut out = Vec::new();
+/// let mut w = Writer::new(&mut out);
+/// let sum: u32 = query!(w, scan, "? {} {}", 2, 3);
+/// let (x, y): (u8, u8) = query!(w, scan, "? bits {}", sum);
+/// assert_eq!((sum, x, y), (5, 1, 0));
+/// drop(w);
+/// assert_eq!(out, b"? 2 3\n? bits 5\n");
+/// ```
+#[macro_export]
+macro_rules! query_impl {
+    ($w:expr, $scan:expr, $($arg:tt)+) => {{
+        let _ = writeln!($w, $($arg)+);
+        let _ = $w.flush();
+        $scan.read()
+    }};
+}
+pub use query_impl as query;
+
+/// Prints labeled values of expressions to stderr, for debugging.
+///
+/// Each expression is printed as `expr = {:?}`, prefixed by the location of
+/// the call. Expressions are only borrowed, so they can be used afterwards.
+///
+/// The macro expands to nothing if the `judge` feature is enabled, or if the
+/// code is compiled with `--cfg ONLINE_JUDGE` (as Codeforces does), so debug
+/// prints never slow down, or leak into, submissions.
+///
+/// # Example
+///
+/// ```
+/// use algorist::io::dbgln;
+///
+/// let (n, v) = (3, vec![1, 2]);
+/// // Prints `[src/main.rs:5] n = 3 v.len() + 1 = 3 v = [1, 2]`.
+/// dbgln!(n, v.len() + 1, v);
+/// assert_eq!(v.len(), 2);
+/// ```
+#[cfg(not(any(feature = "judge", ONLINE_JUDGE)))]
+#[macro_export]
+macro_rules! dbgln_impl {
+    () => {
+        eprintln!("[{}:{}]", file!(), line!())
+    };
+    ($($e:expr),+ $(,)?) => {{
+        let mut line = format!("[{}:{}]", file!(), line!());
+        $(
+            line += &format!(" {} = {:?}", stringify!($e), &$e);
+        )*
+        eprintln!("{}", line);
+    }};
+}
+
+/// Prints labeled values of expressions to stderr, for debugging (disabled by
+/// the `judge` feature or the `ONLINE_JUDGE` cfg).
+#[cfg(any(feature = "judge", ONLINE_JUDGE))]
+#[macro_export]
+macro_rules! dbgln_impl {
+    // The arguments are still type checked (and count as used), but never
+    // evaluated.
+    ($($e:expr),* $(,)?) => {{
+        if false {
+            $(let _ = &$e;)*
+        }
+    }};
+}
+pub use dbgln_impl as dbgln;
+
+/// Writes space separated values of the slice (or any other iterable),
+/// followed by a newline.
+///
+/// Works with any writer, including [`Writer`], and any displayable values,
+/// including [`Modulo`] numbers. For integers, [`Writer::ints`] is faster.
+///
+/// # Example
+///
+/// ```
+/// use algorist::{
+///     io::{Writer, wvln},
+///     math::modulo::Mod7,
+/// };
+///
+/// let mut out = Vec::new();
+/// let mut w = Writer::new(&mut out);
+/// wvln(&mut w, &[Mod7::new(-1), Mod7::new(2)]);
+/// wvln(&mut w, &[1, 2, 3]);
+/// wvln(&mut w, "abc".chars().rev());
+/// drop(w);
+/// assert_eq!(out, b"1000000006 2\n1 2 3\nc b a\n");
+/// ```
+pub fn wvln<W, I>(w: &mut W, v: I)
+where
+    W: Write,
+    I: IntoIterator,
+    I::Item: std::fmt::Display,
+{
     wv(w, v);
     writeln!(w).ok();
 }
 
+/// Runs the solver on each sample input, and checks that its output matches
+/// the expected one, so that solutions can be tested with `cargo test`.
+///
+/// Outputs are compared token by token, so whitespace differences are
+/// ignored, and numbers with a fractional part (or an exponent) are compared
+/// with an absolute or relative error of `1e-6`.
+///
+/// The solver must be generic over the reader and the writer (or accept the
+/// types from the signature), so that `main` can pass it to [`test_case()`]
+/// as well. See [`macro@run_samples`] for a shorter way to call it.
+///
+/// # Panics
+///
+/// Panics with both outputs, and the first mismatching token, if some output
+/// doesn't match.
+///
+/// # Example
+///
+/// ```
+/// use {
+///     algorist::io::{Scanner, Writer, check_samples, wln},
+///     std::io::{BufRead, Write},
+/// };
+///
+/// fn solve<R: BufRead, W: Write>(scan: &mut Scanner<R>, w: &mut Writer<W>) {
+///     scan.test_cases(&mut |scan| {
+///         let (a, b) = scan.u2();
+///         wln!(w, "{}", a as f64 / b as f64);
+///     });
+/// }
+///
+/// check_samples(&mut solve, &[("2\n1 3\n4 2\n", "0.333333333\n2.0\n")]);
+/// ```
+pub fn check_samples<F>(f: &mut F, samples: &[(&str, &str)])
+where
+    F: FnMut(&mut Scanner<io::Cursor<Vec<u8>>>, &mut Writer<Vec<u8>>),
+{
+    for (i, &(input, expected)) in samples.iter().enumerate() {
+        let mut scan = Scanner::new(io::Cursor::new(input.as_bytes().to_vec()));
+        let mut w = Writer::new(Vec::new());
+        f(&mut scan, &mut w);
+        let out = w.0.into_inner().expect("Failed write");
+        let actual = String::from_utf8_lossy(&out);
+        if let Err(mismatch) = compare_outputs(expected, &actual) {
+            panic!(
+                "Sample {} failed: {mismatch}\n--- expected ---\n{expected}\n--- found \
+                 ---\n{actual}",
+                i + 1
+            );
+        }
+    }
+}
+
+/// Compares outputs token by token, see [`check_samples`].
+fn compare_outputs(expected: &str, actual: &str) -> Result<(), String> {
+    const EPS: f64 = 1e-6;
+    let (mut exp, mut act) = (expected.split_whitespace(), actual.split_whitespace());
+    for i in 1.. {
+        let (e, a) = match (exp.next(), act.next()) {
+            (None, None) => return Ok(()),
+            (Some(e), None) => return Err(format!("token {i}: expected {e}, found end of output")),
+            (None, Some(a)) => return Err(format!("token {i}: expected end of output, found {a}")),
+            (Some(e), Some(a)) => (e, a),
+        };
+        if e == a {
+            continue;
+        }
+        let is_float = |s: &str| s.contains(['.', 'e', 'E']);
+        let close = match (e.parse::<f64>(), a.parse::<f64>()) {
+            (Ok(x), Ok(y)) if is_float(e) || is_float(a) => (x - y).abs() <= EPS * x.abs().max(1.),
+            _ => false,
+        };
+        if !close {
+            return Err(format!("token {i}: expected {e}, found {a}"));
+        }
+    }
+    unreachable!()
+}
+
+/// A macro for testing solutions against samples, see [`check_samples`]:
+/// `run_samples!(solve, [input => output, ...])`.
+///
+/// Inputs and outputs are string slices, e.g. literals, or files embedded with
+/// `include_str!`.
+///
+/// # Example
+///
+/// ```
+/// use {
+///     algorist::io::{Scanner, Writer, run_samples, wln},
+///     std::io::{BufRead, Write},
+/// };
+///
+/// fn solve<R: BufRead, W: Write>(scan: &mut Scanner<R>, w: &mut Writer<W>) {
+///     let n = scan.u();
+///     let v: Vec<i64> = scan.vec(n);
+///     wln!(w, "{}", v.iter().sum::<i64>());
+/// }
+///
+/// run_samples!(solve, [
+///     "3\n1 2 3\n" => "6\n",
+///     "1 -5" => "  -5  ",
+/// ]);
+/// ```
+#[macro_export]
+macro_rules! run_samples_impl {
+    ($solve:expr, [$($input:expr => $output:expr),* $(,)?]) => {
+        $crate::io::check_samples(&mut $solve, &[$(($input, $output)),*])
+    };
+}
+pub use run_samples_impl as run_samples;
+
 #[cfg(test)]
 mod tests {
     use {super::*, crate::io::Scanner, std::io::BufReader};
 
+    #[test]
+    fn dbgln_forms() {
+        let v = vec![1, 2];
+        dbgln!();
+        dbgln!(v);
+        dbgln!(v.len(), v[0] + 1,);
+        // Values are borrowed, not moved.
+        assert_eq!(v, [1, 2]);
+
+        // Variables, only used in `dbgln!`, are used in every configuration.
+        let mut steps = 0;
+        steps += 1;
+        dbgln!(steps);
+    }
+
+    #[test]
+    #[cfg(any(feature = "judge", ONLINE_JUDGE))]
+    fn dbgln_not_evaluated() {
+        let mut calls = 0;
+        let mut call = || calls += 1;
+        dbgln!(call());
+        assert_eq!(calls, 0);
+    }
+
+    #[test]
+    fn read_until_eof() {
+        let mut scan = Scanner::new(b"1 x\n\n2".as_ref());
+        assert!(scan.has_next());
+        assert_eq!(scan.try_next::<i32>(), Some(1));
+        // Failed parse consumes the token.
+        assert_eq!(scan.try_next::<i32>(), None);
+        assert!(scan.has_next());
+        assert_eq!(scan.try_next::<i32>(), Some(2));
+        assert!(!scan.has_next());
+        assert_eq!(scan.try_next::<i32>(), None);
+
+        let mut scan = Scanner::new(b"".as_ref());
+        assert!(!scan.has_next());
+    }
+
+    #[test]
+    #[should_panic(expected = "Unexpected end of input")]
+    fn read_past_eof() {
+        let mut scan = Scanner::new(b"1\n".as_ref());
+        scan.u();
+        scan.u();
+    }
+
+    #[test]
+    fn read_lines() {
+        let mut scan = Scanner::new(b"1 2  a b\n\n x \nlast".as_ref());
+        assert_eq!((scan.u(), scan.u()), (1, 2));
+        assert_eq!(scan.line(), "a b");
+        // Nothing is left on the current line, so the next (empty) one is read.
+        assert_eq!(scan.line(), "");
+        assert_eq!(scan.line(), " x ");
+        assert_eq!(scan.line(), "last");
+        assert!(!scan.has_next());
+
+        // Delimiters are not replaced in the returned line.
+        let mut scan = Scanner::with_delimiters(b"1,2,x,y\n".as_ref(), b",");
+        assert_eq!(scan.u(), 1);
+        assert_eq!(scan.line(), "2,x,y");
+    }
+
+    #[test]
+    fn read_remaining() {
+        let mut scan = Scanner::new(b"1 x \n".as_ref());
+        scan.u();
+        assert_eq!(scan.lines().collect::<Vec<_>>(), vec!["x "]);
+        assert_eq!(scan.lines().count(), 0);
+
+        // Nothing is left on the current line, and there are no more lines.
+        let mut scan = Scanner::new(b"1 \n".as_ref());
+        scan.u();
+        assert_eq!(scan.lines().count(), 0);
+
+        let mut scan = Scanner::new(b"1\n\n2 3".as_ref());
+        assert_eq!(scan.remaining_tokens::<u8>(), vec![1, 2, 3]);
+        assert!(!scan.has_next());
+    }
+
+    #[test]
+    #[should_panic(expected = "Unexpected end of input")]
+    fn read_line_past_eof() {
+        let mut scan = Scanner::new(b"1\n".as_ref());
+        scan.u();
+        scan.line();
+    }
+
+    #[test]
+    fn read_columns() {
+        let mut scan = Scanner::new(b"3\n1 2 3\n4 5 6\n7 8 9\n1 x -1 y\n".as_ref());
+        let n = scan.u();
+        let (a, b, c) = scan.unzip3::<u8>(n);
+        assert_eq!((a, b, c), (vec![1, 4, 7], vec![2, 5, 8], vec![3, 6, 9]));
+        let v: Vec<(i32, String)> = scan.vec_pairs(2);
+        assert_eq!(v, vec![(1, "x".to_string()), (-1, "y".to_string())]);
+        assert!(scan.vec_pairs::<u8, u8>(0).is_empty());
+    }
+
+    #[test]
+    fn read_one_based() {
+        let mut scan = Scanner::new(b"3 1 2 5 4\n".as_ref());
+        assert_eq!(scan.vec_u1(3), vec![2, 0, 1]);
+        assert_eq!(scan.edge1(), (4, 3));
+    }
+
+    #[test]
+    #[should_panic(expected = "Index 0 is not a valid 1-based index")]
+    fn read_one_based_zero() {
+        Scanner::new(b"0\n".as_ref()).u1();
+    }
+
     #[test]
     fn read_test_cases() {
         let input = b"2\n1 2\n3 4\n";
@@ -635,6 +2684,14 @@ mod tests {
         assert_eq!(x + y + z, 6);
     }
 
+    #[test]
+    fn read_64_bit() {
+        let input = b"-9223372036854775808 9223372036854775807 18446744073709551615 0\n";
+        let mut scanner = Scanner::new(input.as_ref());
+        assert_eq!(scanner.l2(), (i64::MIN, i64::MAX));
+        assert_eq!(scanner.ul2(), (u64::MAX, 0));
+    }
+
     #[test]
     fn read_pair() {
         let input = b"1 2\n";
@@ -691,9 +2748,465 @@ mod tests {
     }
 
     #[test]
+    fn read_many_tokens() {
+        // Tokens spanning many lines, with irregular whitespace, must be read
+        // in order and without losses.
+        let mut input = String::new();
+        for i in 0..10_000u32 {
+            input.push_str(&i.to_string());
+            input.push_str(match i % 7 {
+                0 => "\n",
+                3 => "  \t",
+                6 => " \r\n\n",
+                _ => " ",
+            });
+        }
+        let mut scanner = Scanner::new(BufReader::with_capacity(16, input.as_bytes()));
+        for i in 0..10_000u32 {
+            assert_eq!(scanner.next::<u32>(), i);
+        }
+    }
+
+    /// Reader, returning at most one byte per read, so that every token and
+    /// line spans several chunks.
+    struct Trickle<'a>(&'a [u8]);
+
+    impl Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let n = self.0.len().min(buf.len()).min(1);
+            buf[..n].copy_from_slice(&self.0[..n]);
+            self.0 = &self.0[n..];
+            Ok(n)
+        }
+    }
+
+    #[test]
+    fn read_across_chunks() {
+        let input = b"  12345 -678\r\n\nabc def  \n  x\n9";
+        let mut scanner = Scanner::new(BufReader::with_capacity(1, Trickle(input)));
+        assert_eq!(scanner.int::<u32>(), 12_345);
+        assert_eq!(scanner.next::<i64>(), -678);
+        assert_eq!(scanner.line(), "");
+        assert_eq!(scanner.string(), "abc");
+        assert_eq!(scanner.line(), "def  ");
+        assert_eq!(scanner.line(), "  x");
+        assert!(scanner.has_next());
+        assert_eq!(scanner.u(), 9);
+        assert!(!scanner.has_next());
+    }
+
+    #[test]
+    fn read_long_tokens() {
+        // Tokens longer than a chunk, and short ones straddling the chunks.
+        let long = "x".repeat(3 * CHUNK_SIZE + 7);
+        let mut input = String::new();
+        for i in 0..CHUNK_SIZE / 2 {
+            input.push_str(&format!("{i} "));
+            if i % 10_000 == 0 {
+                input.push_str(&long);
+                input.push('\n');
+            }
+        }
+        let mut scanner = Scanner::new(input.as_bytes());
+        for i in 0..CHUNK_SIZE / 2 {
+            assert_eq!(scanner.u(), i);
+            if i % 10_000 == 0 {
+                assert_eq!(scanner.string().len(), long.len());
+            }
+        }
+        assert!(!scanner.has_next());
+
+        let mut scanner = Scanner::new(input.as_bytes());
+        assert_eq!(scanner.u(), 0);
+        scanner.read_all();
+        assert_eq!(scanner.string(), long);
+        let rest = std::iter::from_fn(|| scanner.try_next::<String>()).count();
+        assert_eq!(rest, CHUNK_SIZE / 2 - 1 + 3);
+    }
+
+    #[test]
+    fn read_all_at_once() {
+        let input: String = (0..1_000).map(|i| format!("{i}\n")).collect();
+        let mut scanner = Scanner::new(input.as_bytes());
+        scanner.read_all();
+        for i in 0..1_000u32 {
+            assert_eq!(scanner.int::<u32>(), i);
+        }
+        assert_eq!(scanner.try_next::<u32>(), None);
+    }
+
+    #[test]
+    fn parse_ints() {
+        assert_eq!(i8::parse_int(b"-128"), Some(-128));
+        assert_eq!(i8::parse_int(b"128"), None);
+        assert_eq!(i8::parse_int(b"+127"), Some(127));
+        assert_eq!(u8::parse_int(b"256"), None);
+        assert_eq!(u8::parse_int(b"-0"), None);
+        assert_eq!(u64::parse_int(b"18446744073709551615"), Some(u64::MAX));
+        assert_eq!(
+            i128::parse_int(i128::MIN.to_string().as_bytes()),
+            Some(i128::MIN)
+        );
+        for bad in [&b""[..], b"-", b"+", b"1-", b"1.0", b"0x1", b" 1"] {
+            assert_eq!(i32::parse_int(bad), None);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "is not valid UTF-8")]
+    fn read_invalid_utf8() {
+        Scanner::new(b"ok \xff\xfe\n".as_ref()).vec::<String>(2);
+    }
+
+    #[test]
+    fn try_read_invalid_utf8() {
+        let mut scanner = Scanner::new(b"\xc3 1\n".as_ref());
+        assert_eq!(scanner.try_next::<String>(), None);
+        assert_eq!(scanner.try_next::<u8>(), Some(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "Failed parse: 12a")]
+    fn read_invalid_int() {
+        Scanner::new(b"12a\n".as_ref()).int::<i32>();
+    }
+
+    #[test]
+    fn read_with_delimiters() {
+        let input = b"3\n10,20\n-1,,2\n08:05:59;hello, world\n";
+        let mut scanner = Scanner::with_delimiters(input.as_ref(), CSV_DELIMITERS);
+        assert_eq!(scanner.u(), 3);
+        assert_eq!(scanner.pair::<i32>(), (10, 20));
+        assert_eq!(scanner.pair::<i32>(), (-1, 2));
+        assert_eq!(scanner.u3(), (8, 5, 59));
+        assert_eq!(scanner.string(), "hello");
+        assert_eq!(scanner.string(), "world");
+
+        // Switching delimiters affects tokens read after the switch only.
+        let input = b"1,2 3\n4,5\n";
+        let mut scanner = Scanner::new(input.as_ref());
+        assert_eq!(scanner.string(), "1,2");
+        scanner.set_delimiters(b",");
+        assert_eq!(scanner.u(), 3);
+        assert_eq!(scanner.u2(), (4, 5));
+    }
+
+    #[test]
+    #[should_panic(expected = "Delimiters must be ASCII characters")]
+    fn non_ascii_delimiters() {
+        Scanner::new(b"".as_ref()).set_delimiters("→".as_bytes());
+    }
+
+    #[test]
+    fn read_radix() {
+        let input = b"101 -101 0b11 777 0o17 DeadBeef 0XFF zz 10\n";
+        let mut scanner = Scanner::new(input.as_ref());
+        assert_eq!(scanner.next_radix::<u32>(2), 5);
+        assert_eq!(scanner.next_radix::<i8>(2), -5);
+        assert_eq!(scanner.next_radix::<u8>(2), 3);
+        assert_eq!(scanner.next_radix::<u16>(8), 0o777);
+        assert_eq!(scanner.next_radix::<u16>(8), 0o17);
+        assert_eq!(scanner.next_radix::<u64>(16), 0xdead_beef);
+        assert_eq!(scanner.next_radix::<u8>(16), 0xff);
+        assert_eq!(scanner.next_radix::<u16>(36), 36 * 36 - 1);
+        // Decimal works too, and tokens are interleaved with regular reads.
+        assert_eq!(scanner.next_radix::<usize>(10), 10);
+
+        let mut scanner = Scanner::new(b"11 x1\n".as_ref());
+        assert_eq!(scanner.u(), 11);
+    }
+
+    #[test]
+    #[should_panic(expected = "Failed parse: 102 in radix 2")]
+    fn read_radix_invalid_digit() {
+        Scanner::new(b"102\n".as_ref()).next_radix::<u32>(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Failed parse: 100 in radix 16")]
+    fn read_radix_overflow() {
+        Scanner::new(b"100\n".as_ref()).next_radix::<u8>(16);
+    }
+
+    #[test]
+    #[should_panic(expected = "Failed parse: +-5 in radix 10")]
+    fn read_radix_double_sign() {
+        Scanner::new(b"+-5\n".as_ref()).next_radix::<i32>(10);
+    }
+
+    #[test]
+    #[should_panic(expected = "Failed parse: 0x-5 in radix 16")]
+    fn read_radix_sign_after_prefix() {
+        Scanner::new(b"0x-5\n".as_ref()).next_radix::<i32>(16);
+    }
+
+    #[test]
+    #[should_panic(expected = "Failed parse: 0x+1f in radix 16")]
+    fn read_radix_plus_after_prefix() {
+        Scanner::new(b"0x+1f\n".as_ref()).next_radix::<i32>(16);
+    }
+
+    #[test]
+    #[should_panic(expected = "Failed parse: -0x-5 in radix 16")]
+    fn read_radix_sign_around_prefix() {
+        Scanner::new(b"-0x-5\n".as_ref()).next_radix::<i32>(16);
+    }
+
+    #[test]
+    #[should_panic(expected = "Radix must be in [2, 36]")]
+    fn read_radix_invalid_radix() {
+        Scanner::new(b"1\n".as_ref()).next_radix::<u32>(37);
+    }
+
+    #[test]
+    fn read_modulo() {
+        use crate::math::modulo::Mod7;
+
+        // 10^1000 mod p, via a literal with 1001 digits.
+        let mut input = "1".to_string() + &"0".repeat(1_000);
+        input.push_str(" 5 -0\n");
+        let mut scanner = Scanner::new(input.as_bytes());
+        let v: Vec<Mod7> = scanner.mod_vec(3);
+        assert_eq!(v[0], Mod7::new(10).pow_u64(1_000));
+        assert_eq!(v[1..], [Mod7::new(5), Mod7::new(0)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Failed parse: 12a")]
+    fn read_modulo_invalid() {
+        Scanner::new(b"12a\n".as_ref()).next_mod::<i64, crate::math::modulo::Val7>();
+    }
+
+    #[test]
+    fn writer_with_capacity() {
+        let mut expected = Vec::new();
+        for i in 0..1000 {
+            writeln!(expected, "{} {}", i, i * i).unwrap();
+        }
+
+        for capacity in [1, 7, 1 << 10, 1 << 16] {
+            let mut out = Vec::new();
+            let mut w = Writer::with_capacity(capacity, &mut out);
+            assert!(w.capacity() >= capacity);
+            for i in 0..1000 {
+                wln!(w, "{} {}", i, i * i);
+            }
+            drop(w);
+            assert_eq!(out, expected, "capacity = {capacity}");
+        }
+    }
+
+    #[test]
+    // Borrowed slices are the common case, so they are tested as is.
+    #[allow(clippy::needless_borrows_for_generic_args)]
     fn write_vec() {
         let mut output = Vec::new();
-        wv(&mut output, &vec![1, 2, 3]);
+        wv(&mut output, &[1, 2, 3]);
         assert_eq!(output, b"1 2 3");
+        let mut output = Vec::new();
+        wv(&mut output, (1..=3).rev().map(|x| x * 2));
+        assert_eq!(output, b"6 4 2");
+        let mut output = Vec::new();
+        wv(&mut output, Vec::<i32>::new());
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn write_answers() {
+        let mut out = Vec::new();
+        let mut w = Writer::new(&mut out);
+        let mut scan = Scanner::new(b"3\n1\n0\n1\n".as_ref());
+        scan.test_cases_indexed(&mut |t, scan| {
+            w.set_case([Case::Upper, Case::Lower, Case::Title][t - 1]);
+            wln_case!(w, t);
+            w.yesno(scan.u() == 1);
+        });
+        drop(w);
+        assert_eq!(out, b"Case #1:\nYES\nCase #2:\nno\nCase #3:\nYes\n");
+    }
+
+    #[test]
+    fn run_from_buffers() {
+        let mut out = Vec::new();
+        test_case_from(b"3\n1 2 3\n".as_ref(), &mut out, &mut |scan, w| {
+            let n = scan.u();
+            let v: Vec<u64> = scan.vec(n);
+            w.int(v.iter().sum::<u64>());
+        });
+        assert_eq!(out, b"6");
+    }
+
+    #[test]
+    fn input_from_file() {
+        let path = std::env::temp_dir().join(format!("algorist-input-{}.txt", std::process::id()));
+        std::fs::write(&path, "2\n1 2\n3 4\n").unwrap();
+        let open = || io::BufReader::new(std::fs::File::open(&path).unwrap());
+
+        let mut out = Vec::new();
+        test_cases_from(open(), &mut out, &mut |scan, w| {
+            let (a, b) = scan.u2();
+            wln!(w, "{}", a * b);
+        });
+        assert_eq!(out, b"2\n12\n");
+
+        let mut sum = 0;
+        Scanner::new(Input::File(open())).test_cases(&mut |scan| sum += scan.u() * scan.u());
+        assert_eq!(sum, 14);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn compare_sample_outputs() {
+        assert!(compare_outputs("1 2\n3\n", " 1\n2 3").is_ok());
+        assert!(compare_outputs("0.5 abc", "0.5000001 abc\n\n").is_ok());
+        assert!(compare_outputs("1e9", "1000000000.5").is_ok());
+        assert!(compare_outputs("", "").is_ok());
+        assert_eq!(
+            compare_outputs("1 2", "1 3"),
+            Err("token 2: expected 2, found 3".to_string())
+        );
+        assert!(compare_outputs("10", "10.0000000001").is_ok());
+        // Integers are compared exactly.
+        assert!(compare_outputs("10", "010").is_err());
+        assert!(compare_outputs("0.5", "0.51").is_err());
+        assert!(compare_outputs("YES", "yes").is_err());
+        assert!(compare_outputs("1", "1 2").is_err());
+        assert!(compare_outputs("1 2", "1").is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "Sample 2 failed: token 1: expected 4, found 3")]
+    fn run_failing_samples() {
+        run_samples!(|scan: &mut Scanner<_>, w: &mut Writer<_>| wln!(w, "{}", scan.u() + 1), [
+            "1" => "2",
+            "2" => "4",
+        ]);
+    }
+
+    #[test]
+    #[cfg(feature = "graph")]
+    fn read_graphs() {
+        let mut scan = Scanner::new(b"1 2\n1 1\n1 2 7\n".as_ref());
+        assert_eq!(scan.tree(0), Vec::<Vec<usize>>::new());
+        assert_eq!(scan.tree(1), vec![vec![]]);
+        // Self-loops appear twice, as every other edge.
+        assert_eq!(scan.graph(2, 2), vec![vec![1, 0, 0], vec![0]]);
+        assert_eq!(scan.weighted_graph::<u32>(2, 1), vec![vec![(1, 7)], vec![
+            (0, 7)
+        ]]);
+    }
+
+    #[test]
+    #[cfg(feature = "graph")]
+    #[should_panic(expected = "Edge 1 4 is out of bounds")]
+    fn read_graph_out_of_bounds() {
+        Scanner::new(b"1 4\n".as_ref()).graph(3, 1);
+    }
+
+    #[test]
+    fn read_grids() {
+        let mut scan = Scanner::new(b"2 3\n1 -2 3\n4 5 6\nab\ncd\n12\n".as_ref());
+        let (n, m) = scan.u2();
+        let grid = scan.grid::<i64>(n, m);
+        assert_eq!(grid.as_ref(), &vec![1, -2, 3, 4, 5, 6]);
+        let grid = scan.char_grid_padded(2, 2, b' ');
+        assert_eq!(grid.as_ref(), &b"     ab  cd     ".to_vec());
+        assert_eq!(scan.digit_grid(1, 2).as_ref(), &vec![1, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Row 1 has length 2, expected 3")]
+    fn read_ragged_grid() {
+        Scanner::new(b"abc\nde\n".as_ref()).char_grid(2, 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "Not a digit: x")]
+    fn read_invalid_digit_grid() {
+        Scanner::new(b"1x\n".as_ref()).digit_grid(1, 2);
+    }
+
+    #[test]
+    fn query_flushes() {
+        let mut out = Vec::new();
+        let mut w = Writer::new(&mut out);
+        let mut scan = Scanner::new(b"-1\n".as_ref());
+        let res: i32 = query!(w, scan, "? {}", 1);
+        assert_eq!(res, -1);
+        // Nothing is left in the buffer, before the writer is dropped.
+        assert!(w.0.buffer().is_empty());
+        drop(w);
+        assert_eq!(out, b"? 1\n");
+    }
+
+    #[test]
+    fn write_floats() {
+        let cases = [
+            (0.0, 2, "0.00"),
+            (-0.0, 2, "0.00"),
+            (-0.004, 2, "0.00"),
+            (-0.005001, 2, "-0.01"),
+            (123.456, 1, "123.5"),
+            (1e-30, 3, "0.000"),
+            (1.5e15, 0, "1500000000000000"),
+            (-2.0, 3, "-2.000"),
+        ];
+        for (x, digits, want) in cases {
+            assert_eq!(
+                Fixed(x, digits).to_string(),
+                want,
+                "{x} with {digits} digits"
+            );
+        }
+        let mut out = Vec::new();
+        let mut w = Writer::new(&mut out);
+        w.float(-0.25, 1);
+        wfix!(w, 2, 1, 2.0f32);
+        drop(w);
+        assert_eq!(out, b"-0.21.00 2.00\n");
+    }
+
+    #[test]
+    fn write_shapes() {
+        let mut out = Vec::new();
+        let mut w = Writer::new(&mut out);
+        wv_sep(&mut w, Vec::<u8>::new(), ",");
+        wvln_each(&mut w, ["a", "b"]);
+        w.grid(&Arr::from_vec(vec![-1, 2], 2, 1));
+        w.grid(&Arr::<u8>::new(0, 3));
+        w.char_grid(&Arr::from_vec(b"xyzw".to_vec(), 1, 4));
+        drop(w);
+        assert_eq!(out, b"a\nb\n-1\n2\nxyzw\n");
+    }
+
+    #[test]
+    fn write_ints() {
+        let mut rng = crate::misc::rng::Rng::new(7);
+        let mut buf = [0; 40];
+        for x in [0, 1, -1, 9, 10, -10, i64::MAX, i64::MIN] {
+            assert_eq!(x.format_int(&mut buf), x.to_string().as_bytes());
+        }
+        for x in [i8::MIN, i8::MAX] {
+            assert_eq!(x.format_int(&mut buf), x.to_string().as_bytes());
+        }
+        for x in [i128::MIN, i128::MAX] {
+            assert_eq!(x.format_int(&mut buf), x.to_string().as_bytes());
+        }
+        assert_eq!(
+            u128::MAX.format_int(&mut buf),
+            u128::MAX.to_string().as_bytes()
+        );
+        for _ in 0..1000 {
+            let x = rng.gen_range(0..u64::MAX) as i64;
+            assert_eq!(x.format_int(&mut buf), x.to_string().as_bytes());
+        }
+
+        let mut out = Vec::new();
+        let mut w = Writer::new(&mut out);
+        w.ints([1u8, 2, 3].iter(), ", ");
+        w.ints(Vec::<usize>::new(), " ");
+        w.int(0usize);
+        drop(w);
+        assert_eq!(out, b"1, 2, 30");
     }
 }
@@ -0,0 +1,98 @@
+//! Types, which can be read from a [`Scanner`], as a whole.
+//!
+//! Implemented for primitives, `String`, [`Modulo`] numbers, tuples (of up to
+//! 6 readable types, read one after another) and vectors (prefixed by their
+//! length), and allows to read heterogeneous records with
+//! [`Scanner::read`].
+//!
+//! ```
+//! use algorist::io::Scanner;
+//!
+//! let input = b"2 alice 31 bob 27\n3 1 2 3\n";
+//! let mut scan = Scanner::new(input.as_ref());
+//! let people: Vec<(String, u8)> = scan.read();
+//! assert_eq!(people, vec![("alice".into(), 31), ("bob".into(), 27)]);
+//! let v = scan.read::<Vec<i64>>();
+//! assert_eq!(v, vec![1, 2, 3]);
+//! ```
+
+use {
+    super::Scanner,
+    crate::math::{ConstValue, Downcast, Number, modulo::Modulo},
+    std::io::BufRead,
+};
+
+/// Type, which can be read from a [`Scanner`], see the [module
+/// documentation](self).
+pub trait Readable: Sized {
+    /// Reads the value from the scanner.
+    fn read<R: BufRead>(scan: &mut Scanner<R>) -> Self;
+}
+
+macro_rules! readable_impl {
+    ($f: ident: $($t: ty)+) => {$(
+        impl Readable for $t {
+            fn read<R: BufRead>(scan: &mut Scanner<R>) -> Self {
+                scan.$f()
+            }
+        }
+    )+};
+}
+
+readable_impl!(int: i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+readable_impl!(next: f32 f64 bool char String);
+
+macro_rules! readable_tuple_impl {
+    ($($t: ident)+) => {
+        impl<$($t: Readable),+> Readable for ($($t,)+) {
+            fn read<R: BufRead>(scan: &mut Scanner<R>) -> Self {
+                ($($t::read(scan),)+)
+            }
+        }
+    };
+}
+
+readable_tuple_impl!(A);
+readable_tuple_impl!(A B);
+readable_tuple_impl!(A B C);
+readable_tuple_impl!(A B C D);
+readable_tuple_impl!(A B C D E);
+readable_tuple_impl!(A B C D E F);
+
+impl<T: Readable> Readable for Vec<T> {
+    /// Reads the number of elements, followed by the elements.
+    fn read<R: BufRead>(scan: &mut Scanner<R>) -> Self {
+        let n = scan.u();
+        (0..n).map(|_| T::read(scan)).collect()
+    }
+}
+
+impl<T, M> Readable for Modulo<T, M>
+where
+    T: Number + Downcast,
+    T::Source: Number,
+    M: ConstValue<T>,
+{
+    /// Reads the number with [`Scanner::next_mod`], so any literal works.
+    fn read<R: BufRead>(scan: &mut Scanner<R>) -> Self {
+        scan.next_mod()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::math::modulo::Mod7};
+
+    #[test]
+    fn read_nested() {
+        let input = b"1 -2 x 2.5\n2 1 1 a 2 2 b c\n12345678901234567890 false\n";
+        let mut scan = Scanner::new(input.as_ref());
+        let t: (u8, i64, char, f64) = scan.read();
+        assert_eq!(t, (1, -2, 'x', 2.5));
+        let v: Vec<(usize, Vec<char>)> = scan.read();
+        assert_eq!(v, vec![(1, vec!['a']), (2, vec!['b', 'c'])]);
+        let (m, (b,)): (Mod7, (bool,)) = scan.read();
+        assert_eq!(m, Mod7::new(814_816_192));
+        assert!(!b);
+    }
+}
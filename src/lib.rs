@@ -3,6 +3,13 @@
 
 pub mod collections;
 pub mod ext;
+#[cfg(feature = "geom")]
+pub mod geom;
+#[cfg(feature = "graph")]
+pub mod graph;
 pub mod io;
 pub mod math;
 pub mod misc;
+pub mod prelude;
+#[cfg(feature = "strings")]
+pub mod strings;
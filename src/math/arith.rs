@@ -0,0 +1,224 @@
+//! Integer arithmetic helpers.
+//!
+//! Rust's integer division truncates toward zero, which is rarely what is
+//! needed when operands can be negative. Use [`floor_div`] and [`ceil_div`]
+//! instead.
+//!
+//! ```
+//! use algorist::math::arith::{ceil_div, floor_div};
+//!
+//! assert_eq!(-7 / 2, -3);
+//! assert_eq!(floor_div(-7, 2), -4);
+//! assert_eq!(ceil_div(-7, 2), -3);
+//! assert_eq!(ceil_div(7, 2), 4);
+//! ```
+//!
+//! # Sums
+//!
+//! Sum of an arithmetic progression of consecutive integers is computed by
+//! [`sum_range`], and sum of floors of a linear function by [`floor_sum`].
+//!
+//! ```
+//! use algorist::math::arith::{floor_sum, sum_range};
+//!
+//! assert_eq!(sum_range(1, 100), 5050);
+//!
+//! // floor(0 / 3) + floor(2 / 3) + floor(4 / 3) + floor(6 / 3) = 3
+//! assert_eq!(floor_sum(4, 3, 2, 0), 3);
+//! ```
+
+use crate::math::Number;
+
+/// Returns `a / b` rounded toward negative infinity.
+///
+/// # Panics
+///
+/// Panics if `b` is zero.
+///
+/// # Example
+///
+/// ```
+/// use algorist::math::arith::floor_div;
+///
+/// assert_eq!(floor_div(7, 2), 3);
+/// assert_eq!(floor_div(-7, 2), -4);
+/// assert_eq!(floor_div(7, -2), -4);
+/// assert_eq!(floor_div(-7, -2), 3);
+/// assert_eq!(floor_div(-8, 2), -4);
+/// assert_eq!(floor_div(7u32, 2), 3);
+/// ```
+pub fn floor_div<T: Number>(a: T, b: T) -> T {
+    let q = a / b;
+    if a % b != T::zero() && ((a < T::zero()) != (b < T::zero())) {
+        q - T::one()
+    } else {
+        q
+    }
+}
+
+/// Returns `a / b` rounded toward positive infinity.
+///
+/// # Panics
+///
+/// Panics if `b` is zero.
+///
+/// # Example
+///
+/// ```
+/// use algorist::math::arith::ceil_div;
+///
+/// assert_eq!(ceil_div(7, 2), 4);
+/// assert_eq!(ceil_div(-7, 2), -3);
+/// assert_eq!(ceil_div(7, -2), -3);
+/// assert_eq!(ceil_div(-7, -2), 4);
+/// assert_eq!(ceil_div(8, 2), 4);
+/// assert_eq!(ceil_div(7u32, 2), 4);
+/// ```
+pub fn ceil_div<T: Number>(a: T, b: T) -> T {
+    let q = a / b;
+    if a % b != T::zero() && ((a < T::zero()) == (b < T::zero())) {
+        q + T::one()
+    } else {
+        q
+    }
+}
+
+/// Returns the sum of all integers in `[l, r]`, or zero if `l > r`.
+///
+/// # Example
+///
+/// ```
+/// use algorist::math::arith::sum_range;
+///
+/// assert_eq!(sum_range(1, 10), 55);
+/// assert_eq!(sum_range(-3, 5), 9);
+/// assert_eq!(sum_range(5, 5), 5);
+/// assert_eq!(sum_range(6, 5), 0);
+/// ```
+pub fn sum_range<T: Number>(l: T, r: T) -> T {
+    if l > r {
+        return T::zero();
+    }
+    let two = T::one() + T::one();
+    let (s, n) = (l + r, r - l + T::one());
+    // One of `l + r` and `r - l + 1` is even.
+    if s % two == T::zero() {
+        s / two * n
+    } else {
+        n / two * s
+    }
+}
+
+/// Returns `sum(floor((a * i + b) / m))` for `i` in `0..n`, in `O(log m)`.
+///
+/// Both `a` and `b` may be negative. Intermediate values are computed in
+/// `i128`, the result must fit into `i64`.
+///
+/// # Panics
+///
+/// Panics if `n` is negative, or if `m` is not positive.
+///
+/// # Example
+///
+/// ```
+/// use algorist::math::arith::floor_sum;
+///
+/// assert_eq!(floor_sum(4, 10, 6, 3), 3);
+/// assert_eq!(floor_sum(6, 5, 4, 3), 13);
+/// assert_eq!(floor_sum(1, 1, 0, 0), 0);
+/// assert_eq!(floor_sum(31_415, 92_653, 58_979, 32_384), 314_095_480);
+/// assert_eq!(floor_sum(3, 2, -3, 1), -4);
+/// ```
+pub fn floor_sum(n: i64, m: i64, a: i64, b: i64) -> i64 {
+    assert!(n >= 0, "Number of terms must be non-negative");
+    assert!(m > 0, "Divisor must be positive");
+    let (n, m, mut a, mut b) = (i128::from(n), i128::from(m), i128::from(a), i128::from(b));
+
+    let mut ans = 0;
+    if a < 0 {
+        let a2 = a.rem_euclid(m);
+        ans -= n * (n - 1) / 2 * ((a2 - a) / m);
+        a = a2;
+    }
+    if b < 0 {
+        let b2 = b.rem_euclid(m);
+        ans -= n * ((b2 - b) / m);
+        b = b2;
+    }
+    (ans + floor_sum_non_negative(n, m, a, b)) as i64
+}
+
+fn floor_sum_non_negative(mut n: i128, mut m: i128, mut a: i128, mut b: i128) -> i128 {
+    let mut ans = 0;
+    loop {
+        if a >= m {
+            ans += n * (n - 1) / 2 * (a / m);
+            a %= m;
+        }
+        if b >= m {
+            ans += n * (b / m);
+            b %= m;
+        }
+        let y_max = a * n + b;
+        if y_max < m {
+            return ans;
+        }
+        n = y_max / m;
+        b = y_max % m;
+        std::mem::swap(&mut m, &mut a);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_div() {
+        for a in -20i32..=20 {
+            for b in (-7i32..=7).filter(|&b| b != 0) {
+                let (fa, fb) = (f64::from(a), f64::from(b));
+                assert_eq!(floor_div(a, b), (fa / fb).floor() as i32, "{a} / {b}");
+                assert_eq!(ceil_div(a, b), (fa / fb).ceil() as i32, "{a} / {b}");
+            }
+        }
+        assert_eq!(floor_div(i64::MIN, 1), i64::MIN);
+        assert_eq!(ceil_div(u64::MAX, 2), 1 << 63);
+    }
+
+    #[test]
+    fn test_sum_range() {
+        for l in -10i64..=10 {
+            for r in -10i64..=10 {
+                assert_eq!(sum_range(l, r), (l..=r).sum::<i64>(), "[{l}, {r}]");
+            }
+        }
+        assert_eq!(sum_range(1u64, 1_000_000_000), 500_000_000_500_000_000);
+    }
+
+    #[test]
+    fn test_floor_sum() {
+        for n in 0..15 {
+            for m in 1..10 {
+                for a in -12..12 {
+                    for b in -12..12 {
+                        let want: i64 = (0..n).map(|i| floor_div(a * i + b, m)).sum();
+                        assert_eq!(floor_sum(n, m, a, b), want, "n={n}, m={m}, a={a}, b={b}");
+                    }
+                }
+            }
+        }
+
+        // Large values, which overflow `i64` in intermediate computations.
+        let (n, m, a, b) = (
+            50_000,
+            1_000_000_007,
+            999_999_999_999_999_989,
+            1_000_000_000_000_000_000,
+        );
+        let want: i128 = (0..i128::from(n))
+            .map(|i| (i128::from(a) * i + i128::from(b)) / i128::from(m))
+            .sum();
+        assert_eq!(i128::from(floor_sum(n, m, a, b)), want);
+    }
+}
@@ -0,0 +1,324 @@
+//! Continued fractions and the Stern–Brocot tree.
+//!
+//! A rational `p / q` is expanded as `a0 + 1 / (a1 + 1 / (a2 + ...))`, see
+//! [`to_cfrac`]. Truncating the expansion gives the convergents
+//! ([`convergents`]), which are the best rational approximations of `p / q`;
+//! for the best approximation with a bounded denominator, use
+//! [`best_approximation`].
+//!
+//! ```
+//! use algorist::math::cfrac::{best_approximation, convergents, to_cfrac};
+//!
+//! // 415 / 93 = 4 + 1 / (2 + 1 / (6 + 1 / 7))
+//! assert_eq!(to_cfrac(415, 93), vec![4, 2, 6, 7]);
+//! assert_eq!(convergents(&[4, 2, 6, 7]), vec![
+//!     (4, 1),
+//!     (9, 2),
+//!     (58, 13),
+//!     (415, 93)
+//! ]);
+//!
+//! // Approximations of pi = 3.14159265...
+//! assert_eq!(best_approximation(314_159_265, 100_000_000, 10), (22, 7));
+//! assert_eq!(
+//!     best_approximation(314_159_265, 100_000_000, 1_000),
+//!     (355, 113)
+//! );
+//! ```
+//!
+//! # Stern–Brocot tree
+//!
+//! Every positive rational appears exactly once in the Stern–Brocot tree,
+//! where each node is the [`mediant`] of its nearest ancestors to the left
+//! and to the right. The path from the root `1 / 1` to `p / q` is given by
+//! [`stern_brocot_path`], as run-length encoded moves, which is exactly the
+//! continued fraction of `p / q`.
+//!
+//! ```
+//! use algorist::math::cfrac::{Direction::*, mediant, stern_brocot_path};
+//!
+//! assert_eq!(mediant((1, 2), (2, 3)), (3, 5));
+//! // 1/1 -> 1/2 -> 2/3 -> 3/5
+//! assert_eq!(stern_brocot_path(3, 5), vec![
+//!     (Left, 1),
+//!     (Right, 1),
+//!     (Left, 1)
+//! ]);
+//! ```
+
+use {crate::math::gcd::gcd, std::cmp::Ordering};
+
+/// Returns the continued fraction expansion `[a0; a1, a2, ...]` of `p / q`.
+///
+/// The first term is `floor(p / q)` (negative for negative fractions), all
+/// the other terms are positive, and the last term is greater than one
+/// (unless the expansion is a single term).
+///
+/// # Panics
+///
+/// Panics if `q` is zero.
+///
+/// # Example
+///
+/// ```
+/// use algorist::math::cfrac::to_cfrac;
+///
+/// assert_eq!(to_cfrac(3, 1), vec![3]);
+/// assert_eq!(to_cfrac(-7, 3), vec![-3, 1, 2]); // -3 + 1 / (1 + 1 / 2)
+/// assert_eq!(to_cfrac(6, -4), vec![-2, 2]);
+/// ```
+pub fn to_cfrac(p: i64, q: i64) -> Vec<i64> {
+    assert!(q != 0, "Denominator must be non-zero");
+    let (mut p, mut q) = if q < 0 {
+        (-i128::from(p), -i128::from(q))
+    } else {
+        (i128::from(p), i128::from(q))
+    };
+    let mut terms = vec![];
+    while q != 0 {
+        let a = p.div_euclid(q);
+        terms.push(a as i64);
+        (p, q) = (q, p - a * q);
+    }
+    terms
+}
+
+/// Returns convergents of the continued fraction `[a0; a1, a2, ...]`, as
+/// pairs `(numerator, denominator)`, the last one being the value of the
+/// whole fraction.
+///
+/// Convergents are irreducible, and their denominators are positive.
+///
+/// # Example
+///
+/// ```
+/// use algorist::math::cfrac::convergents;
+///
+/// // Fibonacci ratios.
+/// assert_eq!(convergents(&[1, 1, 1, 1, 1]), vec![
+///     (1, 1),
+///     (2, 1),
+///     (3, 2),
+///     (5, 3),
+///     (8, 5)
+/// ]);
+/// assert_eq!(convergents(&[]), vec![]);
+/// ```
+pub fn convergents(terms: &[i64]) -> Vec<(i64, i64)> {
+    let (mut h, mut k) = ((1, 0), (0, 1));
+    terms
+        .iter()
+        .map(|&a| {
+            h = (a * h.0 + h.1, h.0);
+            k = (a * k.0 + k.1, k.0);
+            (h.0, k.0)
+        })
+        .collect()
+}
+
+/// Returns the value `p / q` of the continued fraction `[a0; a1, a2, ...]`.
+///
+/// # Panics
+///
+/// Panics if `terms` is empty.
+///
+/// # Example
+///
+/// ```
+/// use algorist::math::cfrac::{from_cfrac, to_cfrac};
+///
+/// assert_eq!(from_cfrac(&[0, 1, 3]), (3, 4));
+/// assert_eq!(from_cfrac(&to_cfrac(-10, 4)), (-5, 2));
+/// ```
+pub fn from_cfrac(terms: &[i64]) -> (i64, i64) {
+    *convergents(terms)
+        .last()
+        .expect("Continued fraction must have at least one term")
+}
+
+/// Returns the fraction `a / b`, closest to `p / q`, among fractions with
+/// `1 <= b <= max_den`. On ties, the fraction with a smaller denominator is
+/// returned.
+///
+/// The answer is either a convergent, or a semiconvergent (intermediate
+/// fraction) of `p / q`, so the search takes `O(log q)`.
+///
+/// # Panics
+///
+/// Panics if `q` or `max_den` is not positive.
+///
+/// # Example
+///
+/// ```
+/// use algorist::math::cfrac::best_approximation;
+///
+/// assert_eq!(best_approximation(1, 3, 2), (1, 2)); // 1/2 is closer than 0/1
+/// assert_eq!(best_approximation(-1, 3, 2), (-1, 2));
+/// assert_eq!(best_approximation(8, 6, 100), (4, 3));
+/// assert_eq!(best_approximation(7, 3, 1), (2, 1));
+/// ```
+pub fn best_approximation(p: i64, q: i64, max_den: i64) -> (i64, i64) {
+    assert!(q > 0, "Denominator must be positive");
+    assert!(max_den > 0, "Maximum denominator must be positive");
+
+    let (mut h, mut k) = ((1, 0), (0, 1));
+    for a in to_cfrac(p, q) {
+        let next = (a * h.0 + h.1, a * k.0 + k.1);
+        if next.1 > max_den {
+            // The last convergent within the limit, or the largest
+            // semiconvergent between the last two convergents.
+            let t = (max_den - k.1) / k.0;
+            let semi = (t * h.0 + h.1, t * k.0 + k.1);
+            let conv = (h.0, k.0);
+            return match cmp_distance(conv, semi, (p, q)) {
+                Ordering::Greater => semi,
+                Ordering::Less => conv,
+                Ordering::Equal => {
+                    if conv.1 <= semi.1 {
+                        conv
+                    } else {
+                        semi
+                    }
+                }
+            };
+        }
+        h = (next.0, h.0);
+        k = (next.1, k.0);
+    }
+    (h.0, k.0)
+}
+
+/// Compares distances from `a` and `b` to `x`.
+fn cmp_distance(a: (i64, i64), b: (i64, i64), x: (i64, i64)) -> Ordering {
+    let dist = |f: (i64, i64)| {
+        // |f - x| * f.1 * x.1
+        (i128::from(f.0) * i128::from(x.1) - i128::from(x.0) * i128::from(f.1)).abs()
+    };
+    (dist(a) * i128::from(b.1)).cmp(&(dist(b) * i128::from(a.1)))
+}
+
+/// Returns the mediant `(a + c) / (b + d)` of fractions `a / b` and `c / d`,
+/// which lies strictly between them (if they differ and denominators are
+/// positive).
+pub fn mediant((a, b): (i64, i64), (c, d): (i64, i64)) -> (i64, i64) {
+    (a + c, b + d)
+}
+
+/// Move in the Stern–Brocot tree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// Move to the smaller fraction.
+    Left,
+    /// Move to the larger fraction.
+    Right,
+}
+
+/// Returns the path from the root `1 / 1` of the Stern–Brocot tree to
+/// `p / q`, as a list of `(direction, count)` runs. The path to `1 / 1` is
+/// empty.
+///
+/// # Panics
+///
+/// Panics if `p` or `q` is not positive.
+///
+/// # Example
+///
+/// ```
+/// use algorist::math::cfrac::{Direction::*, stern_brocot_path};
+///
+/// assert_eq!(stern_brocot_path(1, 1), vec![]);
+/// assert_eq!(stern_brocot_path(5, 1), vec![(Right, 4)]);
+/// assert_eq!(stern_brocot_path(2, 4), vec![(Left, 1)]);
+/// assert_eq!(stern_brocot_path(7, 5), vec![
+///     (Right, 1),
+///     (Left, 2),
+///     (Right, 1)
+/// ]);
+/// ```
+pub fn stern_brocot_path(p: i64, q: i64) -> Vec<(Direction, i64)> {
+    assert!(p > 0 && q > 0, "Fraction must be positive");
+    let g = gcd(p, q);
+    let mut terms = to_cfrac(p / g, q / g);
+    // The last term is decreased by one: the path stops at the node itself.
+    *terms.last_mut().unwrap() -= 1;
+    terms
+        .into_iter()
+        .enumerate()
+        .filter(|&(_, a)| a > 0)
+        .map(|(i, a)| {
+            let dir = if i % 2 == 0 {
+                Direction::Right
+            } else {
+                Direction::Left
+            };
+            (dir, a)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cfrac_roundtrip() {
+        for p in -50..=50 {
+            for q in (-20..=20).filter(|&q| q != 0) {
+                let terms = to_cfrac(p, q);
+                assert!(terms[1..].iter().all(|&a| a > 0));
+                if terms.len() > 1 {
+                    assert!(*terms.last().unwrap() > 1);
+                }
+                let g = gcd(p.abs(), q.abs()) * q.signum();
+                assert_eq!(from_cfrac(&terms), (p / g, q / g), "{p}/{q}");
+            }
+        }
+        let big = to_cfrac(i64::MAX, i64::MAX - 1);
+        assert_eq!(from_cfrac(&big), (i64::MAX, i64::MAX - 1));
+    }
+
+    #[test]
+    fn test_best_approximation() {
+        for p in -40i64..=40 {
+            for q in 1..=15 {
+                for max_den in 1..=20 {
+                    // Brute force over all denominators.
+                    let mut best = (0, 0);
+                    for b in 1..=max_den {
+                        for a in [(p * b).div_euclid(q), (p * b).div_euclid(q) + 1] {
+                            let better =
+                                best.1 == 0 || cmp_distance((a, b), best, (p, q)) == Ordering::Less;
+                            if better {
+                                best = (a, b);
+                            }
+                        }
+                    }
+                    let got = best_approximation(p, q, max_den);
+                    assert_eq!(got, best, "{p}/{q}, max_den = {max_den}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_stern_brocot() {
+        // Walk the path with mediants, and check that it ends at `p / q`.
+        for p in 1..=30 {
+            for q in 1..=30 {
+                let (mut lo, mut hi) = ((0, 1), (1, 0));
+                let mut cur = mediant(lo, hi);
+                for (dir, cnt) in stern_brocot_path(p, q) {
+                    for _ in 0..cnt {
+                        match dir {
+                            Direction::Left => hi = cur,
+                            Direction::Right => lo = cur,
+                        }
+                        cur = mediant(lo, hi);
+                    }
+                }
+                let g = gcd(p, q);
+                assert_eq!(cur, (p / g, q / g));
+            }
+        }
+    }
+}
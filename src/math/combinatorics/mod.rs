@@ -0,0 +1,363 @@
+//! Combinatorics under a modulo.
+//!
+//! Counting problems normally ask for the answer modulo some prime (e.g.
+//! `1_000_000_007`), so all the values here are [`Modulo`] numbers.
+//!
+//! # Binomial coefficients
+//!
+//! [`Binom`] precomputes factorials and inverse factorials up to `n` in `O(n)`,
+//! after which binomial coefficients, multinomial coefficients and
+//! arrangements are computed in `O(1)` (or `O(k)` for `k` parts).
+//!
+//! ```
+//! use algorist::math::{combinatorics::Binom, modulo::Val7};
+//!
+//! let b = Binom::<i64, Val7>::new(10);
+//! assert_eq!(b.binom(5, 2).val(), 10);
+//!
+//! // Number of distinct permutations of "banana": 6! / (3! * 2! * 1!)
+//! assert_eq!(b.multinomial(&[3, 2, 1]).val(), 60);
+//!
+//! // Number of ways to give 3 different medals to 10 participants.
+//! assert_eq!(b.arrangements(10, 3).val(), 720);
+//! ```
+//!
+//! # Tables
+//!
+//! Sequences, routinely needed in counting problems, are available as tables
+//! of the first `n + 1` values, computed in `O(n)`: Catalan numbers
+//! ([`Binom::catalan_table`]), central binomial coefficients
+//! ([`Binom::central_binom_table`]) and derangements ([`Binom::derangements`]).
+//!
+//! ```
+//! use algorist::math::{combinatorics::Binom, modulo::Val7};
+//!
+//! let b = Binom::<i64, Val7>::new(20);
+//! let catalan: Vec<i64> = b.catalan_table(5).iter().map(|c| c.val()).collect();
+//! assert_eq!(catalan, vec![1, 1, 2, 5, 14, 42]);
+//! ```
+//!
+//! Stirling numbers of both kinds, Bell numbers and integer partition counts,
+//! which need no factorials (thus, work under any modulo), are in the
+//! [`tables`] module.
+
+pub mod tables;
+
+use crate::math::{ConstValue, Downcast, Invertible, Number, modulo::Modulo};
+
+/// Table of factorials and inverse factorials under a modulo `M`.
+///
+/// The modulo must be a prime greater than `n`, so that all factorials up to
+/// `n` are invertible.
+///
+/// # Example
+///
+/// ```
+/// use algorist::math::{combinatorics::Binom, modulo::Val7};
+///
+/// let b = Binom::<i64, Val7>::new(100_000);
+/// assert_eq!(b.fact(5).val(), 120);
+/// assert_eq!(b.binom(100_000, 50_000).val(), 149_033_233);
+/// assert_eq!(b.binom(3, 5).val(), 0);
+/// ```
+pub struct Binom<T, M: ConstValue<T>> {
+    fact: Vec<Modulo<T, M>>,
+    inv_fact: Vec<Modulo<T, M>>,
+}
+
+impl<T, M> Binom<T, M>
+where
+    T: Number + Downcast,
+    T::Source: Number,
+    M: ConstValue<T>,
+{
+    /// Precomputes factorials and inverse factorials of `0..=n`, in `O(n)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if some factorial is not invertible under the modulo (i.e. when
+    /// `n` is not less than the prime modulo).
+    pub fn new(n: usize) -> Self {
+        let mut fact = Vec::with_capacity(n + 1);
+        fact.push(Modulo::new(T::one()));
+        for i in 1..=n {
+            fact.push(fact[i - 1] * Modulo::new(T::new(i)));
+        }
+
+        let mut inv_fact = vec![Modulo::new(T::zero()); n + 1];
+        inv_fact[n] = fact[n]
+            .inverse()
+            .expect("Factorials must be invertible, modulo must be a prime greater than n");
+        for i in (1..=n).rev() {
+            inv_fact[i - 1] = inv_fact[i] * Modulo::new(T::new(i));
+        }
+        Self { fact, inv_fact }
+    }
+
+    /// Returns the maximum `n` for which factorials are precomputed.
+    pub fn max_n(&self) -> usize {
+        self.fact.len() - 1
+    }
+
+    /// Returns `n!`.
+    pub fn fact(&self, n: usize) -> Modulo<T, M> {
+        self.fact[n]
+    }
+
+    /// Returns the inverse of `n!`.
+    pub fn inv_fact(&self, n: usize) -> Modulo<T, M> {
+        self.inv_fact[n]
+    }
+
+    /// Returns the binomial coefficient `C(n, k)`, i.e. the number of ways to
+    /// choose `k` items out of `n`. If `k > n`, returns zero.
+    pub fn binom(&self, n: usize, k: usize) -> Modulo<T, M> {
+        if k > n {
+            return Modulo::new(T::zero());
+        }
+        self.fact[n] * self.inv_fact[k] * self.inv_fact[n - k]
+    }
+
+    /// Returns the multinomial coefficient `(k1 + k2 + ...)! / (k1! * k2! *
+    /// ...)`, i.e. the number of ways to split `k1 + k2 + ...` distinct items
+    /// into groups of sizes `k1, k2, ...`, or, equivalently, the number of
+    /// distinct arrangements of a multiset with the given multiplicities.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sum of parts exceeds [`Binom::max_n`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::math::{combinatorics::Binom, modulo::Val7};
+    ///
+    /// let b = Binom::<i64, Val7>::new(20);
+    ///
+    /// // "mississippi": m = 1, i = 4, s = 4, p = 2.
+    /// assert_eq!(b.multinomial(&[1, 4, 4, 2]).val(), 34_650);
+    ///
+    /// // With two parts it is just a binomial coefficient.
+    /// assert_eq!(b.multinomial(&[3, 7]), b.binom(10, 3));
+    /// assert_eq!(b.multinomial(&[]).val(), 1);
+    /// ```
+    pub fn multinomial(&self, parts: &[usize]) -> Modulo<T, M> {
+        let n: usize = parts.iter().sum();
+        parts
+            .iter()
+            .fold(self.fact[n], |acc, &k| acc * self.inv_fact[k])
+    }
+
+    /// Returns the number of arrangements (partial permutations) `A(n, k) = n!
+    /// / (n - k)!`, i.e. the number of ways to pick `k` out of `n` distinct
+    /// items, when order matters. If `k > n`, returns zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::math::{combinatorics::Binom, modulo::Val7};
+    ///
+    /// let b = Binom::<i64, Val7>::new(10);
+    /// assert_eq!(b.arrangements(5, 2).val(), 20);
+    /// assert_eq!(b.arrangements(5, 5), b.fact(5));
+    /// assert_eq!(b.arrangements(5, 0).val(), 1);
+    /// assert_eq!(b.arrangements(2, 5).val(), 0);
+    /// ```
+    pub fn arrangements(&self, n: usize, k: usize) -> Modulo<T, M> {
+        if k > n {
+            return Modulo::new(T::zero());
+        }
+        self.fact[n] * self.inv_fact[n - k]
+    }
+
+    /// Returns Catalan numbers `C(2k, k) / (k + 1)`, for `k` in `0..=n`.
+    ///
+    /// Catalan numbers count, among others, balanced bracket sequences with
+    /// `k` pairs of brackets, binary trees with `k` nodes, and monotonic
+    /// lattice paths not crossing the diagonal.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `2 * n` or `n + 1` exceeds [`Binom::max_n`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::math::{combinatorics::Binom, modulo::Val7};
+    ///
+    /// let b = Binom::<i64, Val7>::new(20);
+    /// let catalan = b.catalan_table(10);
+    /// assert_eq!(catalan.len(), 11);
+    /// assert_eq!(catalan[3].val(), 5);
+    /// assert_eq!(catalan[10].val(), 16_796);
+    /// ```
+    pub fn catalan_table(&self, n: usize) -> Vec<Modulo<T, M>> {
+        assert!(
+            2 * n <= self.max_n() && n < self.max_n(),
+            "Factorials up to {} are required",
+            (2 * n).max(n + 1)
+        );
+        (0..=n)
+            .map(|k| self.fact[2 * k] * self.inv_fact[k] * self.inv_fact[k + 1])
+            .collect()
+    }
+
+    /// Returns central binomial coefficients `C(2k, k)`, for `k` in `0..=n`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `2 * n` exceeds [`Binom::max_n`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::math::{combinatorics::Binom, modulo::Val7};
+    ///
+    /// let b = Binom::<i64, Val7>::new(10);
+    /// let central: Vec<i64> = b.central_binom_table(5).iter().map(|c| c.val()).collect();
+    /// assert_eq!(central, vec![1, 2, 6, 20, 70, 252]);
+    /// ```
+    pub fn central_binom_table(&self, n: usize) -> Vec<Modulo<T, M>> {
+        assert!(
+            2 * n <= self.max_n(),
+            "Factorials up to {} are required",
+            2 * n
+        );
+        (0..=n)
+            .map(|k| self.fact[2 * k] * self.inv_fact[k] * self.inv_fact[k])
+            .collect()
+    }
+
+    /// Returns the numbers of derangements (permutations without fixed
+    /// points) of `k` elements, for `k` in `0..=n`.
+    ///
+    /// Uses the recurrence `D(k) = (k - 1) * (D(k - 1) + D(k - 2))`, so no
+    /// factorial table is needed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::math::{combinatorics::Binom, modulo::Val7};
+    ///
+    /// let d: Vec<i64> = Binom::<i64, Val7>::derangements(6)
+    ///     .iter()
+    ///     .map(|d| d.val())
+    ///     .collect();
+    /// assert_eq!(d, vec![1, 0, 1, 2, 9, 44, 265]);
+    /// ```
+    pub fn derangements(n: usize) -> Vec<Modulo<T, M>> {
+        let mut d = Vec::with_capacity(n + 1);
+        d.push(Modulo::new(T::one()));
+        for k in 1..=n {
+            let prev2 = if k >= 2 {
+                d[k - 2]
+            } else {
+                Modulo::new(T::zero())
+            };
+            d.push(Modulo::new(T::new(k - 1)) * (d[k - 1] + prev2));
+        }
+        d
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::math::modulo::{Mod7, Val7},
+    };
+
+    #[test]
+    fn test_binom() {
+        let b = Binom::<i64, Val7>::new(30);
+        assert_eq!(b.max_n(), 30);
+
+        // Pascal's triangle.
+        for n in 1..=30 {
+            for k in 1..n {
+                assert_eq!(b.binom(n, k), b.binom(n - 1, k - 1) + b.binom(n - 1, k));
+            }
+            assert_eq!(b.binom(n, 0).val(), 1);
+            assert_eq!(b.binom(n, n).val(), 1);
+        }
+        for n in 0..=30 {
+            assert_eq!((b.fact(n) * b.inv_fact(n)).val(), 1);
+        }
+        assert_eq!(b.fact(20).val(), 146_326_063);
+    }
+
+    #[test]
+    fn test_multinomial_and_arrangements() {
+        let b = Binom::<i64, Val7>::new(30);
+
+        // Multinomial is a product of binomials: C(9, 2) * C(7, 3) * C(4, 4).
+        assert_eq!(
+            b.multinomial(&[2, 3, 4]),
+            b.binom(9, 2) * b.binom(7, 3) * b.binom(4, 4)
+        );
+        assert_eq!(b.multinomial(&[0, 5, 0]).val(), 1);
+        assert_eq!(b.multinomial(&[1; 6]), b.fact(6));
+
+        for n in 0..=10 {
+            for k in 0..=n {
+                assert_eq!(b.arrangements(n, k), b.binom(n, k) * b.fact(k));
+            }
+        }
+    }
+
+    #[test]
+    fn test_tables() {
+        let b = Binom::<i64, Val7>::new(61);
+        let catalan = b.catalan_table(30);
+        let central = b.central_binom_table(30);
+        for k in 0..=30 {
+            assert_eq!(central[k], b.binom(2 * k, k));
+            assert_eq!(catalan[k], b.binom(2 * k, k) - b.binom(2 * k, k + 1));
+        }
+        // Catalan recurrence: C(k + 1) = sum(C(i) * C(k - i)).
+        for k in 0..30 {
+            let sum = (0..=k).fold(Mod7::new(0), |acc, i| acc + catalan[i] * catalan[k - i]);
+            assert_eq!(catalan[k + 1], sum);
+        }
+
+        // Inclusion-exclusion: D(n) = sum((-1)^k * C(n, k) * (n - k)!).
+        let d = Binom::<i64, Val7>::derangements(61);
+        assert_eq!(d.len(), 62);
+        for (n, &dn) in d.iter().enumerate() {
+            let sum = (0..=n).fold(Mod7::new(0), |acc, k| {
+                let term = b.binom(n, k) * b.fact(n - k);
+                if k % 2 == 0 { acc + term } else { acc - term }
+            });
+            assert_eq!(dn, sum, "n = {n}");
+        }
+        assert_eq!(Binom::<i64, Val7>::derangements(0), vec![Mod7::new(1)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Factorials up to 10 are required")]
+    fn test_catalan_table_too_big() {
+        Binom::<i64, Val7>::new(9).catalan_table(5);
+    }
+
+    #[test]
+    fn test_catalan_table_bounds() {
+        let catalan = Binom::<i64, Val7>::new(10).catalan_table(5);
+        assert_eq!(catalan[5].val(), 42);
+        let catalan = Binom::<i64, Val7>::new(1).catalan_table(0);
+        assert_eq!(catalan[0].val(), 1);
+    }
+
+    #[test]
+    fn test_small_modulo() {
+        crate::math::value!(Val13: i32 = 13);
+        let b = Binom::<i32, Val13>::new(12);
+        assert_eq!(b.binom(12, 6).val(), 924 % 13);
+    }
+
+    #[test]
+    #[should_panic(expected = "Factorials must be invertible")]
+    fn test_non_invertible() {
+        crate::math::value!(Val5: i32 = 5);
+        Binom::<i32, Val5>::new(5);
+    }
+}
@@ -0,0 +1,275 @@
+//! Precomputed tables of combinatorial numbers under a modulo.
+//!
+//! All the tables are computed with recurrences, using additions and
+//! multiplications only, so, unlike [`Binom`](super::Binom), any modulo works
+//! (not necessarily a prime):
+//!
+//! | Function | Values | Complexity
+//! | --- | --- | ---
+//! | [`stirling1`] | Unsigned Stirling numbers of the first kind `c(n, k)` | `O(n^2)`
+//! | [`stirling2`] | Stirling numbers of the second kind `S(n, k)` | `O(n^2)`
+//! | [`bell`] | Bell numbers `B(n)` | `O(n^2)`
+//! | [`catalan`] | Catalan numbers `C(n)` | `O(n^2)`
+//! | [`partitions`] | Integer partition counts `p(n)` | `O(n sqrt(n))`
+//!
+//! ```
+//! use algorist::math::{
+//!     combinatorics::tables::{bell, catalan, partitions, stirling1, stirling2},
+//!     modulo::{Mod7, Val7},
+//! };
+//!
+//! let val = |v: Vec<Mod7>| v.iter().map(|x| x.val()).collect::<Vec<_>>();
+//!
+//! // Ways to split 4 labelled elements into 2 non-empty groups.
+//! assert_eq!(stirling2::<i64, Val7>(4)[4][2].val(), 7);
+//! // Ways to arrange 4 elements into 2 cycles.
+//! assert_eq!(stirling1::<i64, Val7>(4)[4][2].val(), 11);
+//!
+//! assert_eq!(val(bell(5)), vec![1, 1, 2, 5, 15, 52]);
+//! assert_eq!(val(catalan(5)), vec![1, 1, 2, 5, 14, 42]);
+//! assert_eq!(val(partitions(7)), vec![1, 1, 2, 3, 5, 7, 11, 15]);
+//! ```
+
+use crate::math::{ConstValue, Downcast, Number, modulo::Modulo};
+
+/// Returns unsigned Stirling numbers of the first kind `c(i, k)`, for
+/// `0 <= k <= i <= n`, as a triangle: row `i` has `i + 1` values.
+///
+/// `c(i, k)` is the number of permutations of `i` elements with exactly `k`
+/// cycles, and `c(i + 1, k) = i * c(i, k) + c(i, k - 1)`.
+///
+/// # Example
+///
+/// ```
+/// use algorist::math::{combinatorics::tables::stirling1, modulo::Val7};
+///
+/// let c = stirling1::<i64, Val7>(5);
+/// let row: Vec<i64> = c[5].iter().map(|x| x.val()).collect();
+/// assert_eq!(row, vec![0, 24, 50, 35, 10, 1]);
+/// ```
+pub fn stirling1<T, M>(n: usize) -> Vec<Vec<Modulo<T, M>>>
+where
+    T: Number + Downcast,
+    T::Source: Number,
+    M: ConstValue<T>,
+{
+    triangle(n, |i, _| Modulo::new(T::new(i)))
+}
+
+/// Returns Stirling numbers of the second kind `S(i, k)`, for
+/// `0 <= k <= i <= n`, as a triangle: row `i` has `i + 1` values.
+///
+/// `S(i, k)` is the number of ways to partition a set of `i` elements into
+/// `k` non-empty subsets, and `S(i + 1, k) = k * S(i, k) + S(i, k - 1)`.
+///
+/// # Example
+///
+/// ```
+/// use algorist::math::{combinatorics::tables::stirling2, modulo::Val7};
+///
+/// let s = stirling2::<i64, Val7>(5);
+/// let row: Vec<i64> = s[5].iter().map(|x| x.val()).collect();
+/// assert_eq!(row, vec![0, 1, 15, 25, 10, 1]);
+/// ```
+pub fn stirling2<T, M>(n: usize) -> Vec<Vec<Modulo<T, M>>>
+where
+    T: Number + Downcast,
+    T::Source: Number,
+    M: ConstValue<T>,
+{
+    triangle(n, |_, k| Modulo::new(T::new(k)))
+}
+
+/// Builds a triangle by the recurrence `t(i + 1, k) = mul(i, k) * t(i, k) +
+/// t(i, k - 1)`, with `t(0, 0) = 1`.
+fn triangle<T, M, F>(n: usize, mul: F) -> Vec<Vec<Modulo<T, M>>>
+where
+    T: Number + Downcast,
+    T::Source: Number,
+    M: ConstValue<T>,
+    F: Fn(usize, usize) -> Modulo<T, M>,
+{
+    let zero = Modulo::new(T::zero());
+    let mut t = Vec::with_capacity(n + 1);
+    t.push(vec![Modulo::new(T::one())]);
+    for i in 0..n {
+        let prev: &Vec<Modulo<T, M>> = &t[i];
+        let row = (0..=i + 1)
+            .map(|k| {
+                let same = if k <= i { mul(i, k) * prev[k] } else { zero };
+                let left = if k > 0 { prev[k - 1] } else { zero };
+                same + left
+            })
+            .collect();
+        t.push(row);
+    }
+    t
+}
+
+/// Returns Bell numbers `B(i)`, for `i` in `0..=n`.
+///
+/// `B(i)` is the number of partitions of a set of `i` elements, computed with
+/// the Bell triangle in `O(n^2)` time and `O(n)` memory.
+///
+/// # Example
+///
+/// ```
+/// use algorist::math::{combinatorics::tables::bell, modulo::Val7};
+///
+/// let b = bell::<i64, Val7>(10);
+/// assert_eq!(b[10].val(), 115_975);
+/// ```
+pub fn bell<T: Number, M: ConstValue<T>>(n: usize) -> Vec<Modulo<T, M>> {
+    let mut bell = Vec::with_capacity(n + 1);
+    // Row of the Bell triangle, starting with `B(i)` and ending with `B(i + 1)`.
+    let mut row = vec![Modulo::new(T::one())];
+    for _ in 0..=n {
+        bell.push(row[0]);
+        let mut next = Vec::with_capacity(row.len() + 1);
+        next.push(*row.last().unwrap());
+        for &x in &row {
+            let last = *next.last().unwrap();
+            next.push(last + x);
+        }
+        row = next;
+    }
+    bell
+}
+
+/// Returns Catalan numbers `C(i)`, for `i` in `0..=n`, using the recurrence
+/// `C(i + 1) = sum(C(j) * C(i - j))`.
+///
+/// For a prime modulo, [`Binom::catalan_table`](super::Binom::catalan_table)
+/// is faster (`O(n)`), this one works for any modulo.
+///
+/// # Example
+///
+/// ```
+/// use algorist::math::{combinatorics::tables::catalan, modulo::Val7};
+///
+/// let c = catalan::<i64, Val7>(10);
+/// assert_eq!(c[10].val(), 16_796);
+/// ```
+pub fn catalan<T, M>(n: usize) -> Vec<Modulo<T, M>>
+where
+    T: Number + Downcast,
+    T::Source: Number,
+    M: ConstValue<T>,
+{
+    let mut c = Vec::with_capacity(n + 1);
+    c.push(Modulo::new(T::one()));
+    for i in 0..n {
+        let next = (0..=i).fold(Modulo::new(T::zero()), |acc, j| acc + c[j] * c[i - j]);
+        c.push(next);
+    }
+    c
+}
+
+/// Returns the numbers of partitions `p(i)` of an integer `i` into positive
+/// summands (order doesn't matter), for `i` in `0..=n`.
+///
+/// Uses Euler's pentagonal number theorem: `p(i) = sum((-1)^(k + 1) * (p(i -
+/// k(3k - 1) / 2) + p(i - k(3k + 1) / 2)))`, in `O(n sqrt(n))`.
+///
+/// # Example
+///
+/// ```
+/// use algorist::math::{combinatorics::tables::partitions, modulo::Val7};
+///
+/// let p = partitions::<i64, Val7>(100);
+/// assert_eq!(p[5].val(), 7); // 5, 4+1, 3+2, 3+1+1, 2+2+1, 2+1+1+1, 1+1+1+1+1
+/// assert_eq!(p[100].val(), 190_569_292);
+/// ```
+pub fn partitions<T: Number, M: ConstValue<T>>(n: usize) -> Vec<Modulo<T, M>> {
+    let mut p = Vec::with_capacity(n + 1);
+    p.push(Modulo::new(T::one()));
+    for i in 1..=n {
+        let mut sum = Modulo::new(T::zero());
+        for k in 1.. {
+            let g = k * (3 * k - 1) / 2;
+            if g > i {
+                break;
+            }
+            let mut term = p[i - g];
+            if g + k <= i {
+                term += p[i - g - k];
+            }
+            if k % 2 == 1 {
+                sum += term;
+            } else {
+                sum -= term;
+            }
+        }
+        p.push(sum);
+    }
+    p
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::math::{
+            combinatorics::Binom,
+            modulo::{Mod7, Val7},
+        },
+    };
+
+    #[test]
+    fn test_stirling() {
+        let n = 30;
+        let b = Binom::<i64, Val7>::new(n);
+        let c = stirling1::<i64, Val7>(n);
+        let s = stirling2::<i64, Val7>(n);
+        let bl = bell::<i64, Val7>(n);
+        for i in 0..=n {
+            assert_eq!(c[i].len(), i + 1);
+            // Sum of a row of the first kind is the number of permutations.
+            let sum = c[i].iter().fold(Mod7::new(0), |acc, &x| acc + x);
+            assert_eq!(sum, b.fact(i));
+            // Sum of a row of the second kind is the Bell number.
+            let sum = s[i].iter().fold(Mod7::new(0), |acc, &x| acc + x);
+            assert_eq!(sum, bl[i]);
+            // Surjections onto `k` elements: k! * S(i, k) = sum((-1)^j C(k, j) (k - j)^i).
+            for (k, &sik) in s[i].iter().enumerate() {
+                let surj = (0..=k).fold(Mod7::new(0), |acc, j| {
+                    let term = b.binom(k, j) * Mod7::new((k - j) as i64).pow_usize(i);
+                    if j % 2 == 0 { acc + term } else { acc - term }
+                });
+                assert_eq!(b.fact(k) * sik, surj, "S({i}, {k})");
+            }
+        }
+    }
+
+    #[test]
+    fn test_catalan_and_partitions() {
+        let b = Binom::<i64, Val7>::new(61);
+        assert_eq!(catalan::<i64, Val7>(30), b.catalan_table(30));
+
+        // Brute force: partitions of `i` into parts at most `j`.
+        let n = 60;
+        let mut dp = vec![vec![0u64; n + 1]; n + 1];
+        dp[0] = vec![1; n + 1];
+        for i in 1..=n {
+            for j in 1..=n {
+                dp[i][j] = dp[i][j - 1] + if j <= i { dp[i - j][j] } else { 0 };
+            }
+        }
+        let p = partitions::<i64, Val7>(n);
+        for i in 0..=n {
+            assert_eq!(p[i].val() as u64, dp[i][n], "p({i})");
+        }
+    }
+
+    #[test]
+    fn test_small_modulo() {
+        crate::math::value!(Val6: i32 = 6);
+        let c = catalan::<i32, Val6>(10);
+        assert_eq!(c[10].val(), 16_796 % 6);
+        let b = bell::<i32, Val6>(10);
+        assert_eq!(b[10].val(), 115_975 % 6);
+        assert_eq!(stirling2::<i32, Val6>(10)[10][5].val(), 42_525 % 6);
+        assert_eq!(partitions::<i32, Val6>(10)[10].val(), 42 % 6);
+        assert_eq!(stirling1::<i32, Val6>(0), vec![vec![Modulo::new(1)]]);
+    }
+}
@@ -0,0 +1,243 @@
+//! Digits of numbers in arbitrary bases.
+//!
+//! The [`Digits`] trait is implemented for all unsigned integer types, and
+//! works in any base `>= 2`. Digits are ordered as numbers are written: the
+//! most significant digit first.
+//!
+//! ```
+//! use algorist::math::digits::Digits;
+//!
+//! assert_eq!(1234u32.digits(10), vec![1, 2, 3, 4]);
+//! assert_eq!(u32::from_digits(&[1, 0, 1], 2), 5);
+//! assert_eq!(1234u32.digit_sum(10), 10);
+//! assert_eq!(1234u32.digit_count(10), 4);
+//! assert_eq!(1200u32.reversed_number(10), 21);
+//! ```
+//!
+//! # Base conversion
+//!
+//! For bases up to 36, numbers are converted to and from strings with digits
+//! `0-9`, followed by letters `a-z` (parsing is case-insensitive):
+//!
+//! ```
+//! use algorist::math::digits::{convert_base, format_radix, parse_radix};
+//!
+//! assert_eq!(format_radix(255u32, 16), "ff");
+//! assert_eq!(parse_radix::<u64>("FF", 16), Some(255));
+//! assert_eq!(parse_radix::<u8>("100", 16), None); // overflow
+//! assert_eq!(convert_base("777", 8, 2), Some("111111111".to_string()));
+//! ```
+
+/// Digits of unsigned integers, see the [module documentation](self).
+pub trait Digits: Sized {
+    /// Returns digits of the number in the `base`, the most significant first
+    /// (zero has a single digit `0`).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `base < 2`.
+    fn digits(self, base: u32) -> Vec<u32>;
+
+    /// Returns the number with the given digits in the `base`, the most
+    /// significant first, or `None` on overflow.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `base < 2`, or if some digit is not less than the base.
+    fn checked_from_digits(digits: &[u32], base: u32) -> Option<Self>;
+
+    /// Same as [`checked_from_digits`](Self::checked_from_digits), but panics
+    /// on overflow.
+    fn from_digits(digits: &[u32], base: u32) -> Self {
+        Self::checked_from_digits(digits, base).expect("Number overflows")
+    }
+
+    /// Returns the sum of digits in the `base`.
+    fn digit_sum(self, base: u32) -> u32 {
+        self.digits(base).into_iter().sum()
+    }
+
+    /// Returns the number of digits in the `base` (`1` for zero).
+    fn digit_count(self, base: u32) -> u32 {
+        self.digits(base).len() as u32
+    }
+
+    /// Returns the number with digits in the `base` reversed (leading zeros
+    /// of the result are dropped).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result overflows.
+    fn reversed_number(self, base: u32) -> Self {
+        let mut d = self.digits(base);
+        d.reverse();
+        Self::from_digits(&d, base)
+    }
+}
+
+macro_rules! digits_impl {
+    ($($t: ident)+) => {$(
+        impl Digits for $t {
+            fn digits(self, base: u32) -> Vec<u32> {
+                assert!(base >= 2, "Base must be at least 2, got {base}");
+                // A base not fitting into the type is larger than any number.
+                let Some(b) = <$t>::try_from(base).ok() else {
+                    return vec![self as u32];
+                };
+                let mut n = self;
+                let mut res = vec![];
+                loop {
+                    res.push((n % b) as u32);
+                    n /= b;
+                    if n == 0 {
+                        break;
+                    }
+                }
+                res.reverse();
+                res
+            }
+
+            fn checked_from_digits(digits: &[u32], base: u32) -> Option<$t> {
+                assert!(base >= 2, "Base must be at least 2, got {base}");
+                let b = <$t>::try_from(base).ok();
+                digits.iter().try_fold(0 as $t, |acc, &d| {
+                    assert!(d < base, "Digit {d} is out of the base {base}");
+                    match b {
+                        Some(b) => acc.checked_mul(b)?.checked_add(<$t>::try_from(d).ok()?),
+                        // A base not fitting into the type only allows for a
+                        // single non-zero digit.
+                        None if acc == 0 => <$t>::try_from(d).ok(),
+                        None => None,
+                    }
+                })
+            }
+        }
+    )+};
+}
+
+digits_impl!(u8 u16 u32 u64 u128 usize);
+
+/// Returns the character for the digit `d` (`0-9`, then `a-z`).
+fn digit_char(d: u32) -> char {
+    char::from_digit(d, 36).unwrap()
+}
+
+/// Formats the number in the `base`, with digits `0-9` and `a-z`.
+///
+/// # Panics
+///
+/// Panics if the base is not in `2..=36`.
+pub fn format_radix<T: Digits>(n: T, base: u32) -> String {
+    assert!(
+        (2..=36).contains(&base),
+        "Base must be in 2..=36, got {base}"
+    );
+    n.digits(base).into_iter().map(digit_char).collect()
+}
+
+/// Parses a number in the `base`, with digits `0-9` and `a-z` (or `A-Z`).
+/// Returns `None` if the string is empty, contains invalid digits, or the
+/// number overflows.
+///
+/// # Panics
+///
+/// Panics if the base is not in `2..=36`.
+pub fn parse_radix<T: Digits>(s: &str, base: u32) -> Option<T> {
+    assert!(
+        (2..=36).contains(&base),
+        "Base must be in 2..=36, got {base}"
+    );
+    if s.is_empty() {
+        return None;
+    }
+    let digits = s
+        .chars()
+        .map(|c| c.to_digit(base))
+        .collect::<Option<Vec<_>>>()?;
+    T::checked_from_digits(&digits, base)
+}
+
+/// Converts a number, written in the base `from`, to the base `to` (both in
+/// `2..=36`). Numbers of any length are supported. Returns `None` if the
+/// string is empty or contains invalid digits.
+///
+/// # Example
+///
+/// ```
+/// use algorist::math::digits::convert_base;
+///
+/// let big = "f".repeat(40); // 16^40 - 1 doesn't fit into u128
+/// assert_eq!(convert_base(&big, 16, 2), Some("1".repeat(160)));
+/// assert_eq!(convert_base("0010", 2, 10), Some("2".to_string()));
+/// assert_eq!(convert_base("12", 2, 10), None);
+/// ```
+pub fn convert_base(s: &str, from: u32, to: u32) -> Option<String> {
+    assert!(
+        (2..=36).contains(&from) && (2..=36).contains(&to),
+        "Bases must be in 2..=36, got {from} and {to}"
+    );
+    if s.is_empty() {
+        return None;
+    }
+    // Digits in the base `to`, the least significant first.
+    let mut res: Vec<u32> = vec![];
+    for c in s.chars() {
+        let mut carry = c.to_digit(from)?;
+        for d in res.iter_mut() {
+            let v = *d * from + carry;
+            *d = v % to;
+            carry = v / to;
+        }
+        while carry > 0 {
+            res.push(carry % to);
+            carry /= to;
+        }
+    }
+    if res.is_empty() {
+        res.push(0);
+    }
+    Some(res.into_iter().rev().map(digit_char).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::misc::rng::Rng};
+
+    #[test]
+    fn test_round_trip() {
+        let mut rng = Rng::new(22);
+        for _ in 0..200 {
+            let n: u64 = rng.gen_range(0..u64::MAX);
+            let base = rng.gen_range(2..=36);
+            let d = n.digits(base);
+            assert!(d[0] != 0 || n == 0);
+            assert_eq!(u64::from_digits(&d, base), n);
+            assert_eq!(n.digit_count(base) as usize, d.len());
+            let s = format_radix(n, base);
+            assert_eq!(u64::from_str_radix(&s, base), Ok(n));
+            assert_eq!(parse_radix::<u64>(&s, base), Some(n));
+            let to = rng.gen_range(2..=36);
+            assert_eq!(convert_base(&s, base, to), Some(format_radix(n, to)));
+        }
+    }
+
+    #[test]
+    fn test_edge_cases() {
+        assert_eq!(0u8.digits(7), vec![0]);
+        assert_eq!(255u8.digits(1000), vec![255]);
+        assert_eq!(u8::checked_from_digits(&[1, 0], 1000), None);
+        assert_eq!(u8::checked_from_digits(&[0, 255], 1000), Some(255));
+        assert_eq!(u8::checked_from_digits(&[2, 5, 6], 10), None);
+        assert_eq!(u128::MAX.digit_sum(2), 128);
+        assert_eq!(10u32.reversed_number(2), 5); // 1010 -> 0101
+        assert_eq!(parse_radix::<u32>("", 10), None);
+        assert_eq!(parse_radix::<u32>("1z", 35), None);
+        assert_eq!(convert_base("000", 10, 3), Some("0".to_string()));
+    }
+
+    #[test]
+    #[should_panic(expected = "Digit 2 is out of the base 2")]
+    fn test_invalid_digit() {
+        u32::from_digits(&[1, 2], 2);
+    }
+}
@@ -0,0 +1,200 @@
+//! Fibonacci numbers modulo `m`.
+//!
+//! [`fib_mod`] computes `F(n) mod m` in `O(log n)` using the fast doubling
+//! identities:
+//!
+//! ```text
+//! F(2k)     = F(k) * (2 * F(k + 1) - F(k))
+//! F(2k + 1) = F(k)^2 + F(k + 1)^2
+//! ```
+//!
+//! ```
+//! use algorist::math::fibonacci::fib_mod;
+//!
+//! assert_eq!(fib_mod(10, 1_000), 55);
+//! assert_eq!(
+//!     fib_mod(1_000_000_000_000_000_000, 1_000_000_007),
+//!     209_783_453
+//! );
+//! ```
+//!
+//! # Pisano period
+//!
+//! Fibonacci numbers modulo `m` are periodic, and the length of the period is
+//! known as the Pisano period, see [`pisano_period`]. It is useful when the
+//! index itself is huge, e.g. given as a result of another computation:
+//!
+//! ```
+//! use algorist::math::fibonacci::{fib_mod, pisano_period};
+//!
+//! // F(F(n)) mod m, since F(n) itself is too large to compute.
+//! let (n, m) = (1_000_000_000_000, 1_000);
+//! let period = pisano_period(m);
+//! assert_eq!(period, 1_500);
+//! assert_eq!(fib_mod(fib_mod(n, period), m), 250);
+//! ```
+
+use crate::math::{
+    gcd::lcm,
+    modulo::mul_mod_u64,
+    primes::{factorize, factors},
+};
+
+/// Returns `(F(n) mod m, F(n + 1) mod m)`, in `O(log n)`.
+///
+/// # Panics
+///
+/// Panics if `m` is zero.
+///
+/// # Example
+///
+/// ```
+/// use algorist::math::fibonacci::fib_pair_mod;
+///
+/// assert_eq!(fib_pair_mod(0, 100), (0, 1));
+/// assert_eq!(fib_pair_mod(12, 100), (44, 33));
+/// assert_eq!(fib_pair_mod(5, 1), (0, 0));
+/// ```
+pub fn fib_pair_mod(n: u64, m: u64) -> (u64, u64) {
+    assert!(m > 0, "Modulo must be positive");
+    let (mut a, mut b) = (0, 1 % m);
+    for i in (0..u64::BITS - n.leading_zeros()).rev() {
+        // (a, b) = (F(k), F(k + 1)) -> (F(2k), F(2k + 1)).
+        let c = mul_mod_u64(a, add_mod(b, add_mod(b, m - a, m), m), m);
+        let d = add_mod(mul_mod_u64(a, a, m), mul_mod_u64(b, b, m), m);
+        (a, b) = if n >> i & 1 == 1 {
+            (d, add_mod(c, d, m))
+        } else {
+            (c, d)
+        };
+    }
+    (a, b)
+}
+
+/// Returns `(a + b) mod m`, for `a, b < m`, without overflow.
+fn add_mod(a: u64, b: u64, m: u64) -> u64 {
+    ((u128::from(a) + u128::from(b)) % u128::from(m)) as u64
+}
+
+/// Returns `F(n) mod m`, in `O(log n)`, where `F(0) = 0` and `F(1) = 1`.
+///
+/// Intermediate products are computed in `u128`, so any `m` fitting into
+/// `u64` is supported.
+///
+/// # Panics
+///
+/// Panics if `m` is zero.
+///
+/// # Example
+///
+/// ```
+/// use algorist::math::fibonacci::fib_mod;
+///
+/// let fib: Vec<u64> = (0..10).map(|n| fib_mod(n, 1_000)).collect();
+/// assert_eq!(fib, vec![0, 1, 1, 2, 3, 5, 8, 13, 21, 34]);
+/// assert_eq!(fib_mod(90, u64::MAX), 2_880_067_194_370_816_120);
+/// ```
+pub fn fib_mod(n: u64, m: u64) -> u64 {
+    fib_pair_mod(n, m).0
+}
+
+/// Returns the Pisano period of `m`, i.e. the smallest `p > 0`, such that
+/// `F(n + p) = F(n) (mod m)` for all `n`.
+///
+/// The modulo is factorized, so the complexity is `O(sqrt(m))`. The period
+/// never exceeds `6m`.
+///
+/// # Panics
+///
+/// Panics if `m` is zero.
+///
+/// # Example
+///
+/// ```
+/// use algorist::math::fibonacci::pisano_period;
+///
+/// assert_eq!(pisano_period(1), 1);
+/// assert_eq!(pisano_period(2), 3);
+/// assert_eq!(pisano_period(10), 60);
+/// assert_eq!(pisano_period(1_000_000_007), 2_000_000_016);
+/// ```
+pub fn pisano_period(m: u64) -> u64 {
+    assert!(m > 0, "Modulo must be positive");
+    factorize(m as usize)
+        .into_iter()
+        .map(|f| {
+            let (p, k) = (f.factor() as u64, f.count() as u32);
+            // The period of `p^k` is `p^(k - 1)` times the period of `p`, which
+            // holds for all primes checked so far (Wall's conjecture).
+            pisano_period_prime(p) * p.pow(k - 1)
+        })
+        .fold(1, lcm)
+}
+
+/// Returns the Pisano period of a prime `p`.
+fn pisano_period_prime(p: u64) -> u64 {
+    // The period divides `p - 1` if `p = ±1 (mod 5)`, and `2(p + 1)` if
+    // `p = ±2 (mod 5)`.
+    let bound = match p {
+        2 => 3,
+        5 => 20,
+        _ if p % 5 == 1 || p % 5 == 4 => p - 1,
+        _ => 2 * (p + 1),
+    };
+    let mut divisors = factors(bound as usize);
+    divisors.sort_unstable();
+    divisors
+        .into_iter()
+        .map(|d| d as u64)
+        .find(|&d| fib_pair_mod(d, p) == (0, 1))
+        .expect("Period must divide the bound")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fib_mod() {
+        let mut fib = vec![0u128, 1];
+        for i in 2..150 {
+            fib.push(fib[i - 1] + fib[i - 2]);
+        }
+        for m in [1, 2, 7, 1_000, 1_000_000_007, u64::MAX - 58, u64::MAX] {
+            for (n, &f) in fib.iter().enumerate() {
+                let want = (f % u128::from(m)) as u64;
+                assert_eq!(fib_mod(n as u64, m), want, "F({n}) mod {m}");
+            }
+        }
+        // F(n - 1) * F(n + 1) - F(n)^2 = (-1)^n
+        let (n, m) = (u64::MAX, 998_244_353);
+        let (a, b) = fib_pair_mod(n - 1, m);
+        let lhs = add_mod(
+            mul_mod_u64(a, add_mod(a, b, m), m),
+            m - mul_mod_u64(b, b, m),
+            m,
+        );
+        assert_eq!(lhs, m - 1);
+    }
+
+    #[test]
+    fn test_pisano_period() {
+        for m in 1..300 {
+            // Brute force: first return to (0, 1).
+            let (mut a, mut b, mut want) = (0, 1 % m, 0);
+            loop {
+                (a, b) = (b, (a + b) % m);
+                want += 1;
+                if (a, b) == (0, 1 % m) {
+                    break;
+                }
+            }
+            let period = pisano_period(m);
+            assert_eq!(period, want, "m = {m}");
+            assert!(period <= 6 * m);
+            for n in [0, 1, 17, 12_345] {
+                assert_eq!(fib_mod(n + period, m), fib_mod(n, m));
+            }
+        }
+    }
+}
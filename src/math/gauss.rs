@@ -0,0 +1,363 @@
+//! Gaussian elimination over fields: [`Modulo`] numbers and `f64`.
+//!
+//! Solves systems of linear equations `A * x = b` (see [`solve`]), and computes
+//! [`rank`] and [`determinant`] of matrices, given as [`Arr`].
+//!
+//! For `f64`, partial pivoting (the row with the biggest absolute value goes
+//! first) is used, and values with absolute value `<= EPS` are treated as
+//! zeros (see [`linear::EPS`]). For modulo numbers, the computations are
+//! exact, but the modulo must be a prime.
+//!
+//! # Example
+//!
+//! ```
+//! use algorist::{
+//!     collections::arr_2d::Arr,
+//!     math::{
+//!         gauss::{Solution, determinant, solve},
+//!         modulo::Mod7,
+//!     },
+//! };
+//!
+//! // x + 2y = 5
+//! // 3x + 4y = 6
+//! let a = Arr::from_vec(vec![1., 2., 3., 4.], 2, 2);
+//! let Solution::Unique(x) = solve(&a, &[5., 6.]) else {
+//!     panic!("Unique solution expected")
+//! };
+//! assert!((x[0] + 4.).abs() < 1e-9 && (x[1] - 4.5).abs() < 1e-9);
+//! assert!((determinant(&a) + 2.).abs() < 1e-9);
+//!
+//! // Same system, modulo 1_000_000_007.
+//! let a = Arr::from_vec([1, 2, 3, 4].map(Mod7::new).to_vec(), 2, 2);
+//! let b = [5, 6].map(Mod7::new);
+//! assert_eq!(
+//!     solve(&a, &b),
+//!     Solution::Unique(vec![Mod7::new(-4), Mod7::new(9) / Mod7::new(2)])
+//! );
+//! ```
+
+use {
+    crate::{
+        collections::arr_2d::Arr,
+        math::{ConstValue, Downcast, Number, linear, modulo::Modulo},
+    },
+    std::{
+        fmt::Debug,
+        ops::{Add, Div, Mul, Sub},
+    },
+};
+
+/// Field, over which Gaussian elimination can be performed.
+pub trait Field:
+    Copy + Debug + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self>
+{
+    fn zero() -> Self;
+
+    fn one() -> Self;
+
+    /// Checks whether the value is (close enough to) zero.
+    fn is_zero(&self) -> bool;
+
+    /// Quality of the value as a pivot, the bigger the better.
+    fn magnitude(&self) -> f64;
+}
+
+impl Field for f64 {
+    fn zero() -> Self {
+        0.
+    }
+
+    fn one() -> Self {
+        1.
+    }
+
+    fn is_zero(&self) -> bool {
+        self.abs() <= linear::EPS
+    }
+
+    fn magnitude(&self) -> f64 {
+        self.abs()
+    }
+}
+
+impl<T, M> Field for Modulo<T, M>
+where
+    T: Number + Downcast,
+    T::Source: Number,
+    M: ConstValue<T>,
+{
+    fn zero() -> Self {
+        Self::new(T::zero())
+    }
+
+    fn one() -> Self {
+        Self::new(T::one())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.val() == T::zero()
+    }
+
+    fn magnitude(&self) -> f64 {
+        if self.is_zero() { 0. } else { 1. }
+    }
+}
+
+/// Solution space of a system of linear equations.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Solution<F> {
+    /// System is inconsistent.
+    None,
+    /// System has the only solution.
+    Unique(Vec<F>),
+    /// System has infinitely many (or, for modulo numbers, more than one)
+    /// solutions. All of them are obtained by choosing arbitrary values of
+    /// the `free` variables, and solving for the rest. The `particular`
+    /// solution has all free variables set to zero.
+    Infinite {
+        particular: Vec<F>,
+        free: Vec<usize>,
+    },
+}
+
+/// Result of the elimination.
+struct Echelon<F> {
+    /// Pivot columns, in increasing order.
+    pivots: Vec<usize>,
+    /// Product of pivot values, with the sign of the row permutation.
+    det: F,
+}
+
+/// Transforms rows into the reduced row echelon form, eliminating the first
+/// `cols` columns.
+fn eliminate<F: Field>(m: &mut [Vec<F>], cols: usize) -> Echelon<F> {
+    let mut pivots = Vec::new();
+    let mut det = F::one();
+    let mut row = 0;
+    for col in 0..cols {
+        if row == m.len() {
+            break;
+        }
+        let best = (row..m.len())
+            .max_by(|&i, &j| m[i][col].magnitude().total_cmp(&m[j][col].magnitude()))
+            .unwrap();
+        if m[best][col].is_zero() {
+            continue;
+        }
+        if best != row {
+            m.swap(best, row);
+            det = F::zero() - det;
+        }
+
+        let pivot = m[row][col];
+        det = det * pivot;
+        for x in &mut m[row][col..] {
+            *x = *x / pivot;
+        }
+        let pivot_row = m[row].clone();
+        for (r, cur) in m.iter_mut().enumerate() {
+            let factor = cur[col];
+            if r != row && !factor.is_zero() {
+                for (x, &p) in cur[col..].iter_mut().zip(&pivot_row[col..]) {
+                    *x = *x - factor * p;
+                }
+            }
+        }
+        pivots.push(col);
+        row += 1;
+    }
+    Echelon { pivots, det }
+}
+
+fn rows_of<F: Field>(a: &Arr<F>) -> Vec<Vec<F>> {
+    (0..a.rows()).map(|r| a[r].to_vec()).collect()
+}
+
+/// Solves the system of linear equations `a * x = b`, in `O(n * m * min(n,
+/// m))`, where `n` is the number of equations (rows of `a`) and `m` is the
+/// number of variables (columns of `a`).
+///
+/// # Panics
+///
+/// Panics if the length of `b` is not equal to the number of rows of `a`.
+///
+/// # Example
+///
+/// ```
+/// use algorist::{
+///     collections::arr_2d::Arr,
+///     math::{
+///         gauss::{Solution, solve},
+///         modulo::Mod7,
+///     },
+/// };
+///
+/// let m = |v: &[i64]| v.iter().map(|&x| Mod7::new(x)).collect::<Vec<_>>();
+///
+/// // x + y + z = 6, x - y = 0: z is free.
+/// let a = Arr::from_vec(m(&[1, 1, 1, 1, -1, 0]), 2, 3);
+/// assert_eq!(solve(&a, &m(&[6, 0])), Solution::Infinite {
+///     particular: m(&[3, 3, 0]),
+///     free: vec![2],
+/// });
+///
+/// // x + y = 1, x + y = 2: inconsistent.
+/// let a = Arr::from_vec(m(&[1, 1, 1, 1]), 2, 2);
+/// assert_eq!(solve(&a, &m(&[1, 2])), Solution::None);
+/// ```
+pub fn solve<F: Field>(a: &Arr<F>, b: &[F]) -> Solution<F> {
+    let (n, vars) = (a.rows(), a.cols());
+    assert_eq!(
+        n,
+        b.len(),
+        "Number of equations and right-hand sides must be the same"
+    );
+
+    let mut m = rows_of(a);
+    for (row, &rhs) in m.iter_mut().zip(b) {
+        row.push(rhs);
+    }
+    let pivots = eliminate(&mut m, vars).pivots;
+
+    if m[pivots.len()..].iter().any(|row| !row[vars].is_zero()) {
+        return Solution::None;
+    }
+    let mut x = vec![F::zero(); vars];
+    for (row, &col) in pivots.iter().enumerate() {
+        x[col] = m[row][vars];
+    }
+    if pivots.len() == vars {
+        return Solution::Unique(x);
+    }
+    let free = (0..vars)
+        .filter(|col| pivots.binary_search(col).is_err())
+        .collect();
+    Solution::Infinite {
+        particular: x,
+        free,
+    }
+}
+
+/// Returns the rank of the matrix.
+///
+/// For `f64` matrices with configurable tolerance, see
+/// [`rank_f64`](linear::rank_f64).
+///
+/// # Example
+///
+/// ```
+/// use algorist::{collections::arr_2d::Arr, math::gauss::rank};
+///
+/// let a = Arr::from_vec(vec![1., 2., 2., 4.], 2, 2);
+/// assert_eq!(rank(&a), 1);
+/// ```
+pub fn rank<F: Field>(a: &Arr<F>) -> usize {
+    eliminate(&mut rows_of(a), a.cols()).pivots.len()
+}
+
+/// Returns the determinant of the square matrix.
+///
+/// # Panics
+///
+/// Panics if the matrix is not square.
+///
+/// # Example
+///
+/// ```
+/// use algorist::{
+///     collections::arr_2d::Arr,
+///     math::{gauss::determinant, modulo::Mod7},
+/// };
+///
+/// let a = Arr::from_vec([0, 1, 1, 0].map(Mod7::new).to_vec(), 2, 2);
+/// assert_eq!(determinant(&a), Mod7::new(-1));
+///
+/// let a = Arr::from_vec(vec![2., 0., 0., 0., 3., 0., 0., 0., 4.], 3, 3);
+/// assert!((determinant(&a) - 24.).abs() < 1e-9);
+/// ```
+pub fn determinant<F: Field>(a: &Arr<F>) -> F {
+    assert_eq!(a.rows(), a.cols(), "Matrix must be square");
+    let n = a.rows();
+    let Echelon { pivots, det } = eliminate(&mut rows_of(a), n);
+    if pivots.len() < n { F::zero() } else { det }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::math::modulo::Mod7};
+
+    fn m7(v: &[i64]) -> Vec<Mod7> {
+        v.iter().map(|&x| Mod7::new(x)).collect()
+    }
+
+    /// Checks that `a * x = b`.
+    fn check<F: Field + PartialEq>(a: &Arr<F>, x: &[F], b: &[F]) {
+        for r in 0..a.rows() {
+            let lhs = a[r]
+                .iter()
+                .zip(x)
+                .fold(F::zero(), |acc, (&c, &v)| acc + c * v);
+            assert!((lhs - b[r]).is_zero(), "row {r}: {lhs:?} != {:?}", b[r]);
+        }
+    }
+
+    #[test]
+    fn test_solve_modulo() {
+        let a = Arr::from_vec(m7(&[2, 1, -1, -3, -1, 2, -2, 1, 2]), 3, 3);
+        let b = m7(&[8, -11, -3]);
+        assert_eq!(solve(&a, &b), Solution::Unique(m7(&[2, 3, -1])));
+        assert_eq!(determinant(&a), Mod7::new(-1));
+        assert_eq!(rank(&a), 3);
+
+        // Dependent equations, two free variables.
+        let a = Arr::from_vec(m7(&[1, 2, 3, 4, 2, 4, 6, 8]), 2, 4);
+        let Solution::Infinite { particular, free } = solve(&a, &m7(&[5, 10])) else {
+            panic!("Infinite solutions expected");
+        };
+        assert_eq!(free, vec![1, 2, 3]);
+        check(&a, &particular, &m7(&[5, 10]));
+        assert_eq!(solve(&a, &m7(&[5, 11])), Solution::None);
+        assert_eq!(rank(&a), 1);
+
+        // More equations than variables.
+        let a = Arr::from_vec(m7(&[1, 1, 1, -1, 2, 1]), 3, 2);
+        assert_eq!(solve(&a, &m7(&[3, 1, 5])), Solution::Unique(m7(&[2, 1])));
+        assert_eq!(solve(&a, &m7(&[3, 1, 6])), Solution::None);
+    }
+
+    #[test]
+    fn test_solve_f64() {
+        let a = Arr::from_vec(vec![0., 2., 1., 1., 1., 1., 2., 1., 0.], 3, 3);
+        let b = [5., 6., 4.];
+        let Solution::Unique(x) = solve(&a, &b) else {
+            panic!("Unique solution expected");
+        };
+        check(&a, &x, &b);
+        assert!((determinant(&a) - 3.).abs() < 1e-9);
+
+        let a = Arr::from_vec(vec![1., 2., 2., 4.], 2, 2);
+        assert_eq!(determinant(&a), 0.);
+        assert_eq!(solve(&a, &[1., 3.]), Solution::None);
+        assert_eq!(solve(&a, &[1., 2.]), Solution::Infinite {
+            particular: vec![1., 0.],
+            free: vec![1],
+        });
+    }
+
+    #[test]
+    fn test_determinant() {
+        // Permutation matrices: determinant is the sign of the permutation.
+        let a = Arr::from_vec(m7(&[0, 1, 0, 0, 0, 1, 1, 0, 0]), 3, 3);
+        assert_eq!(determinant(&a), Mod7::new(1));
+        let a = Arr::from_vec(m7(&[0, 1, 0, 1, 0, 0, 0, 0, 1]), 3, 3);
+        assert_eq!(determinant(&a), Mod7::new(-1));
+
+        // Vandermonde matrix of 1, 2, 3, 4: product of (xj - xi).
+        let a = Arr::with_generator(4, 4, |i, j| Mod7::new((i as i64 + 1).pow(j as u32)));
+        assert_eq!(determinant(&a), Mod7::new(12));
+
+        let a = Arr::from_vec(Vec::<f64>::new(), 0, 0);
+        assert_eq!(determinant(&a), 1.);
+    }
+}
@@ -18,9 +18,36 @@
 //! let (a, b) = (240, 46);
 //! assert_eq!(gcd(a, b) * lcm(a, b), a * b);
 //! ```
+//!
+//! All functions work with any primitive integer type, including `i128` and
+//! `u128`. For unsigned types, the faster [`BinaryGcd`] is also available. When
+//! LCM may not fit into the type, use [`checked_lcm`].
+//!
+//! # Linear Diophantine equations
+//!
+//! Using the [extended Euclidean algorithm](gcd_extended), all integer
+//! solutions of `a * x + b * y = c` can be found with [`solve_linear`], and
+//! modular inverses with [`mod_inverse`].
+//!
+//! ```
+//! use algorist::math::gcd::{mod_inverse, solve_linear};
+//!
+//! // 6x + 9y = 21
+//! let sol = solve_linear(6i64, 9, 21).unwrap();
+//! for k in -3..3 {
+//!     let (x, y) = sol.at(k);
+//!     assert_eq!(6 * x + 9 * y, 21);
+//! }
+//!
+//! // 6x + 9y = 20 has no integer solutions, since gcd(6, 9) = 3 doesn't divide 20.
+//! assert!(solve_linear(6, 9, 20).is_none());
+//!
+//! assert_eq!(mod_inverse(3, 7), Some(5));
+//! assert_eq!(mod_inverse(2, 4), None);
+//! ```
 
 use {
-    crate::math::{Downcast, Number, One, Zero},
+    crate::math::{Bezout, CheckedMul, Number, One, Zero},
     std::mem::swap,
 };
 
@@ -37,16 +64,16 @@ use {
 /// assert_eq!(gcd_extended(a, b), (2, -9, 47));
 /// ```
 #[allow(clippy::many_single_char_names)]
-pub fn gcd_extended<T>(a: T, b: T) -> (T, T::Source, T::Source)
+pub fn gcd_extended<T>(a: T, b: T) -> (T, T::Coeff, T::Coeff)
 where
-    T: Number + Downcast,
-    T::Source: Number,
+    T: Number + Bezout,
+    T::Coeff: Number,
 {
     if b == T::zero() {
-        return (a, T::Source::one(), T::Source::zero());
+        return (a, T::Coeff::one(), T::Coeff::zero());
     }
     let (d, x, y) = gcd_extended(b, a % b);
-    (d, y, x - T::Source::from(a / b) * y)
+    (d, y, x - T::Coeff::from(a / b) * y)
 }
 
 /// Computes the greatest common divisor (GCD) of two numbers.
@@ -82,6 +109,200 @@ pub fn lcm<T: Number>(a: T, b: T) -> T {
     a / gcd(a, b) * b
 }
 
+/// Returns the least common multiple (LCM) of two numbers, or `None` if it
+/// overflows `T`.
+///
+/// # Example
+///
+/// ```
+/// use algorist::math::gcd::checked_lcm;
+///
+/// assert_eq!(checked_lcm(24u8, 36), Some(72));
+/// assert_eq!(checked_lcm(24u8, 35), None);
+/// assert_eq!(checked_lcm(0u8, 35), Some(0));
+///
+/// let p = 1_000_000_007i128;
+/// assert_eq!(checked_lcm(p * p, p * p * p), Some(p * p * p));
+/// assert_eq!(checked_lcm(i128::MAX, i128::MAX - 1), None);
+/// ```
+pub fn checked_lcm<T: Number + CheckedMul>(a: T, b: T) -> Option<T> {
+    if a == T::zero() || b == T::zero() {
+        return Some(T::zero());
+    }
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// Binary GCD (Stein's algorithm) for unsigned integers.
+///
+/// Replaces divisions of the Euclidean algorithm with shifts and subtractions,
+/// which is about twice as fast for types up to 64 bits. For `u128`, where
+/// shifts and subtractions are emulated, it is slower than [`gcd`] (see
+/// `benches/gcd.rs`).
+///
+/// # Example
+///
+/// ```
+/// use algorist::math::gcd::BinaryGcd;
+///
+/// assert_eq!(240u32.binary_gcd(46), 2);
+/// assert_eq!(0u64.binary_gcd(5), 5);
+/// assert_eq!((1u128 << 100).binary_gcd(3 << 40), 1 << 40);
+/// ```
+pub trait BinaryGcd {
+    /// Computes the greatest common divisor of `self` and `other`.
+    fn binary_gcd(self, other: Self) -> Self;
+}
+
+macro_rules! binary_gcd_impl {
+    ($($t: ident)+) => {$(
+        impl BinaryGcd for $t {
+            fn binary_gcd(self, other: $t) -> $t {
+                let (mut u, mut v) = (self, other);
+                if u == 0 || v == 0 {
+                    return u | v;
+                }
+                let shift = (u | v).trailing_zeros();
+                u >>= u.trailing_zeros();
+                loop {
+                    v >>= v.trailing_zeros();
+                    if u > v {
+                        swap(&mut u, &mut v);
+                    }
+                    v -= u;
+                    if v == 0 {
+                        return u << shift;
+                    }
+                }
+            }
+        }
+    )+};
+}
+
+binary_gcd_impl!(u8 u16 u32 u64 u128 usize);
+
+/// All integer solutions of a linear Diophantine equation `a * x + b * y = c`.
+///
+/// Solutions are `(x + k * dx, y - k * dy)` for any integer `k`, see
+/// [`LinearSolution::at`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LinearSolution<T> {
+    /// Particular solution, `x` part.
+    pub x: T,
+    /// Particular solution, `y` part.
+    pub y: T,
+    /// Step of `x` between consecutive solutions, `b / gcd(a, b)`.
+    pub dx: T,
+    /// Step of `y` between consecutive solutions, `a / gcd(a, b)`.
+    pub dy: T,
+}
+
+impl<T: Number> LinearSolution<T> {
+    /// Returns the `k`-th solution of the family, `(x + k * dx, y - k * dy)`.
+    pub fn at(&self, k: T) -> (T, T) {
+        (self.x + k * self.dx, self.y - k * self.dy)
+    }
+}
+
+/// Solves the linear Diophantine equation `a * x + b * y = c`.
+///
+/// Returns `None` if there are no integer solutions (i.e. when `gcd(a, b)`
+/// doesn't divide `c`). Otherwise, returns the particular solution along with
+/// steps describing all the solutions, see [`LinearSolution`]. If `b != 0`,
+/// the particular solution has the smallest non-negative `x`.
+///
+/// Computations are done in the wider type, `T::Coeff` (e.g. `i64` for `i32`
+/// input), and must not overflow it.
+///
+/// # Panics
+///
+/// Panics if both `a` and `b` are zero.
+///
+/// # Example
+///
+/// ```
+/// use algorist::math::gcd::{LinearSolution, solve_linear};
+///
+/// // 4x - 6y = 2
+/// let sol = solve_linear(4i32, -6, 2).unwrap();
+/// assert_eq!(sol, LinearSolution {
+///     x: 2,
+///     y: 1,
+///     dx: -3,
+///     dy: 2
+/// });
+/// assert_eq!(sol.at(1), (-1, -1));
+///
+/// assert!(solve_linear(4, -6, 3).is_none());
+/// ```
+pub fn solve_linear<T>(a: T, b: T, c: T) -> Option<LinearSolution<T::Coeff>>
+where
+    T: Number + Bezout,
+    T::Coeff: Number,
+{
+    assert!(
+        a != T::zero() || b != T::zero(),
+        "At least one of coefficients must be non-zero"
+    );
+    let zero = T::Coeff::zero();
+    let (g, x, y) = gcd_extended(a, b);
+    let g = T::Coeff::from(g);
+    let (a, b, c) = (T::Coeff::from(a), T::Coeff::from(b), T::Coeff::from(c));
+    let (g, x, y) = if g < zero {
+        (zero - g, zero - x, zero - y)
+    } else {
+        (g, x, y)
+    };
+    if c % g != zero {
+        return None;
+    }
+
+    let (dx, dy) = (b / g, a / g);
+    let (mut x, mut y) = (x * (c / g), y * (c / g));
+    if dx != zero {
+        let step = if dx < zero { zero - dx } else { dx };
+        x %= step;
+        if x < zero {
+            x += step;
+        }
+        y = (c - a * x) / b;
+    }
+    Some(LinearSolution { x, y, dx, dy })
+}
+
+/// Computes the modular inverse of `a` modulo `m`, i.e. such `x` in `[0, m)`
+/// that `a * x = 1 (mod m)`.
+///
+/// Returns `None` if the inverse doesn't exist (i.e. `a` and `m` are not
+/// coprime). Negative `a` is allowed. Just like [`gcd_extended`], requires a
+/// signed type.
+///
+/// # Example
+///
+/// ```
+/// use algorist::math::gcd::mod_inverse;
+///
+/// assert_eq!(mod_inverse(2, 1_000_000_007), Some(500_000_004));
+/// assert_eq!(mod_inverse(-3, 7), Some(2));
+/// assert_eq!(mod_inverse(6, 9), None);
+/// ```
+pub fn mod_inverse<T>(a: T, m: T) -> Option<T>
+where
+    T: Number + Bezout,
+    T::Coeff: Number,
+{
+    assert!(m > T::zero(), "Modulo must be positive");
+    let mut a = a % m;
+    if a < T::zero() {
+        a += m;
+    }
+    let (d, x, _) = gcd_extended(a, m);
+    if d != T::one() {
+        return None;
+    }
+    let m = T::Coeff::from(m);
+    Some(T::from_coeff((x % m + m) % m))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -105,4 +326,108 @@ mod tests {
         assert_eq!(lcm(5, 0), 0);
         assert_eq!(lcm(24, 36), 72);
     }
+
+    #[test]
+    fn test_128_bit() {
+        let p = 1_000_000_007i128;
+        let q = 998_244_353i128;
+        assert_eq!(gcd(p * p * q, p * q * q), p * q);
+        assert_eq!(lcm(p * p, q * q), p * p * q * q);
+        assert_eq!(gcd(u128::MAX, 3u128 << 100), 3);
+
+        let (d, x, y) = gcd_extended(p * p, q * q);
+        assert_eq!(d, 1);
+        assert_eq!(p * p * x + q * q * y, 1);
+
+        assert_eq!(mod_inverse(2, p * p), Some((p * p + 1) / 2));
+        let sol = solve_linear(p * p, q * q, 5).unwrap();
+        assert_eq!(p * p * sol.x + q * q * sol.y, 5);
+    }
+
+    #[test]
+    fn test_checked_lcm() {
+        for a in 0u8..=255 {
+            for b in 0u8..=255 {
+                let want = if a == 0 || b == 0 {
+                    Some(0)
+                } else {
+                    u8::try_from(u32::from(a) * u32::from(b) / u32::from(gcd(a, b))).ok()
+                };
+                assert_eq!(checked_lcm(a, b), want, "a = {a}, b = {b}");
+            }
+        }
+        assert_eq!(checked_lcm(-4i32, 6), Some(-12));
+        assert_eq!(checked_lcm(u64::MAX, u64::MAX), Some(u64::MAX));
+        assert_eq!(checked_lcm(u64::MAX, u64::MAX - 1), None);
+    }
+
+    #[test]
+    fn test_binary_gcd() {
+        for a in 0u16..300 {
+            for b in 0u16..300 {
+                assert_eq!(a.binary_gcd(b), gcd(a, b), "a = {a}, b = {b}");
+            }
+        }
+        let mut x = 0x9e37_79b9_7f4a_7c15_u64;
+        for _ in 0..1000 {
+            x = x.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1);
+            let (a, b) = (x >> 20, (x << 7) >> 30);
+            assert_eq!(a.binary_gcd(b), gcd(a, b));
+            assert_eq!(
+                u128::from(a).binary_gcd(u128::from(b)),
+                u128::from(gcd(a, b))
+            );
+            assert_eq!((a as usize).binary_gcd(b as usize), gcd(a, b) as usize);
+        }
+        assert_eq!(u8::MAX.binary_gcd(u8::MAX), u8::MAX);
+        assert_eq!(u128::MAX.binary_gcd(0), u128::MAX);
+    }
+
+    #[test]
+    fn test_solve_linear() {
+        for a in -12i32..=12 {
+            for b in -12i32..=12 {
+                if a == 0 && b == 0 {
+                    continue;
+                }
+                for c in -20i32..=20 {
+                    let sol = solve_linear(a, b, c);
+                    let (a, b, c) = (i64::from(a), i64::from(b), i64::from(c));
+                    let solvable = (-30..=30).any(|x| b != 0 && (c - a * x) % b == 0)
+                        || (b == 0 && c % a == 0);
+                    assert_eq!(sol.is_some(), solvable, "{a}x + {b}y = {c}");
+
+                    let Some(sol) = sol else { continue };
+                    for k in -5..=5 {
+                        let (x, y) = sol.at(k);
+                        assert_eq!(a * x + b * y, c, "{a}x + {b}y = {c}, k = {k}");
+                    }
+                    if b != 0 {
+                        assert!(0 <= sol.x && sol.x < sol.dx.abs());
+                    }
+                    // Consecutive solutions are as close as possible.
+                    let g = i64::from(gcd(a.unsigned_abs(), b.unsigned_abs()) as i32);
+                    assert_eq!((sol.dx.abs(), sol.dy.abs()), (b.abs() / g, a.abs() / g));
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "At least one of coefficients must be non-zero")]
+    fn test_solve_linear_zero_coefficients() {
+        solve_linear(0, 0, 0);
+    }
+
+    #[test]
+    fn test_mod_inverse() {
+        for m in 1i64..50 {
+            for a in -60..60 {
+                let inv = mod_inverse(a, m);
+                let want = (0..m).find(|&x| (a * x).rem_euclid(m) == 1 % m);
+                assert_eq!(inv, want, "a = {a}, m = {m}");
+            }
+        }
+        assert_eq!(mod_inverse(i64::MAX - 1, i64::MAX), Some(i64::MAX - 1));
+    }
 }
@@ -0,0 +1,117 @@
+//! Linear algebra over floating point numbers.
+//!
+//! # Rank
+//!
+//! To compute the rank of a matrix (given as [`Arr<f64>`]), without solving
+//! any system, use [`rank_f64`]. Geometry problems often need it to detect
+//! degenerate configurations (e.g. whether points are collinear or coplanar).
+//!
+//! ```
+//! use algorist::{
+//!     collections::arr_2d::Arr,
+//!     math::linear::{EPS, rank_f64},
+//! };
+//!
+//! // Vectors (1, 2, 3), (2, 4, 6) and (1, 0, 1): the first two are collinear.
+//! let m = Arr::from_vec(vec![1., 2., 3., 2., 4., 6., 1., 0., 1.], 3, 3);
+//! let (rank, pivot_cols) = rank_f64(&m, EPS);
+//! assert_eq!(rank, 2);
+//! assert_eq!(pivot_cols, vec![0, 1]);
+//! ```
+
+use crate::collections::arr_2d::Arr;
+
+/// Default tolerance, below which values are treated as zeros.
+pub const EPS: f64 = 1e-9;
+
+/// Computes the rank of a matrix, using Gaussian elimination with partial
+/// pivoting, in `O(rows * cols * min(rows, cols))`.
+///
+/// Values with absolute value `<= eps` are treated as zeros. The tolerance is
+/// absolute, so for matrices with large entries consider normalizing rows
+/// first, or use bigger `eps`.
+///
+/// Returns the rank, and the columns in which pivots were found, in order of
+/// elimination (i.e. the indexes of linearly independent columns, in
+/// increasing order). The length of the returned vector is equal to the rank.
+///
+/// # Example
+///
+/// ```
+/// use algorist::{collections::arr_2d::Arr, math::linear::rank_f64};
+///
+/// // The second column is zero, the third one is twice the first one.
+/// let m = Arr::from_vec(vec![1., 0., 2., 3., 0., 6.], 2, 3);
+/// assert_eq!(rank_f64(&m, 1e-9), (1, vec![0]));
+///
+/// // Tolerance decides what is (almost) degenerate.
+/// let m = Arr::from_vec(vec![1., 1., 1., 1.000_001], 2, 2);
+/// assert_eq!(rank_f64(&m, 1e-9).0, 2);
+/// assert_eq!(rank_f64(&m, 1e-3).0, 1);
+/// ```
+pub fn rank_f64(matrix: &Arr<f64>, eps: f64) -> (usize, Vec<usize>) {
+    let (rows, cols) = (matrix.rows(), matrix.cols());
+    let mut a: Vec<Vec<f64>> = (0..rows).map(|r| matrix[r].to_vec()).collect();
+    let mut pivot_cols = Vec::with_capacity(rows.min(cols));
+
+    let mut row = 0;
+    for col in 0..cols {
+        if row == rows {
+            break;
+        }
+        let best = (row..rows)
+            .max_by(|&i, &j| a[i][col].abs().total_cmp(&a[j][col].abs()))
+            .unwrap();
+        if a[best][col].abs() <= eps {
+            continue;
+        }
+        a.swap(row, best);
+        let (top, bottom) = a.split_at_mut(row + 1);
+        let pivot = &top[row];
+        for r in bottom {
+            let factor = r[col] / pivot[col];
+            if factor != 0. {
+                for (x, &p) in r[col..].iter_mut().zip(&pivot[col..]) {
+                    *x -= factor * p;
+                }
+            }
+        }
+        pivot_cols.push(col);
+        row += 1;
+    }
+    (row, pivot_cols)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rank_f64() {
+        let m = Arr::from_vec(vec![], 0, 0);
+        assert_eq!(rank_f64(&m, EPS), (0, vec![]));
+
+        let m = Arr::from_vec(vec![0.; 6], 2, 3);
+        assert_eq!(rank_f64(&m, EPS), (0, vec![]));
+
+        let m = Arr::with_generator(3, 3, |i, j| f64::from(u8::from(i == j)));
+        assert_eq!(rank_f64(&m, EPS), (3, vec![0, 1, 2]));
+
+        // Wide matrix.
+        let m = Arr::from_vec(vec![1., 2., 3., 4., 2., 4., 6., 9.], 2, 4);
+        assert_eq!(rank_f64(&m, EPS), (2, vec![0, 3]));
+
+        // Tall matrix.
+        let m = Arr::from_vec(vec![1., 2., 2., 4., 3., 6., 0., 1.], 4, 2);
+        assert_eq!(rank_f64(&m, EPS), (2, vec![0, 1]));
+
+        // Pivot requires row swap.
+        let m = Arr::from_vec(vec![0., 1., 1., 0.], 2, 2);
+        assert_eq!(rank_f64(&m, EPS), (2, vec![0, 1]));
+
+        // Points (0, 0, 0), (1, 1, 1), (2, 2, 2.0000001) are almost collinear.
+        let m = Arr::from_vec(vec![1., 1., 1., 2., 2., 2.000_000_1], 2, 3);
+        assert_eq!(rank_f64(&m, EPS).0, 2);
+        assert_eq!(rank_f64(&m, 1e-6).0, 1);
+    }
+}
@@ -0,0 +1,171 @@
+//! Integer logarithms.
+//!
+//! Computing `(n as f64).log(b)` and rounding is subtly wrong near powers of
+//! the base (e.g. `1000f64.log10()` is fine, but `(1000f64).ln() / 10f64.ln()`
+//! is `2.9999999999999996`), and loses precision for large 64-bit numbers.
+//! The [`IntLog`] trait computes logarithms using integer operations only,
+//! for all integer types.
+//!
+//! ```
+//! use algorist::math::log::IntLog;
+//!
+//! assert_eq!(1000.log_floor(10), 3);
+//! assert_eq!(999.log_floor(10), 2);
+//! assert_eq!(1000.log_ceil(10), 3);
+//! assert_eq!(1001.log_ceil(10), 4);
+//!
+//! // Number of digits of `n` in base `b` is `log_floor(b) + 1`.
+//! assert_eq!(u64::MAX.log_floor(10) + 1, 20);
+//!
+//! assert_eq!(0.checked_ilog(2), None);
+//! assert_eq!((-8).checked_ilog(2), None);
+//! ```
+
+use crate::math::Integer;
+
+/// Integer logarithms, see the [module documentation](self).
+pub trait IntLog: Integer {
+    /// Returns `floor(log_base(self))`, or `None` if the number is not
+    /// positive, or the base is less than 2.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::math::log::IntLog;
+    ///
+    /// assert_eq!(8u8.checked_ilog(2), Some(3));
+    /// assert_eq!(255u8.checked_ilog(2), Some(7));
+    /// assert_eq!(1.checked_ilog(7), Some(0));
+    /// assert_eq!(10.checked_ilog(1), None);
+    /// ```
+    fn checked_ilog(self, base: Self) -> Option<u32> {
+        if self <= Self::zero() || base <= Self::one() {
+            return None;
+        }
+        let (mut n, mut k) = (self, 0);
+        while n >= base {
+            n /= base;
+            k += 1;
+        }
+        Some(k)
+    }
+
+    /// Returns the largest `k`, such that `base^k <= self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is not positive, or the base is less than 2.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::math::log::IntLog;
+    ///
+    /// assert_eq!(1.log_floor(2), 0);
+    /// assert_eq!(1023.log_floor(2), 9);
+    /// assert_eq!(1024.log_floor(2), 10);
+    /// assert_eq!(i64::MAX.log_floor(2), 62);
+    /// ```
+    fn log_floor(self, base: Self) -> u32 {
+        assert!(base > Self::one(), "Base must be at least 2, got {base}");
+        self.checked_ilog(base)
+            .unwrap_or_else(|| panic!("Logarithm of non-positive number: {self}"))
+    }
+
+    /// Returns the smallest `k`, such that `base^k >= self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is not positive, or the base is less than 2.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::math::log::IntLog;
+    ///
+    /// assert_eq!(1.log_ceil(2), 0);
+    /// assert_eq!(1024.log_ceil(2), 10);
+    /// assert_eq!(1025.log_ceil(2), 11);
+    /// assert_eq!(u64::MAX.log_ceil(2), 64);
+    /// ```
+    fn log_ceil(self, base: Self) -> u32 {
+        assert!(base > Self::one(), "Base must be at least 2, got {base}");
+        assert!(
+            self > Self::zero(),
+            "Logarithm of non-positive number: {self}"
+        );
+        // `ceil(ceil(n / b) / b) = ceil(n / b^2)`, so the ceiling can be taken
+        // at every step, without overflow.
+        let (mut n, mut k) = (self, 0);
+        while n > Self::one() {
+            let rem = n % base;
+            n /= base;
+            if rem != Self::zero() {
+                n += Self::one();
+            }
+            k += 1;
+        }
+        k
+    }
+}
+
+impl<T: Integer> IntLog for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_near_powers() {
+        for base in 2u64..=20 {
+            let mut pow = 1u64;
+            let mut k = 0;
+            loop {
+                assert_eq!(pow.log_floor(base), k, "{base}^{k}");
+                assert_eq!(pow.log_ceil(base), k, "{base}^{k}");
+                if pow > 2 {
+                    assert_eq!((pow - 1).log_floor(base), k - 1, "{base}^{k} - 1");
+                    assert_eq!((pow - 1).log_ceil(base), k, "{base}^{k} - 1");
+                }
+                // `2^0 + 1` is a power of the base itself.
+                if base > 2 || k > 0 {
+                    assert_eq!((pow + 1).log_floor(base), k, "{base}^{k} + 1");
+                    assert_eq!((pow + 1).log_ceil(base), k + 1, "{base}^{k} + 1");
+                }
+                match pow.checked_mul(base) {
+                    Some(next) => pow = next,
+                    None => break,
+                }
+                k += 1;
+            }
+            // Agrees with the standard library.
+            assert_eq!(u64::MAX.log_floor(base), u64::MAX.ilog(base));
+        }
+    }
+
+    #[test]
+    fn test_types() {
+        assert_eq!(i8::MAX.log_floor(2), 6);
+        assert_eq!(i8::MAX.log_ceil(2), 7);
+        assert_eq!(u128::MAX.log_floor(2), 127);
+        assert_eq!(u128::MAX.log_ceil(2), 128);
+        assert_eq!(i128::MAX.log_ceil(10), 39);
+        assert_eq!(usize::MAX.checked_ilog(usize::MAX), Some(1));
+        assert_eq!(isize::MIN.checked_ilog(2), None);
+        for n in 1..=1_000u32 {
+            assert_eq!(n.log_floor(3), n.ilog(3));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Logarithm of non-positive number: 0")]
+    fn test_log_zero() {
+        0.log_ceil(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Base must be at least 2, got 1")]
+    fn test_invalid_base() {
+        5.log_floor(1);
+    }
+}
@@ -11,12 +11,62 @@
 //! To compute the greatest common divisor (GCD) and least common multiple
 //! (LCM), rely on [`gcd`](module@gcd) module.
 //!
-//! To compute integer roots, rely on [`root::IntRoot`] trait.
-
+//! To compute integer roots, rely on [`root::IntRoot`] trait, and for exact
+//! integer logarithms, on [`log::IntLog`] trait.
+//!
+//! For division rounding toward negative or positive infinity, and for sums of
+//! arithmetic progressions and of floors of linear functions, see the
+//! [`arith`](module@arith) module.
+//!
+//! For Fibonacci numbers modulo `m`, for indices up to `10^18`, and their
+//! Pisano periods, see [`fibonacci`](module@fibonacci).
+//!
+//! For continued fractions, best rational approximations and the Stern–Brocot
+//! tree, see [`cfrac`](module@cfrac).
+//!
+//! For digits of numbers in arbitrary bases, and conversions between bases,
+//! see [`digits::Digits`].
+//!
+//! For exponentiation by squaring over any associative operation (matrices,
+//! permutations, affine maps), see [`pow::pow_monoid`].
+//!
+//! # Combinatorics
+//!
+//! For binomial and multinomial coefficients under a modulo, see
+//! [`combinatorics::Binom`]. Stirling, Bell, Catalan and partition numbers are
+//! precomputed by [`combinatorics::tables`].
+//!
+//! # Linear algebra
+//!
+//! To solve systems of linear equations, and to compute rank and determinant
+//! of matrices, over modulo numbers or `f64`, see [`gauss`](module@gauss).
+//!
+//! To compute the rank of a floating point matrix, with configurable
+//! tolerance, see [`linear::rank_f64`].
+//!
+//! For linear algebra over GF(2), i.e. XORs of subsets of numbers, see
+//! [`xor_basis::XorBasis`].
+//!
+//! # Bitmasks
+//!
+//! For sums over submasks and supermasks, and for OR, AND and XOR
+//! convolutions, see [`sos`](module@sos).
+
+pub mod arith;
+pub mod cfrac;
+pub mod combinatorics;
+pub mod digits;
+pub mod fibonacci;
+pub mod gauss;
 pub mod gcd;
+pub mod linear;
+pub mod log;
 pub mod modulo;
+pub mod pow;
 pub mod primes;
 pub mod root;
+pub mod sos;
+pub mod xor_basis;
 
 use {
     core::fmt::Display,
@@ -137,6 +187,24 @@ macro_rules! pow_impl {
 
 pow_impl!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
 
+/// Type supporting multiplication with overflow check.
+pub trait CheckedMul: Sized {
+    /// Returns `self * rhs`, or `None` if overflow occurred.
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! checked_mul_impl {
+    ($($t: ident)+) => {$(
+        impl $crate::math::CheckedMul for $t {
+            fn checked_mul(self, rhs: $t) -> Option<$t> {
+                <$t>::checked_mul(self, rhs)
+            }
+        }
+    )+};
+}
+
+checked_mul_impl!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+
 /// Type that can be raised to a floating point power.
 pub trait Powf {
     /// Raises a number to a floating point power.
@@ -206,6 +274,39 @@ macro_rules! downcast_impl {
 }
 downcast_impl!(i8 i16, i16 i32, i32 i64, i64 i128, u8 u16, u16 u32, u32 u64, u64 u128);
 
+/// Trait for types of Bézout coefficients, see
+/// [`gcd_extended`](gcd::gcd_extended).
+///
+/// Coefficients are of the wider type (see [`Downcast`]), and, since there is
+/// no wider primitive type, of the type itself for `i128` and `u128`: the
+/// coefficients are bounded by the arguments, so they still fit.
+pub trait Bezout: Sized {
+    type Coeff: From<Self>;
+
+    fn from_coeff(c: Self::Coeff) -> Self;
+}
+
+impl<T: Downcast> Bezout for T {
+    type Coeff = T::Source;
+
+    fn from_coeff(c: Self::Coeff) -> Self {
+        T::downcast(c)
+    }
+}
+
+macro_rules! bezout_impl {
+    ($($t: ident)+) => {$(
+        impl Bezout for $t {
+            type Coeff = $t;
+
+            fn from_coeff(c: Self::Coeff) -> Self {
+                c
+            }
+        }
+    )+};
+}
+bezout_impl!(i128 u128);
+
 /// Trait for up-casting numeric types.
 pub trait Upcast: Sized {
     type Target: From<Self>;
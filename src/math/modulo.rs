@@ -65,11 +65,26 @@
 //! assert_eq!(ma!(12) + ma!(2), ma!(1));
 //! assert_eq!(ma!(12) * ma!(2), ma!(11));
 //! ```
+//!
+//! # Raw `u64` arithmetic
+//!
+//! When the modulo is not known at compile time (e.g. it is read from the
+//! input), or is too big for `i64` multiplication, use [`mul_mod_u64`] and
+//! [`pow_mod_u64`], which work on raw `u64` values, using `u128` for
+//! intermediate results.
+//!
+//! ```
+//! use algorist::math::modulo::{mul_mod_u64, pow_mod_u64};
+//!
+//! let m = (1 << 61) - 1;
+//! assert_eq!(mul_mod_u64(m - 1, m - 1, m), 1);
+//! assert_eq!(pow_mod_u64(3, m - 1, m), 1);
+//! ```
 
 use {
     crate::{
         ext::slice::sum::{MaxSum, max_sum_from_iter},
-        math::{ConstValue, Downcast, Invertible, Number, gcd::gcd_extended},
+        math::{ConstValue, Downcast, Invertible, Number, One, Zero, gcd::gcd_extended},
     },
     std::{
         cmp::PartialOrd,
@@ -176,6 +191,55 @@ impl<T: Number, M: ConstValue<T>> Modulo<T, M> {
     }
 }
 
+impl<T, M> Modulo<T, M>
+where
+    T: Number + Downcast,
+    T::Source: Number,
+    M: ConstValue<T>,
+{
+    /// Raises the modulo number to the power of `exp`, using binary
+    /// exponentiation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::math::modulo::Mod7;
+    ///
+    /// assert_eq!(Mod7::new(2).pow_u64(3).val(), 8);
+    /// assert_eq!(Mod7::new(2).pow_u64(1_000_000_006).val(), 1);
+    /// assert_eq!(Mod7::new(2).pow_u64(u64::MAX).val(), 981_530_768);
+    /// ```
+    #[must_use]
+    pub fn pow_u64(self, mut exp: u64) -> Self {
+        let mut result = Self::new(T::one());
+        let mut base = self;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result *= base;
+            }
+            base *= base;
+            exp >>= 1;
+        }
+        result
+    }
+
+    /// Raises the modulo number to the power of `exp`, given as `usize` (e.g.
+    /// a length or an index), see [`Modulo::pow_u64`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::math::modulo::Mod7;
+    ///
+    /// let v = vec![1, 2, 3];
+    /// assert_eq!(Mod7::new(2).pow_usize(v.len()).val(), 8);
+    /// ```
+    #[must_use]
+    pub fn pow_usize(self, exp: usize) -> Self {
+        self.pow_u64(exp as u64)
+    }
+}
+
 impl<T, M> Modulo<T, M>
 where
     T: Number + Downcast + BitAnd<Output = T> + ShrAssign<T>,
@@ -184,15 +248,19 @@ where
 {
     /// Raises the modulo number to the power of `exp`.
     ///
+    /// Negative exponents are treated as zero.
+    ///
     /// # Example
     ///
     /// ```
+    /// #![allow(deprecated)]
     /// use algorist::math::modulo::Mod7;
     ///
     /// assert_eq!(Mod7::new(2).pow(3).val(), 8);
     /// assert_eq!(Mod7::new(2).pow(1_000_000_006).val(), 1);
     /// ```
     #[must_use]
+    #[deprecated(note = "exponent of the value type is ambiguous, use `pow_u64` or `pow_usize`")]
     pub fn pow(self, mut exp: T) -> Self {
         let mut result = Self::new(T::one());
         let mut base = self;
@@ -213,6 +281,18 @@ impl<T: Number, M: ConstValue<T>> From<T> for Modulo<T, M> {
     }
 }
 
+impl<T: Number, M: ConstValue<T>> Zero for Modulo<T, M> {
+    fn zero() -> Self {
+        Self::new(T::zero())
+    }
+}
+
+impl<T: Number, M: ConstValue<T>> One for Modulo<T, M> {
+    fn one() -> Self {
+        Self::new(T::one())
+    }
+}
+
 impl<T: Number, M: ConstValue<T>> FromStr for Modulo<T, M> {
     type Err = <T as FromStr>::Err;
 
@@ -349,6 +429,58 @@ impl<T: Number, M: ConstValue<T>> Neg for Modulo<T, M> {
     }
 }
 
+/// Returns `a * b % m`, without overflow, using `u128` for the intermediate
+/// product.
+///
+/// # Panics
+///
+/// Panics if `m` is zero.
+///
+/// # Example
+///
+/// ```
+/// use algorist::math::modulo::mul_mod_u64;
+///
+/// assert_eq!(mul_mod_u64(3, 4, 5), 2);
+/// assert_eq!(mul_mod_u64(u64::MAX, u64::MAX, 1_000_000_007), 114_944_269);
+/// ```
+pub fn mul_mod_u64(a: u64, b: u64, m: u64) -> u64 {
+    (u128::from(a) * u128::from(b) % u128::from(m)) as u64
+}
+
+/// Returns `base ^ exp % m`, using binary exponentiation with `u128`
+/// intermediate products.
+///
+/// # Panics
+///
+/// Panics if `m` is zero.
+///
+/// # Example
+///
+/// ```
+/// use algorist::math::modulo::pow_mod_u64;
+///
+/// assert_eq!(pow_mod_u64(2, 10, 1_000), 24);
+/// assert_eq!(pow_mod_u64(5, 0, 7), 1);
+/// assert_eq!(pow_mod_u64(5, 0, 1), 0);
+///
+/// // Fermat's little theorem, with a modulo that doesn't fit into `u32`.
+/// let p = 1_000_000_000_000_000_003;
+/// assert_eq!(pow_mod_u64(123_456_789, p - 1, p), 1);
+/// ```
+pub fn pow_mod_u64(base: u64, mut exp: u64, m: u64) -> u64 {
+    let mut result = 1 % m;
+    let mut base = base % m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod_u64(result, base, m);
+        }
+        base = mul_mod_u64(base, base, m);
+        exp >>= 1;
+    }
+    result
+}
+
 #[macro_export]
 macro_rules! modulo_alias_impl {
     ($name:ident, $macro_name:ident) => {
@@ -359,6 +491,7 @@ macro_rules! modulo_alias_impl {
                 $name::new($val)
             };
         }
+        #[allow(unused_imports)]
         pub use $macro_name;
     };
 }
@@ -378,7 +511,7 @@ modulo_alias_impl!(Mod7, ma);
 
 #[cfg(test)]
 mod tests {
-    use {super::*, std::i64};
+    use super::*;
 
     #[test]
     fn modulo_creation() {
@@ -615,9 +748,50 @@ mod tests {
         ];
 
         for &(base, exp, expected) in &test_cases {
+            #[allow(deprecated)]
             let m = Mod7::new(base).pow(exp);
             assert_eq!(m.val, expected, "pow()");
+
+            let m = Mod7::new(base).pow_u64(exp as u64);
+            assert_eq!(m.val, expected, "pow_u64()");
+
+            let m = Mod7::new(base).pow_usize(exp as usize);
+            assert_eq!(m.val, expected, "pow_usize()");
         }
+
+        // Exponents not fitting into the value type.
+        assert_eq!(
+            Mod7::new(3).pow_u64(u64::MAX),
+            Mod7::new(3).pow_u64(u64::MAX % 1_000_000_006)
+        );
+        modulo!(Mod13, Val13: i8 = 13);
+        assert_eq!(Mod13::new(2).pow_usize(1000).val(), 3);
+    }
+
+    #[test]
+    fn raw_u64_arithmetic() {
+        let naive_pow = |base: u64, exp: u64, m: u64| {
+            (0..exp).fold(1 % m, |acc, _| {
+                (u128::from(acc) * u128::from(base) % u128::from(m)) as u64
+            })
+        };
+        for m in [1, 2, 7, 1_000_000_007, u64::MAX - 58, u64::MAX] {
+            for base in [0, 1, 2, 3, m - 1, m / 2 + 1, u64::MAX] {
+                for exp in 0..20 {
+                    assert_eq!(pow_mod_u64(base, exp, m), naive_pow(base, exp, m));
+                }
+            }
+        }
+
+        // 2^64 - 59 is prime.
+        let p = u64::MAX - 58;
+        assert_eq!(mul_mod_u64(p - 1, p - 1, p), 1);
+        assert_eq!(pow_mod_u64(u64::MAX, p - 1, p), 1);
+
+        assert_eq!(
+            pow_mod_u64(2, u64::MAX, 1_000_000_007),
+            Mod7::new(2).pow_u64(u64::MAX).val() as u64
+        );
     }
 
     #[test]
@@ -0,0 +1,91 @@
+//! Exponentiation by squaring over an arbitrary monoid.
+//!
+//! Any associative operation with an identity can be "raised to a power" in
+//! `O(log exp)` operations: matrix powers (linear recurrences), compositions of
+//! permutations or functional graphs (applying a transformation `k` times),
+//! affine maps, etc.
+//!
+//! ```
+//! use algorist::math::pow::pow_monoid;
+//!
+//! // Apply a permutation 10^18 times.
+//! let compose = |p: &Vec<usize>, q: &Vec<usize>| q.iter().map(|&i| p[i]).collect();
+//! let p = vec![1, 2, 0, 4, 3];
+//! let id = vec![0, 1, 2, 3, 4];
+//! assert_eq!(pow_monoid(p, 1_000_000_000_000_000_000, id, compose), vec![
+//!     1, 2, 0, 3, 4
+//! ]);
+//! ```
+
+/// Returns `base` combined with itself `exp` times by the associative `op`
+/// (`identity` for `exp = 0`), in `O(log exp)` calls of `op`.
+///
+/// # Example
+///
+/// ```
+/// use algorist::math::pow::pow_monoid;
+///
+/// // Affine map `x -> a * x + b`, modulo 1000: `(a, b)`, applied 5 times.
+/// let compose = |f: &(u64, u64), g: &(u64, u64)| {
+///     // f(g(x)) = f.0 * (g.0 * x + g.1) + f.1
+///     (f.0 * g.0 % 1000, (f.0 * g.1 + f.1) % 1000)
+/// };
+/// let (a, b) = pow_monoid((2, 1), 5, (1, 0), compose);
+/// assert_eq!(a * 0 + b, 31); // 0 -> 1 -> 3 -> 7 -> 15 -> 31
+///
+/// // Repeating a string.
+/// let s = pow_monoid("ab".to_string(), 3, String::new(), |a, b| a.clone() + b);
+/// assert_eq!(s, "ababab");
+/// ```
+pub fn pow_monoid<T, F>(base: T, mut exp: u64, identity: T, mut op: F) -> T
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> T,
+{
+    let mut res = identity;
+    let mut base = base;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            res = op(&res, &base);
+        }
+        exp >>= 1;
+        if exp > 0 {
+            base = op(&base, &base);
+        }
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pow_monoid() {
+        for exp in 0..20 {
+            let mul = pow_monoid(3u64, exp, 1, |a, b| a * b % 1_000_007);
+            let want = (0..exp).fold(1, |acc, _| acc * 3 % 1_000_007);
+            assert_eq!(mul, want);
+            assert_eq!(pow_monoid(5i64, exp, 0, |a, b| a + b), 5 * exp as i64);
+        }
+    }
+
+    #[test]
+    fn test_non_commutative() {
+        // Fibonacci numbers, with 2x2 matrices.
+        type M = [[u64; 2]; 2];
+        let mul = |a: &M, b: &M| {
+            let mut c = [[0; 2]; 2];
+            for i in 0..2 {
+                for j in 0..2 {
+                    for k in 0..2 {
+                        c[i][j] += a[i][k] * b[k][j];
+                    }
+                }
+            }
+            c
+        };
+        let f = pow_monoid([[1, 1], [1, 0]], 50, [[1, 0], [0, 1]], mul);
+        assert_eq!(f[0][1], 12_586_269_025);
+    }
+}
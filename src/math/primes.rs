@@ -706,8 +706,8 @@ mod tests {
         let primes = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29];
         let n = 30;
         let sieve = sieve(n);
-        for i in 0..n {
-            assert_eq!(sieve[i], primes.contains(&i));
+        for (i, &is_prime) in sieve.iter().enumerate().take(n) {
+            assert_eq!(is_prime, primes.contains(&i));
         }
     }
 
@@ -245,14 +245,14 @@ mod tests {
         assert_eq!(x.root_floor(4), 10);
         assert_eq!(x.root_floor(13), 2);
         assert_eq!(x.root_floor(14), 1);
-        assert_eq!(x.root_floor(std::usize::MAX), 1);
+        assert_eq!(x.root_floor(usize::MAX), 1);
 
-        assert_eq!(std::i32::MAX.root_floor(30), 2);
-        assert_eq!(std::i32::MAX.root_floor(31), 1);
-        assert_eq!(std::i32::MIN.root_floor(31), -2);
-        assert_eq!((std::i32::MIN + 1).root_floor(31), -1);
+        assert_eq!(i32::MAX.root_floor(30), 2);
+        assert_eq!(i32::MAX.root_floor(31), 1);
+        assert_eq!(i32::MIN.root_floor(31), -2);
+        assert_eq!((i32::MIN + 1).root_floor(31), -1);
 
-        assert_eq!(std::u32::MAX.root_floor(31), 2);
-        assert_eq!(std::u32::MAX.root_floor(32), 1);
+        assert_eq!(u32::MAX.root_floor(31), 2);
+        assert_eq!(u32::MAX.root_floor(32), 1);
     }
 }
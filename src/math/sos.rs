@@ -0,0 +1,304 @@
+//! Sum over subsets (SOS) and bitwise convolutions.
+//!
+//! Values are indexed by bitmasks, so the length of the input must be a power
+//! of two. All the transforms work in place, in `O(n log n)` for `n` values
+//! (i.e. `O(2^k * k)` for masks of `k` bits).
+//!
+//! # Zeta and Möbius transforms
+//!
+//! [`subset_zeta`] replaces each `a[mask]` with the sum of `a[sub]` over all
+//! submasks `sub` of `mask`, and [`subset_mobius`] reverts it. Similarly,
+//! [`superset_zeta`] and [`superset_mobius`] sum over supermasks.
+//!
+//! ```
+//! use algorist::math::sos::{subset_mobius, subset_zeta, superset_zeta};
+//!
+//! let mut a = vec![1, 2, 3, 4];
+//! subset_zeta(&mut a);
+//! // a[0b11] = a[0b00] + a[0b01] + a[0b10] + a[0b11]
+//! assert_eq!(a, vec![1, 3, 4, 10]);
+//! subset_mobius(&mut a);
+//! assert_eq!(a, vec![1, 2, 3, 4]);
+//!
+//! let mut a = vec![1, 2, 3, 4];
+//! superset_zeta(&mut a);
+//! assert_eq!(a, vec![10, 6, 7, 4]);
+//! ```
+//!
+//! # Bitwise convolutions
+//!
+//! Convolutions `c[i | j] += a[i] * b[j]` (and similarly for `&` and `^`) are
+//! computed by [`or_convolution`], [`and_convolution`] and
+//! [`xor_convolution`], the latter relying on the Walsh–Hadamard transform
+//! ([`walsh_hadamard`]).
+//!
+//! ```
+//! use algorist::math::sos::{and_convolution, or_convolution, xor_convolution};
+//!
+//! let (a, b) = (vec![1, 2, 3, 4], vec![5, 6, 7, 8]);
+//! assert_eq!(or_convolution(&a, &b), vec![5, 28, 43, 184]);
+//! assert_eq!(and_convolution(&a, &b), vec![103, 52, 73, 32]);
+//! assert_eq!(xor_convolution(&a, &b), vec![70, 68, 62, 60]);
+//! ```
+//!
+//! Values may be any ring-like type: integers, or [`Modulo`] numbers when the
+//! sums overflow.
+//!
+//! [`Modulo`]: crate::math::modulo::Modulo
+
+use {
+    crate::math::One,
+    std::ops::{Add, Div, Mul, Sub},
+};
+
+/// Applies `f(lo, hi)` to all pairs of values, whose indices differ only in a
+/// single bit (`lo` has the bit unset), bit by bit.
+fn butterfly<T: Copy, F: Fn(&mut T, &mut T)>(a: &mut [T], f: F) {
+    assert!(
+        a.len().is_power_of_two(),
+        "Length must be a power of two, got {}",
+        a.len()
+    );
+    let mut half = 1;
+    while half < a.len() {
+        for block in a.chunks_exact_mut(2 * half) {
+            let (lo, hi) = block.split_at_mut(half);
+            for (x, y) in lo.iter_mut().zip(hi) {
+                f(x, y);
+            }
+        }
+        half *= 2;
+    }
+}
+
+/// Replaces `a[mask]` with the sum of `a[sub]` over all submasks of `mask`.
+///
+/// # Panics
+///
+/// Panics if the length of `a` is not a power of two.
+pub fn subset_zeta<T: Copy + Add<Output = T>>(a: &mut [T]) {
+    butterfly(a, |lo, hi| *hi = *hi + *lo);
+}
+
+/// Inverse of [`subset_zeta`]: recovers values from their sums over
+/// submasks.
+///
+/// # Panics
+///
+/// Panics if the length of `a` is not a power of two.
+pub fn subset_mobius<T: Copy + Sub<Output = T>>(a: &mut [T]) {
+    butterfly(a, |lo, hi| *hi = *hi - *lo);
+}
+
+/// Replaces `a[mask]` with the sum of `a[sup]` over all supermasks of `mask`.
+///
+/// # Panics
+///
+/// Panics if the length of `a` is not a power of two.
+pub fn superset_zeta<T: Copy + Add<Output = T>>(a: &mut [T]) {
+    butterfly(a, |lo, hi| *lo = *lo + *hi);
+}
+
+/// Inverse of [`superset_zeta`]: recovers values from their sums over
+/// supermasks.
+///
+/// # Panics
+///
+/// Panics if the length of `a` is not a power of two.
+pub fn superset_mobius<T: Copy + Sub<Output = T>>(a: &mut [T]) {
+    butterfly(a, |lo, hi| *lo = *lo - *hi);
+}
+
+/// Applies the (unnormalized) Walsh–Hadamard transform. Applying it twice
+/// multiplies all the values by `a.len()`, see [`inverse_walsh_hadamard`].
+///
+/// # Panics
+///
+/// Panics if the length of `a` is not a power of two.
+pub fn walsh_hadamard<T: Copy + Add<Output = T> + Sub<Output = T>>(a: &mut [T]) {
+    butterfly(a, |lo, hi| (*lo, *hi) = (*lo + *hi, *lo - *hi));
+}
+
+/// Inverse of [`walsh_hadamard`].
+///
+/// Values are divided by `a.len()`, so for integers the division must be
+/// exact (which is the case for the transformed values), and for modulo
+/// numbers the modulo must be odd.
+///
+/// # Panics
+///
+/// Panics if the length of `a` is not a power of two.
+///
+/// # Example
+///
+/// ```
+/// use algorist::math::sos::{inverse_walsh_hadamard, walsh_hadamard};
+///
+/// let mut a = vec![3, -1, 4, 1, 5, -9, 2, 6];
+/// walsh_hadamard(&mut a);
+/// assert_eq!(a[0], 11);
+/// inverse_walsh_hadamard(&mut a);
+/// assert_eq!(a, vec![3, -1, 4, 1, 5, -9, 2, 6]);
+/// ```
+pub fn inverse_walsh_hadamard<T>(a: &mut [T])
+where
+    T: Copy + One + Add<Output = T> + Sub<Output = T> + Div<Output = T>,
+{
+    walsh_hadamard(a);
+    let mut n = T::one();
+    for _ in 0..a.len().trailing_zeros() {
+        n = n + n;
+    }
+    for x in a {
+        *x = *x / n;
+    }
+}
+
+/// Multiplies transformed values pointwise.
+fn pointwise<T: Copy + Mul<Output = T>>(a: &[T], b: &[T]) -> Vec<T> {
+    assert_eq!(a.len(), b.len(), "Lengths must be equal");
+    a.iter().zip(b).map(|(&x, &y)| x * y).collect()
+}
+
+/// Returns `c`, where `c[k]` is the sum of `a[i] * b[j]` over all `i | j = k`.
+///
+/// # Panics
+///
+/// Panics if lengths of `a` and `b` differ, or are not a power of two.
+pub fn or_convolution<T>(a: &[T], b: &[T]) -> Vec<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    let (mut a, mut b) = (a.to_vec(), b.to_vec());
+    subset_zeta(&mut a);
+    subset_zeta(&mut b);
+    let mut c = pointwise(&a, &b);
+    subset_mobius(&mut c);
+    c
+}
+
+/// Returns `c`, where `c[k]` is the sum of `a[i] * b[j]` over all `i & j = k`.
+///
+/// # Panics
+///
+/// Panics if lengths of `a` and `b` differ, or are not a power of two.
+pub fn and_convolution<T>(a: &[T], b: &[T]) -> Vec<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    let (mut a, mut b) = (a.to_vec(), b.to_vec());
+    superset_zeta(&mut a);
+    superset_zeta(&mut b);
+    let mut c = pointwise(&a, &b);
+    superset_mobius(&mut c);
+    c
+}
+
+/// Returns `c`, where `c[k]` is the sum of `a[i] * b[j]` over all `i ^ j = k`.
+///
+/// # Panics
+///
+/// Panics if lengths of `a` and `b` differ, or are not a power of two.
+///
+/// # Example
+///
+/// ```
+/// use algorist::math::{modulo::Mod7, sos::xor_convolution};
+///
+/// // Number of ways to pick two masks (with repetitions) with a given XOR.
+/// let a = vec![Mod7::new(1); 4];
+/// let c = xor_convolution(&a, &a);
+/// assert_eq!(c, vec![Mod7::new(4); 4]);
+/// ```
+pub fn xor_convolution<T>(a: &[T], b: &[T]) -> Vec<T>
+where
+    T: Copy + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    let (mut a, mut b) = (a.to_vec(), b.to_vec());
+    walsh_hadamard(&mut a);
+    walsh_hadamard(&mut b);
+    let mut c = pointwise(&a, &b);
+    inverse_walsh_hadamard(&mut c);
+    c
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::math::modulo::Mod7};
+
+    fn random(n: usize, seed: &mut u64) -> Vec<i64> {
+        (0..n)
+            .map(|_| {
+                *seed = seed
+                    .wrapping_mul(6_364_136_223_846_793_005)
+                    .wrapping_add(1_442_695_040_888_963_407);
+                (*seed >> 40) as i64 % 100 - 50
+            })
+            .collect()
+    }
+
+    fn naive(a: &[i64], b: &[i64], op: fn(usize, usize) -> usize) -> Vec<i64> {
+        let mut c = vec![0; a.len()];
+        for (i, &x) in a.iter().enumerate() {
+            for (j, &y) in b.iter().enumerate() {
+                c[op(i, j)] += x * y;
+            }
+        }
+        c
+    }
+
+    #[test]
+    fn test_transforms() {
+        let mut seed = 42;
+        for k in 0..7 {
+            let n = 1 << k;
+            let a = random(n, &mut seed);
+
+            let mut sub = a.clone();
+            subset_zeta(&mut sub);
+            let mut sup = a.clone();
+            superset_zeta(&mut sup);
+            for mask in 0..n {
+                let want: i64 = (0..n).filter(|&s| s & mask == s).map(|s| a[s]).sum();
+                assert_eq!(sub[mask], want);
+                let want: i64 = (0..n).filter(|&s| s & mask == mask).map(|s| a[s]).sum();
+                assert_eq!(sup[mask], want);
+            }
+            subset_mobius(&mut sub);
+            superset_mobius(&mut sup);
+            assert_eq!(sub, a);
+            assert_eq!(sup, a);
+
+            let mut w = a.clone();
+            walsh_hadamard(&mut w);
+            inverse_walsh_hadamard(&mut w);
+            assert_eq!(w, a);
+        }
+    }
+
+    #[test]
+    fn test_convolutions() {
+        let mut seed = 7;
+        for k in 0..7 {
+            let n = 1 << k;
+            let (a, b) = (random(n, &mut seed), random(n, &mut seed));
+            assert_eq!(or_convolution(&a, &b), naive(&a, &b, |i, j| i | j));
+            assert_eq!(and_convolution(&a, &b), naive(&a, &b, |i, j| i & j));
+            assert_eq!(xor_convolution(&a, &b), naive(&a, &b, |i, j| i ^ j));
+
+            let ma: Vec<Mod7> = a.iter().map(|&x| Mod7::new(x)).collect();
+            let mb: Vec<Mod7> = b.iter().map(|&x| Mod7::new(x)).collect();
+            let want: Vec<Mod7> = naive(&a, &b, |i, j| i ^ j)
+                .into_iter()
+                .map(Mod7::new)
+                .collect();
+            assert_eq!(xor_convolution(&ma, &mb), want);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Length must be a power of two, got 3")]
+    fn test_invalid_length() {
+        subset_zeta(&mut [1, 2, 3]);
+    }
+}
@@ -0,0 +1,270 @@
+//! Linear basis of integers over GF(2).
+//!
+//! Considering integers as vectors of bits, XOR is vector addition over GF(2).
+//! Then, the set of all XORs of subsets of some numbers is a linear space,
+//! and [`XorBasis`] keeps its basis: at most 64 numbers, spanning the same
+//! space. This allows to answer questions like "what is the maximum XOR of a
+//! subset" or "can `x` be obtained as XOR of some subset" efficiently.
+//!
+//! # Example
+//!
+//! ```
+//! use algorist::math::xor_basis::XorBasis;
+//!
+//! let mut basis = XorBasis::new();
+//! for x in [0b0110, 0b1100, 0b1010, 0b0001] {
+//!     basis.insert(x);
+//! }
+//! // 0b1010 = 0b0110 ^ 0b1100, so it is dependent.
+//! assert_eq!(basis.rank(), 3);
+//!
+//! assert_eq!(basis.max_xor(), 0b1101);
+//! assert!(basis.can_represent(0b1011));
+//! assert!(!basis.can_represent(0b0010));
+//!
+//! // All representable values, in increasing order.
+//! let all: Vec<u64> = (0..8).map(|k| basis.kth_smallest_xor(k).unwrap()).collect();
+//! assert_eq!(all, vec![0, 1, 6, 7, 10, 11, 12, 13]);
+//! ```
+
+/// Linear basis of `u64` numbers over GF(2).
+#[derive(Debug, Clone)]
+pub struct XorBasis {
+    /// `basis[i]` is either zero, or a number with the highest set bit `i`.
+    basis: [u64; 64],
+    rank: usize,
+}
+
+impl Default for XorBasis {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl XorBasis {
+    /// Creates an empty basis (spanning `{0}` only).
+    pub fn new() -> Self {
+        Self {
+            basis: [0; 64],
+            rank: 0,
+        }
+    }
+
+    /// Returns the number of vectors in the basis, i.e. the dimension of the
+    /// spanned space. There are `2^rank` representable values.
+    pub fn rank(&self) -> usize {
+        self.rank
+    }
+
+    /// Returns vectors of the basis, in increasing order.
+    pub fn vectors(&self) -> impl Iterator<Item = u64> + '_ {
+        self.basis.iter().copied().filter(|&b| b != 0)
+    }
+
+    /// Reduces `x` by the basis, i.e. clears all the bits of `x` that are the
+    /// highest bits of basis vectors. The result is zero iff `x` is
+    /// representable.
+    fn reduce(&self, mut x: u64) -> u64 {
+        for i in (0..64).rev() {
+            if x >> i & 1 == 1 {
+                x ^= self.basis[i];
+            }
+        }
+        x
+    }
+
+    /// Inserts `x` into the basis, in `O(log x)`.
+    ///
+    /// Returns `true` if `x` was linearly independent from the basis (and,
+    /// thus, the rank increased), and `false` otherwise.
+    pub fn insert(&mut self, mut x: u64) -> bool {
+        for i in (0..64).rev() {
+            if x >> i & 1 == 0 {
+                continue;
+            }
+            if self.basis[i] == 0 {
+                self.basis[i] = x;
+                self.rank += 1;
+                return true;
+            }
+            x ^= self.basis[i];
+        }
+        false
+    }
+
+    /// Checks whether `x` is XOR of some subset of inserted numbers.
+    pub fn can_represent(&self, x: u64) -> bool {
+        self.reduce(x) == 0
+    }
+
+    /// Returns the maximum XOR of a subset of inserted numbers.
+    pub fn max_xor(&self) -> u64 {
+        self.max_xor_with(0)
+    }
+
+    /// Returns the maximum of `x ^ s`, over all XORs `s` of subsets of
+    /// inserted numbers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::math::xor_basis::XorBasis;
+    ///
+    /// let mut basis = XorBasis::new();
+    /// basis.insert(0b100);
+    /// basis.insert(0b010);
+    /// assert_eq!(basis.max_xor_with(0b101), 0b111);
+    /// assert_eq!(basis.max_xor_with(0b001), 0b111);
+    /// ```
+    pub fn max_xor_with(&self, mut x: u64) -> u64 {
+        for i in (0..64).rev() {
+            x = x.max(x ^ self.basis[i]);
+        }
+        x
+    }
+
+    /// Returns the `k`-th (0-based) smallest distinct value representable as
+    /// XOR of a subset of inserted numbers, or `None` if `k >= 2^rank`.
+    ///
+    /// The empty subset is included, so the `0`-th smallest value is zero.
+    /// Runs in `O(64^2)`; for many queries, build the basis once and reuse it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::math::xor_basis::XorBasis;
+    ///
+    /// let mut basis = XorBasis::new();
+    /// basis.insert(3);
+    /// basis.insert(5);
+    /// // Representable: 0, 3, 5, 6.
+    /// assert_eq!(basis.kth_smallest_xor(0), Some(0));
+    /// assert_eq!(basis.kth_smallest_xor(2), Some(5));
+    /// assert_eq!(basis.kth_smallest_xor(3), Some(6));
+    /// assert_eq!(basis.kth_smallest_xor(4), None);
+    /// ```
+    pub fn kth_smallest_xor(&self, k: u64) -> Option<u64> {
+        if self.rank < 64 && k >> self.rank != 0 {
+            return None;
+        }
+        // In the reduced row echelon form, highest bit of each vector is not
+        // set in any other vector, so the bits of `k` choose vectors in order.
+        let mut reduced = self.basis;
+        for i in 0..64 {
+            if reduced[i] == 0 {
+                continue;
+            }
+            for j in i + 1..64 {
+                if reduced[j] >> i & 1 == 1 {
+                    reduced[j] ^= reduced[i];
+                }
+            }
+        }
+        Some(
+            reduced
+                .iter()
+                .filter(|&&b| b != 0)
+                .enumerate()
+                .filter(|&(bit, _)| k >> bit & 1 == 1)
+                .fold(0, |acc, (_, &b)| acc ^ b),
+        )
+    }
+
+    /// Merges another basis into this one, so that it spans the sum of both
+    /// spaces.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::math::xor_basis::XorBasis;
+    ///
+    /// let (mut a, mut b) = (XorBasis::new(), XorBasis::new());
+    /// a.insert(0b01);
+    /// b.insert(0b10);
+    /// b.insert(0b11);
+    /// a.merge(&b);
+    /// assert_eq!(a.rank(), 2);
+    /// assert_eq!(a.max_xor(), 0b11);
+    /// ```
+    pub fn merge(&mut self, other: &Self) {
+        for x in other.vectors() {
+            self.insert(x);
+        }
+    }
+}
+
+impl FromIterator<u64> for XorBasis {
+    fn from_iter<I: IntoIterator<Item = u64>>(iter: I) -> Self {
+        let mut basis = Self::new();
+        for x in iter {
+            basis.insert(x);
+        }
+        basis
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, std::collections::BTreeSet};
+
+    /// All XORs of subsets, in increasing order.
+    fn span(nums: &[u64]) -> Vec<u64> {
+        let mut set = BTreeSet::from([0]);
+        for &x in nums {
+            let next: Vec<u64> = set.iter().map(|&s| s ^ x).collect();
+            set.extend(next);
+        }
+        set.into_iter().collect()
+    }
+
+    #[test]
+    fn test_xor_basis() {
+        let mut x = 12345u64;
+        for len in 0..12 {
+            let nums: Vec<u64> = (0..len)
+                .map(|_| {
+                    x = x
+                        .wrapping_mul(6_364_136_223_846_793_005)
+                        .wrapping_add(1_442_695_040_888_963_407);
+                    (x >> 40) & 0x1ff
+                })
+                .collect();
+            let basis: XorBasis = nums.iter().copied().collect();
+            let all = span(&nums);
+
+            assert_eq!(1 << basis.rank(), all.len());
+            assert_eq!(basis.max_xor(), *all.last().unwrap());
+            for v in 0..512 {
+                assert_eq!(basis.can_represent(v), all.binary_search(&v).is_ok());
+                let best = all.iter().map(|&s| s ^ v).max().unwrap();
+                assert_eq!(basis.max_xor_with(v), best);
+            }
+            for (k, &want) in all.iter().enumerate() {
+                assert_eq!(basis.kth_smallest_xor(k as u64), Some(want));
+            }
+            assert_eq!(basis.kth_smallest_xor(all.len() as u64), None);
+
+            // Merging halves gives the same span.
+            let (l, r) = nums.split_at(len / 2);
+            let mut a: XorBasis = l.iter().copied().collect();
+            a.merge(&r.iter().copied().collect());
+            assert_eq!(a.rank(), basis.rank());
+            assert!(basis.vectors().all(|v| a.can_represent(v)));
+        }
+    }
+
+    #[test]
+    fn test_full_rank() {
+        let basis: XorBasis = (0..64).map(|i| 1u64 << i).collect();
+        assert_eq!(basis.rank(), 64);
+        assert_eq!(basis.max_xor(), u64::MAX);
+        assert_eq!(basis.kth_smallest_xor(u64::MAX), Some(u64::MAX));
+        assert_eq!(basis.kth_smallest_xor(12345), Some(12345));
+
+        let mut basis = XorBasis::new();
+        assert!(basis.insert(u64::MAX));
+        assert!(!basis.insert(u64::MAX));
+        assert!(!basis.insert(0));
+        assert_eq!(basis.kth_smallest_xor(1), Some(u64::MAX));
+    }
+}
@@ -1,5 +1,5 @@
 use {
-    crate::io::Scanner,
+    crate::{io::Scanner, misc::rng::Rng},
     std::{fmt, io::BufRead},
 };
 
@@ -20,6 +20,28 @@ pub enum CardRank {
     Ace,
 }
 
+impl CardRank {
+    /// Returns all the ranks, from `Two` to `Ace`.
+    pub fn all() -> impl Iterator<Item = Self> {
+        [
+            Self::Two,
+            Self::Three,
+            Self::Four,
+            Self::Five,
+            Self::Six,
+            Self::Seven,
+            Self::Eight,
+            Self::Nine,
+            Self::Ten,
+            Self::Jack,
+            Self::Queen,
+            Self::King,
+            Self::Ace,
+        ]
+        .into_iter()
+    }
+}
+
 impl From<char> for CardRank {
     fn from(c: char) -> Self {
         match c {
@@ -150,6 +172,61 @@ impl Card {
     pub fn is_same_rank(&self, other: &Self) -> bool {
         self.0 == other.0
     }
+
+    /// Checks if the card beats `other` in a trick, which was led with a card
+    /// of `lead_suit`.
+    ///
+    /// Trumps beat all other cards, then cards of the lead suit beat cards of
+    /// other suits. Cards of the same suit are compared by rank, and a card of
+    /// neither trump nor lead suit never beats anything.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::misc::cards::{Card, CardSuit};
+    ///
+    /// let card = |s: &str| Card::from(s.to_string());
+    /// let (trump, lead) = (Some(CardSuit::Spades), CardSuit::Hearts);
+    /// assert!(card("2S").beats(&card("AH"), trump, lead));
+    /// assert!(card("3H").beats(&card("AD"), trump, lead));
+    /// assert!(card("KH").beats(&card("QH"), trump, lead));
+    /// assert!(!card("AD").beats(&card("2C"), trump, lead));
+    /// assert!(!card("AD").beats(&card("2C"), None, CardSuit::Clubs));
+    /// ```
+    pub fn beats(&self, other: &Self, trump: Option<CardSuit>, lead_suit: CardSuit) -> bool {
+        self.beats_with(other, trump, lead_suit, &RankOrder::default())
+    }
+
+    /// Same as [`beats`](Self::beats), but compares ranks by the given
+    /// `order`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::misc::cards::{Card, CardSuit, RankOrder};
+    ///
+    /// let card = |s: &str| Card::from(s.to_string());
+    /// let order = RankOrder::ace_low();
+    /// assert!(card("2H").beats_with(&card("AH"), None, CardSuit::Hearts, &order));
+    /// ```
+    pub fn beats_with(
+        &self,
+        other: &Self,
+        trump: Option<CardSuit>,
+        lead_suit: CardSuit,
+        order: &RankOrder,
+    ) -> bool {
+        let power = |card: &Self| {
+            if trump == Some(card.1) {
+                (2, order.key(card.0))
+            } else if card.1 == lead_suit {
+                (1, order.key(card.0))
+            } else {
+                (0, 0)
+            }
+        };
+        power(self) > power(other)
+    }
 }
 
 impl fmt::Display for Card {
@@ -186,11 +263,115 @@ impl std::fmt::Debug for Card {
     }
 }
 
+/// Order of card ranks, for games and problems, in which the ranks don't go
+/// from `Two` to `Ace`.
+///
+/// An order may list only some of the ranks, e.g. `6..A` in Durak, in which
+/// case decks, created with it, only have cards of the listed ranks.
+///
+/// # Example
+///
+/// ```
+/// use algorist::misc::cards::{CardRank, RankOrder};
+///
+/// let order = RankOrder::ace_low();
+/// assert!(order.key(CardRank::Ace) < order.key(CardRank::Two));
+///
+/// // Jacks are the highest, as in some trump games.
+/// let order = RankOrder::custom(&[
+///     CardRank::Seven,
+///     CardRank::Eight,
+///     CardRank::Nine,
+///     CardRank::Queen,
+///     CardRank::King,
+///     CardRank::Ten,
+///     CardRank::Ace,
+///     CardRank::Jack,
+/// ]);
+/// assert!(order.key(CardRank::Jack) > order.key(CardRank::Ace));
+/// assert!(order.key(CardRank::Ten) > order.key(CardRank::King));
+/// assert_eq!(order.ranks().count(), 8);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RankOrder {
+    /// `pos[rank]` is the position of the rank in the order, from the lowest
+    /// one, or `u8::MAX` if the rank is not in the order.
+    pos: [u8; 13],
+}
+
+impl Default for RankOrder {
+    /// The standard order, from `Two` to `Ace`.
+    fn default() -> Self {
+        Self::custom(&CardRank::all().collect::<Vec<_>>())
+    }
+}
+
+impl RankOrder {
+    /// The standard order, from `Two` to `Ace`.
+    pub fn ace_high() -> Self {
+        Self::default()
+    }
+
+    /// Order, in which the ace is the lowest rank, followed by `Two` to `King`.
+    pub fn ace_low() -> Self {
+        let mut ranks: Vec<_> = CardRank::all().collect();
+        ranks.rotate_right(1);
+        Self::custom(&ranks)
+    }
+
+    /// Order of the given ranks, from the lowest to the highest.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a rank is listed more than once.
+    pub fn custom(ranks: &[CardRank]) -> Self {
+        let mut pos = [u8::MAX; 13];
+        for (i, &rank) in ranks.iter().enumerate() {
+            assert!(
+                pos[rank as usize] == u8::MAX,
+                "Rank {rank:?} is listed more than once"
+            );
+            pos[rank as usize] = i as u8;
+        }
+        Self { pos }
+    }
+
+    /// Returns the position of the rank in the order, from `0` for the lowest
+    /// rank.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rank is not in the order.
+    pub fn key(&self, rank: CardRank) -> usize {
+        let pos = self.pos[rank as usize];
+        assert!(pos != u8::MAX, "Rank {rank:?} is not in the order");
+        pos as usize
+    }
+
+    /// Checks if the rank is in the order.
+    pub fn contains(&self, rank: CardRank) -> bool {
+        self.pos[rank as usize] != u8::MAX
+    }
+
+    /// Returns the ranks of the order, from the lowest to the highest.
+    pub fn ranks(&self) -> impl Iterator<Item = CardRank> {
+        let mut ranks: Vec<_> = CardRank::all().filter(|&r| self.contains(r)).collect();
+        ranks.sort_by_key(|&r| self.key(r));
+        ranks.into_iter()
+    }
+
+    /// Compares cards by rank, in this order.
+    pub fn cmp(&self, a: &Card, b: &Card) -> std::cmp::Ordering {
+        self.key(a.rank()).cmp(&self.key(b.rank()))
+    }
+}
+
 #[derive(Debug)]
 pub struct CardDeck {
     cards: Vec<Card>,
     trump: Option<CardSuit>,
     by_suit: Vec<Vec<Card>>,
+    order: RankOrder,
 }
 
 impl Default for CardDeck {
@@ -201,37 +382,32 @@ impl Default for CardDeck {
 
 impl CardDeck {
     pub fn new() -> Self {
-        let mut cards = vec![];
-        let mut by_suit = vec![vec![]; 4];
-        for &suit in &[
-            CardSuit::Clubs,
-            CardSuit::Diamonds,
-            CardSuit::Hearts,
-            CardSuit::Spades,
-        ] {
-            for &rank in &[
-                CardRank::Two,
-                CardRank::Three,
-                CardRank::Four,
-                CardRank::Five,
-                CardRank::Six,
-                CardRank::Seven,
-                CardRank::Eight,
-                CardRank::Nine,
-                CardRank::Ten,
-                CardRank::Jack,
-                CardRank::Queen,
-                CardRank::King,
-                CardRank::Ace,
-            ] {
-                cards.push(Card::new(rank, suit));
-                by_suit[suit as usize].push(Card::new(rank, suit));
-            }
-        }
+        Self::with_order(RankOrder::default())
+    }
+
+    /// Creates a deck of all the cards with ranks from `order`, sorted by suit
+    /// and then by rank. The deck compares and sorts cards in this order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::misc::cards::{CardDeck, CardRank, RankOrder};
+    ///
+    /// // Durak is played with a deck of 36 cards, from sixes to aces.
+    /// let ranks: Vec<_> = CardRank::all().skip(4).collect();
+    /// let deck = CardDeck::with_order(RankOrder::custom(&ranks));
+    /// assert_eq!(deck.cards().len(), 36);
+    ///
+    /// let deck = CardDeck::with_order(RankOrder::ace_low());
+    /// assert_eq!(deck.cards()[0].to_string(), "AC");
+    /// ```
+    pub fn with_order(order: RankOrder) -> Self {
+        let cards = CardSuit::all()
+            .flat_map(|suit| order.ranks().map(move |rank| Card::new(rank, suit)))
+            .collect();
         Self {
-            cards,
-            trump: None,
-            by_suit,
+            order,
+            ..Self::from_vec(cards, None)
         }
     }
 
@@ -247,6 +423,7 @@ impl CardDeck {
             cards,
             trump,
             by_suit,
+            order: RankOrder::default(),
         }
     }
 
@@ -259,25 +436,97 @@ impl CardDeck {
             cards,
             trump,
             by_suit,
+            order: RankOrder::default(),
         }
     }
 
+    /// Returns the deck with the cards sorted by rank, in the deck's order.
     #[must_use]
     pub fn sorted(self) -> Self {
+        let order = self.order;
         let mut cards = self.cards;
-        cards.sort();
+        cards.sort_by(|a, b| order.cmp(a, b));
         let mut by_suit = self.by_suit;
         for suit in &mut by_suit {
-            suit.sort();
+            suit.sort_by(|a, b| order.cmp(a, b));
         }
 
         Self {
             cards,
             trump: self.trump,
             by_suit,
+            order,
         }
     }
 
+    /// Returns the deck with the cards in random order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::misc::{cards::CardDeck, rng::Rng};
+    ///
+    /// let deck = CardDeck::new().shuffled(&mut Rng::new(42));
+    /// assert_eq!(deck.cards().len(), 52);
+    /// assert_ne!(deck.cards(), CardDeck::new().cards());
+    /// ```
+    #[must_use]
+    pub fn shuffled(self, rng: &mut Rng) -> Self {
+        let mut cards = self.cards;
+        rng.shuffle(&mut cards);
+        Self {
+            order: self.order,
+            ..Self::from_vec(cards, self.trump)
+        }
+    }
+
+    /// Deals `per_hand` cards to each of `n_hands` hands, one card at a time
+    /// to each hand in turn, from the top (the front) of the deck. The dealt
+    /// cards are removed from the deck, and the hands get the deck's trump.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are not enough cards in the deck.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::misc::cards::{Card, CardDeck};
+    ///
+    /// let mut deck = CardDeck::new();
+    /// let hands = deck.deal(4, 6);
+    /// assert_eq!(hands.len(), 4);
+    /// assert!(hands.iter().all(|hand| hand.cards().len() == 6));
+    /// assert_eq!(hands[1].cards()[..2], [
+    ///     Card::from("3C".to_string()),
+    ///     Card::from("7C".to_string())
+    /// ]);
+    /// assert_eq!(deck.cards().len(), 52 - 24);
+    /// ```
+    pub fn deal(&mut self, n_hands: usize, per_hand: usize) -> Vec<Self> {
+        let total = n_hands * per_hand;
+        assert!(
+            total <= self.cards.len(),
+            "Not enough cards: {total} needed, {} left",
+            self.cards.len()
+        );
+        let mut hands = vec![vec![]; n_hands];
+        for (i, card) in self.cards.drain(..total).enumerate() {
+            hands[i % n_hands].push(card);
+        }
+        *self = Self {
+            order: self.order,
+            ..Self::from_vec(std::mem::take(&mut self.cards), self.trump)
+        };
+        hands
+            .into_iter()
+            .map(|cards| Self {
+                order: self.order,
+                ..Self::from_vec(cards, self.trump)
+            })
+            .collect()
+    }
+
     pub fn set_trump(&mut self, trump: CardSuit) {
         self.trump = Some(trump);
     }
@@ -297,6 +546,114 @@ impl CardDeck {
     pub fn is_trump(&self, suit: CardSuit) -> bool {
         self.trump == Some(suit)
     }
+
+    /// Sets the order of ranks, used to sort and compare cards of the deck.
+    pub fn set_order(&mut self, order: RankOrder) {
+        self.order = order;
+    }
+
+    pub fn order(&self) -> RankOrder {
+        self.order
+    }
+
+    /// Checks if card `a` beats card `b` in a trick led with `lead_suit`, using
+    /// the deck's trump and order of ranks (see [`Card::beats`]).
+    pub fn beats(&self, a: &Card, b: &Card, lead_suit: CardSuit) -> bool {
+        a.beats_with(b, self.trump, lead_suit, &self.order)
+    }
+}
+
+/// Category of a 5-card poker hand, from the weakest to the strongest.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub enum HandRank {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    Straight,
+    Flush,
+    FullHouse,
+    FourOfAKind,
+    StraightFlush,
+}
+
+impl HandRank {
+    /// Evaluates a 5-card poker hand, returning its category and the ranks
+    /// breaking ties between hands of the same category. The returned pairs
+    /// compare as the hands do.
+    ///
+    /// Tie-breaking ranks are ordered by the number of cards of the rank, and
+    /// then by rank, both descending (e.g. `[Queen, Queen, Queen, Nine, Nine]`
+    /// for a full house of queens over nines). In the ace-low straight
+    /// (`A2345`), the ace counts as one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the hand doesn't consist of exactly 5 cards.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::misc::cards::{Card, CardRank, HandRank};
+    ///
+    /// let hand = |s: &str| {
+    ///     let cards: Vec<_> = s.split(' ').map(|c| Card::from(c.to_string())).collect();
+    ///     HandRank::evaluate(&cards)
+    /// };
+    /// assert_eq!(hand("TH JH QH KH AH").0, HandRank::StraightFlush);
+    /// assert_eq!(hand("2C 2D 5H 5S 9C").0, HandRank::TwoPair);
+    /// assert_eq!(hand("AD 2C 3H 4S 5C").1[0], CardRank::Five);
+    ///
+    /// // Same category, so the pairs, and then the kickers decide.
+    /// assert!(hand("KC KD 7H 5S 2C") > hand("QC QD AH KS JC"));
+    /// assert!(hand("KC KD 7H 5S 3C") > hand("KH KS 7C 5D 2D"));
+    /// assert!(hand("2C 3D 4H 5S 6C") > hand("AS 2S 3S 4D 5D"));
+    /// ```
+    pub fn evaluate(hand: &[Card]) -> (Self, Vec<CardRank>) {
+        assert!(
+            hand.len() == 5,
+            "Poker hand has {} cards, expected 5",
+            hand.len()
+        );
+        let mut cnt = [0; 13];
+        for card in hand {
+            cnt[card.rank() as usize] += 1;
+        }
+        let mut ranks: Vec<(usize, CardRank)> = hand
+            .iter()
+            .map(|card| (cnt[card.rank() as usize], card.rank()))
+            .collect();
+        ranks.sort_unstable_by(|a, b| b.cmp(a));
+        let mut ranks: Vec<CardRank> = ranks.into_iter().map(|(_, rank)| rank).collect();
+
+        let flush = hand.iter().all(|card| card.is_same_suit(&hand[0]));
+        let distinct = cnt.iter().filter(|&&c| c > 0).count();
+        let wheel = ranks
+            == [
+                CardRank::Ace,
+                CardRank::Five,
+                CardRank::Four,
+                CardRank::Three,
+                CardRank::Two,
+            ];
+        if wheel {
+            ranks.rotate_left(1);
+        }
+        let straight = distinct == 5 && (wheel || ranks[0] as usize - ranks[4] as usize == 4);
+
+        let category = match (straight, flush, cnt.iter().max().unwrap(), distinct) {
+            (true, true, ..) => Self::StraightFlush,
+            (_, _, 4, _) => Self::FourOfAKind,
+            (_, _, 3, 2) => Self::FullHouse,
+            (_, true, ..) => Self::Flush,
+            (true, ..) => Self::Straight,
+            (_, _, 3, _) => Self::ThreeOfAKind,
+            (_, _, 2, 3) => Self::TwoPair,
+            (_, _, 2, _) => Self::OnePair,
+            _ => Self::HighCard,
+        };
+        (category, ranks)
+    }
 }
 
 #[cfg(test)]
@@ -583,4 +940,140 @@ mod tests {
             vec![CardSuit::Diamonds, CardSuit::Hearts, CardSuit::Spades]
         );
     }
+
+    #[test]
+    fn deal_and_shuffle() {
+        let mut deck = CardDeck::new().shuffled(&mut Rng::new(7));
+        deck.set_trump(CardSuit::Diamonds);
+        let hands = deck.deal(3, 5);
+        assert_eq!(deck.cards().len(), 37);
+        let total: usize = CardSuit::all().map(|s| deck.cards_by_suit(s).len()).sum();
+        assert_eq!(total, 37);
+
+        let mut all: Vec<_> = deck.cards().to_vec();
+        for hand in &hands {
+            assert_eq!(hand.cards().len(), 5);
+            assert_eq!(hand.trump(), Some(CardSuit::Diamonds));
+            all.extend_from_slice(hand.cards());
+        }
+        let mut all: Vec<_> = all.iter().map(ToString::to_string).collect();
+        all.sort();
+        all.dedup();
+        assert_eq!(all.len(), 52);
+
+        let rest = deck.deal(37, 1);
+        assert!(deck.cards().is_empty());
+        assert_eq!(rest.len(), 37);
+    }
+
+    #[test]
+    #[should_panic(expected = "Not enough cards: 60 needed, 52 left")]
+    fn deal_too_many() {
+        CardDeck::new().deal(6, 10);
+    }
+
+    #[test]
+    fn beats() {
+        let card = |s: &str| Card::from(s.to_string());
+        let trump = Some(CardSuit::Clubs);
+        assert!(card("2C").beats(&card("AD"), trump, CardSuit::Diamonds));
+        assert!(!card("AD").beats(&card("2C"), trump, CardSuit::Diamonds));
+        assert!(card("3C").beats(&card("2C"), trump, CardSuit::Diamonds));
+        assert!(!card("2C").beats(&card("2C"), trump, CardSuit::Diamonds));
+        assert!(!card("AH").beats(&card("2D"), trump, CardSuit::Diamonds));
+        assert!(!card("AH").beats(&card("KS"), trump, CardSuit::Diamonds));
+    }
+
+    #[test]
+    fn poker_hands() {
+        let hand = |s: &str| {
+            let cards: Vec<_> = s.split(' ').map(|c| Card::from(c.to_string())).collect();
+            HandRank::evaluate(&cards)
+        };
+        let ladder = [
+            ("2C 5D 7H 9S JC", HandRank::HighCard),
+            ("2C 2D 7H 9S JC", HandRank::OnePair),
+            ("2C 2D 7H 7S JC", HandRank::TwoPair),
+            ("2C 2D 2H 9S JC", HandRank::ThreeOfAKind),
+            ("AC 2D 3H 4S 5C", HandRank::Straight),
+            ("9C TD JH QS KC", HandRank::Straight),
+            ("TC JD QH KS AC", HandRank::Straight),
+            ("2H 5H 7H 9H JH", HandRank::Flush),
+            ("2C 2D 2H 9S 9C", HandRank::FullHouse),
+            ("2C 2D 2H 2S JC", HandRank::FourOfAKind),
+            ("AD 2D 3D 4D 5D", HandRank::StraightFlush),
+            ("TS JS QS KS AS", HandRank::StraightFlush),
+        ];
+        for w in ladder.windows(2) {
+            assert_eq!(hand(w[0].0).0, w[0].1, "{}", w[0].0);
+            assert!(hand(w[0].0) < hand(w[1].0), "{} < {}", w[0].0, w[1].0);
+        }
+        assert_eq!(hand("QC QD 9H 9S QH").1, vec![
+            CardRank::Queen,
+            CardRank::Queen,
+            CardRank::Queen,
+            CardRank::Nine,
+            CardRank::Nine
+        ]);
+        assert_eq!(hand("2C 3D 4H 5S 7C").0, HandRank::HighCard);
+        assert_eq!(hand("QC KD AH 2S 3C").0, HandRank::HighCard);
+        assert_eq!(hand("5C 6D 7H 8S 9C"), hand("5D 6H 7S 8C 9D"));
+    }
+
+    #[test]
+    fn rank_orders() {
+        let all: Vec<_> = CardRank::all().collect();
+        assert_eq!(RankOrder::ace_high().ranks().collect::<Vec<_>>(), all);
+        let low: Vec<_> = RankOrder::ace_low().ranks().collect();
+        assert_eq!(low[0], CardRank::Ace);
+        assert_eq!(low[1..], all[..12]);
+
+        let deck = CardDeck::new();
+        assert_eq!(
+            deck.cards(),
+            CardDeck::with_order(RankOrder::ace_high()).cards()
+        );
+
+        let cards = ["AH", "2H", "KH", "AS"].map(|s| Card::from(s.to_string()));
+        let mut deck = CardDeck::from_vec(cards.to_vec(), None);
+        deck.set_order(RankOrder::ace_low());
+        let deck = deck.sorted();
+        let sorted: Vec<_> = deck.cards().iter().map(ToString::to_string).collect();
+        assert_eq!(sorted, ["AH", "AS", "2H", "KH"]);
+        let hearts: Vec<_> = deck
+            .cards_by_suit(CardSuit::Hearts)
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(hearts, ["AH", "2H", "KH"]);
+
+        let mut deck = CardDeck::with_order(RankOrder::ace_low()).shuffled(&mut Rng::new(3));
+        deck.set_trump(CardSuit::Spades);
+        assert_eq!(deck.order(), RankOrder::ace_low());
+        let hands = deck.deal(2, 2);
+        assert!(hands.iter().all(|h| h.order() == RankOrder::ace_low()));
+        assert!(deck.beats(&cards[1], &cards[0], CardSuit::Hearts));
+        assert!(deck.beats(&cards[3], &cards[2], CardSuit::Hearts));
+    }
+
+    #[test]
+    fn durak_deck() {
+        let ranks: Vec<_> = CardRank::all().skip(4).collect();
+        let deck = CardDeck::with_order(RankOrder::custom(&ranks));
+        assert_eq!(deck.cards().len(), 36);
+        assert!(deck.cards().iter().all(|c| c.rank() >= CardRank::Six));
+        assert_eq!(deck.cards_by_suit(CardSuit::Hearts).len(), 9);
+    }
+
+    #[test]
+    #[should_panic(expected = "Rank 2 is not in the order")]
+    fn rank_not_in_order() {
+        RankOrder::custom(&[CardRank::Ace]).key(CardRank::Two);
+    }
+
+    #[test]
+    #[should_panic(expected = "Rank A is listed more than once")]
+    fn rank_listed_twice() {
+        RankOrder::custom(&[CardRank::Ace, CardRank::King, CardRank::Ace]);
+    }
 }
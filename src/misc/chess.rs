@@ -0,0 +1,171 @@
+//! Squares and piece moves on a chess board.
+//!
+//! Squares are `(row, col)` pairs, with rows growing downwards, as the board is
+//! usually printed in the input: on an `n x n` board, rank `n` is row `0`, and
+//! file `a` is column `0`. Boards up to `26 x 26` are supported, with ranks of
+//! any number of digits (e.g. `b10`).
+//!
+//! ```
+//! use algorist::misc::chess::{knight_moves, parse_square, square_name};
+//!
+//! let (row, col) = parse_square("e4", 8).unwrap();
+//! assert_eq!((row, col), (4, 4));
+//! assert_eq!(square_name(row, col, 8), "e4");
+//!
+//! let mut moves: Vec<_> = knight_moves((7, 0), 8)
+//!     .map(|(r, c)| square_name(r, c, 8))
+//!     .collect();
+//! moves.sort();
+//! assert_eq!(moves, vec!["b3", "c2"]);
+//! ```
+
+use crate::misc::direction::Dir8;
+
+/// Changes of `(row, col)` of the 8 knight moves.
+pub const KNIGHT_DELTAS: [(isize, isize); 8] = [
+    (-2, -1),
+    (-2, 1),
+    (-1, 2),
+    (1, 2),
+    (2, 1),
+    (2, -1),
+    (1, -2),
+    (-1, -2),
+];
+
+/// Parses a square in algebraic notation (e.g. `e4`) on an `n x n` board into
+/// `(row, col)`, or returns `None` if it is not a square of the board.
+///
+/// # Example
+///
+/// ```
+/// use algorist::misc::chess::parse_square;
+///
+/// assert_eq!(parse_square("a8", 8), Some((0, 0)));
+/// assert_eq!(parse_square("h1", 8), Some((7, 7)));
+/// assert_eq!(parse_square("j10", 10), Some((0, 9)));
+/// assert_eq!(parse_square("i1", 8), None);
+/// assert_eq!(parse_square("a0", 8), None);
+/// ```
+pub fn parse_square(s: &str, n: usize) -> Option<(usize, usize)> {
+    let (&file, rank) = s.as_bytes().split_first()?;
+    let col = usize::from(file.checked_sub(b'a')?);
+    if rank.is_empty() || rank[0] == b'0' || !rank.iter().all(u8::is_ascii_digit) {
+        return None;
+    }
+    let rank: usize = std::str::from_utf8(rank).ok()?.parse().ok()?;
+    (col < n && rank <= n).then(|| (n - rank, col))
+}
+
+/// Returns the algebraic notation of square `(row, col)` on an `n x n` board.
+///
+/// # Panics
+///
+/// Panics if the square is not on the board, or the board is wider than 26
+/// files.
+///
+/// # Example
+///
+/// ```
+/// use algorist::misc::chess::square_name;
+///
+/// assert_eq!(square_name(0, 0, 8), "a8");
+/// assert_eq!(square_name(7, 4, 8), "e1");
+/// assert_eq!(square_name(0, 1, 12), "b12");
+/// ```
+pub fn square_name(row: usize, col: usize, n: usize) -> String {
+    assert!(n <= 26, "Board of size {n} is wider than 26 files");
+    assert!(row < n && col < n, "Square ({row}, {col}) is out of bounds");
+    format!("{}{}", char::from(b'a' + col as u8), n - row)
+}
+
+/// Returns the squares, reachable with `deltas` from `pos`, which are on the
+/// `n x n` board.
+fn moves(
+    pos: (usize, usize),
+    n: usize,
+    deltas: impl Iterator<Item = (isize, isize)>,
+) -> impl Iterator<Item = (usize, usize)> {
+    deltas.filter_map(move |(dr, dc)| {
+        let row = pos.0.checked_add_signed(dr).filter(|&r| r < n)?;
+        let col = pos.1.checked_add_signed(dc).filter(|&c| c < n)?;
+        Some((row, col))
+    })
+}
+
+/// Returns the squares of the `n x n` board, reachable from `pos` with a
+/// knight move.
+///
+/// # Example
+///
+/// ```
+/// use algorist::misc::chess::knight_moves;
+///
+/// assert_eq!(knight_moves((3, 3), 8).count(), 8);
+/// assert_eq!(knight_moves((0, 0), 3).collect::<Vec<_>>(), vec![
+///     (1, 2),
+///     (2, 1)
+/// ]);
+/// assert_eq!(knight_moves((1, 1), 3).count(), 0);
+/// ```
+pub fn knight_moves(pos: (usize, usize), n: usize) -> impl Iterator<Item = (usize, usize)> {
+    moves(pos, n, KNIGHT_DELTAS.into_iter())
+}
+
+/// Returns the squares of the `n x n` board, reachable from `pos` with a king
+/// move.
+///
+/// # Example
+///
+/// ```
+/// use algorist::misc::chess::{king_moves, parse_square};
+///
+/// let corner = parse_square("h1", 8).unwrap();
+/// assert_eq!(king_moves(corner, 8).count(), 3);
+/// assert_eq!(king_moves((4, 4), 8).count(), 8);
+/// ```
+pub fn king_moves(pos: (usize, usize), n: usize) -> impl Iterator<Item = (usize, usize)> {
+    moves(pos, n, Dir8::ALL.into_iter().map(Dir8::delta))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_notation_roundtrip() {
+        for n in [1, 8, 10, 26] {
+            for row in 0..n {
+                for col in 0..n {
+                    let name = square_name(row, col, n);
+                    assert_eq!(parse_square(&name, n), Some((row, col)), "{name}");
+                }
+            }
+        }
+        for s in ["", "e", "4", "E4", "e9", "e04", "e-1", "e4 ", "a27"] {
+            assert_eq!(parse_square(s, 8), None, "{s:?}");
+        }
+    }
+
+    #[test]
+    fn test_moves() {
+        // Number of knight and king moves from each square of the 8x8 board.
+        let knight: usize = (0..64)
+            .map(|i| knight_moves((i / 8, i % 8), 8).count())
+            .sum();
+        let king: usize = (0..64).map(|i| king_moves((i / 8, i % 8), 8).count()).sum();
+        assert_eq!((knight, king), (336, 420));
+
+        for (r, c) in knight_moves((2, 5), 8) {
+            let (dr, dc) = (r.abs_diff(2), c.abs_diff(5));
+            assert_eq!(dr * dr + dc * dc, 5);
+        }
+        assert_eq!(king_moves((0, 0), 1).count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Square (8, 0) is out of bounds")]
+    fn test_square_name_out_of_bounds() {
+        square_name(8, 0, 8);
+    }
+}
@@ -0,0 +1,259 @@
+//! Directions on a grid.
+//!
+//! [`Dir4`] and [`Dir8`] are directions to the 4 (orthogonal) and 8
+//! (including diagonal) neighbors of a cell, with rows growing downwards, as
+//! in the input. Both are parsed from `U`, `D`, `L`, `R` or `N`, `S`, `W`, `E`
+//! letters, and can be rotated and reversed.
+//!
+//! ```
+//! use algorist::misc::direction::Dir4;
+//!
+//! let mut dir: Dir4 = 'R'.try_into().unwrap();
+//! assert_eq!(dir.delta(), (0, 1));
+//! dir = dir.turn_right();
+//! assert_eq!(dir, Dir4::Down);
+//! assert_eq!(dir.opposite(), Dir4::Up);
+//! assert_eq!("W".parse::<Dir4>(), Ok(Dir4::Left));
+//! ```
+//!
+//! To move on an [`Arr`](crate::collections::arr_2d::Arr), staying within its
+//! bounds, use [`Arr::step`](crate::collections::arr_2d::Arr::step).
+
+use std::{fmt, str::FromStr};
+
+/// Error of parsing a direction from a string or a character.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDirError(String);
+
+impl fmt::Display for ParseDirError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Invalid direction: {}", self.0)
+    }
+}
+
+/// One of 4 orthogonal directions, in clockwise order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Dir4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir4 {
+    /// All directions, in clockwise order, starting from [`Dir4::Up`].
+    pub const ALL: [Dir4; 4] = [Dir4::Up, Dir4::Right, Dir4::Down, Dir4::Left];
+
+    /// Returns the `(row, col)` change of moving in the direction.
+    pub fn delta(self) -> (isize, isize) {
+        match self {
+            Dir4::Up => (-1, 0),
+            Dir4::Right => (0, 1),
+            Dir4::Down => (1, 0),
+            Dir4::Left => (0, -1),
+        }
+    }
+
+    /// Returns the reversed direction.
+    #[must_use]
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    /// Returns the direction rotated by 90 degrees counterclockwise.
+    #[must_use]
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    /// Returns the direction rotated by 90 degrees clockwise.
+    #[must_use]
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+}
+
+impl TryFrom<char> for Dir4 {
+    type Error = ParseDirError;
+
+    /// Parses `U`, `R`, `D`, `L`, or `N`, `E`, `S`, `W` (in either case).
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c.to_ascii_uppercase() {
+            'U' | 'N' => Ok(Dir4::Up),
+            'R' | 'E' => Ok(Dir4::Right),
+            'D' | 'S' => Ok(Dir4::Down),
+            'L' | 'W' => Ok(Dir4::Left),
+            _ => Err(ParseDirError(c.to_string())),
+        }
+    }
+}
+
+impl FromStr for Dir4 {
+    type Err = ParseDirError;
+
+    /// Parses a single direction letter, see [`Dir4::try_from`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Dir4::try_from(c).map_err(|_| ParseDirError(s.to_string())),
+            _ => Err(ParseDirError(s.to_string())),
+        }
+    }
+}
+
+/// One of 8 directions (orthogonal and diagonal), in clockwise order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Dir8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Dir8 {
+    /// All directions, in clockwise order, starting from [`Dir8::Up`].
+    pub const ALL: [Dir8; 8] = [
+        Dir8::Up,
+        Dir8::UpRight,
+        Dir8::Right,
+        Dir8::DownRight,
+        Dir8::Down,
+        Dir8::DownLeft,
+        Dir8::Left,
+        Dir8::UpLeft,
+    ];
+
+    /// Returns the `(row, col)` change of moving in the direction.
+    pub fn delta(self) -> (isize, isize) {
+        const DELTAS: [(isize, isize); 8] = [
+            (-1, 0),
+            (-1, 1),
+            (0, 1),
+            (1, 1),
+            (1, 0),
+            (1, -1),
+            (0, -1),
+            (-1, -1),
+        ];
+        DELTAS[self as usize]
+    }
+
+    /// Returns the direction with the given `(row, col)` change, if any.
+    pub fn from_delta(delta: (isize, isize)) -> Option<Self> {
+        Self::ALL.into_iter().find(|d| d.delta() == delta)
+    }
+
+    /// Returns the reversed direction.
+    #[must_use]
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    /// Returns the direction rotated by 45 degrees counterclockwise.
+    #[must_use]
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    /// Returns the direction rotated by 45 degrees clockwise.
+    #[must_use]
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Checks whether the direction is diagonal.
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(d: Dir4) -> Self {
+        Dir8::ALL[d as usize * 2]
+    }
+}
+
+impl FromStr for Dir8 {
+    type Err = ParseDirError;
+
+    /// Parses one or two [`Dir4`] letters, e.g. `U`, `UR`, `NE`, `SW` or `LD`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::misc::direction::Dir8;
+    ///
+    /// assert_eq!("NE".parse(), Ok(Dir8::UpRight));
+    /// assert_eq!("ld".parse(), Ok(Dir8::DownLeft));
+    /// assert!("UD".parse::<Dir8>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseDirError(s.to_string());
+        if s.is_empty() || s.chars().count() > 2 {
+            return Err(err());
+        }
+        let mut delta = (0, 0);
+        for c in s.chars() {
+            let (dr, dc) = Dir4::try_from(c).map_err(|_| err())?.delta();
+            delta = (delta.0 + dr, delta.1 + dc);
+        }
+        let d = Dir8::from_delta(delta).ok_or_else(err)?;
+        // Reject repeated letters, such as "UU" or "NU".
+        if d.is_diagonal() == (s.chars().count() == 2) {
+            Ok(d)
+        } else {
+            Err(err())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dir4() {
+        for d in Dir4::ALL {
+            assert_eq!(d.opposite().opposite(), d);
+            assert_eq!(d.turn_left().turn_right(), d);
+            assert_eq!(d.turn_right().turn_right(), d.opposite());
+            let (dr, dc) = d.delta();
+            assert_eq!(d.opposite().delta(), (-dr, -dc));
+            // Clockwise rotation (with rows growing downwards).
+            assert_eq!(d.turn_right().delta(), (dc, -dr));
+            assert_eq!(Dir8::from(d).delta(), d.delta());
+        }
+        let parsed: Vec<Dir4> = "URDLnesw".chars().map(|c| c.try_into().unwrap()).collect();
+        assert_eq!(&parsed[..4], &Dir4::ALL);
+        assert_eq!(&parsed[4..], &Dir4::ALL);
+        assert!(Dir4::try_from('X').is_err());
+        assert!("UR".parse::<Dir4>().is_err());
+        assert!("".parse::<Dir4>().is_err());
+    }
+
+    #[test]
+    fn test_dir8() {
+        for d in Dir8::ALL {
+            assert_eq!(d.opposite().opposite(), d);
+            assert_eq!(d.turn_left().turn_right(), d);
+            assert_eq!(
+                d.turn_right().turn_right(),
+                d.opposite().turn_right().turn_right().opposite()
+            );
+            assert_eq!(Dir8::from_delta(d.delta()), Some(d));
+            let (dr, dc) = d.delta();
+            assert_eq!(d.opposite().delta(), (-dr, -dc));
+            assert_eq!(d.is_diagonal(), dr != 0 && dc != 0);
+        }
+        assert_eq!(Dir8::from_delta((0, 0)), None);
+        assert_eq!("S".parse(), Ok(Dir8::Down));
+        assert_eq!("RU".parse(), Ok(Dir8::UpRight));
+        for s in ["", "UU", "NU", "URD", "X"] {
+            assert_eq!(s.parse::<Dir8>(), Err(ParseDirError(s.to_string())));
+        }
+    }
+}
@@ -0,0 +1,151 @@
+//! Dynamic programming drivers.
+//!
+//! # Interval DP
+//!
+//! Many problems ask for the cheapest way to merge a sequence into a single
+//! item, where merging two adjacent blocks `[i, k]` and `[k + 1, j]` costs
+//! something depending on the blocks: matrix chain multiplication, merging
+//! piles of stones, optimal polygon triangulation, etc. All of them are solved
+//! by the same `O(n^3)` recurrence:
+//!
+//! ```text
+//! dp[i][i] = base(i)
+//! dp[i][j] = min(dp[i][k] + dp[k + 1][j] + combine(i, k, j)), i <= k < j
+//! ```
+//!
+//! which is implemented by [`interval_dp`].
+//!
+//! ```
+//! use algorist::misc::dp::interval_dp;
+//!
+//! // Merge adjacent piles, merging costs the total size of both piles.
+//! let piles = [4, 1, 1, 4];
+//! let mut prefix = vec![0];
+//! for p in piles {
+//!     prefix.push(prefix.last().unwrap() + p);
+//! }
+//! let dp = interval_dp(piles.len(), |_| 0, |i, _, j| prefix[j + 1] - prefix[i]);
+//! assert_eq!(dp.cost[(0, 3)], 18); // (4 (1 1)) 4: 2 + 6 + 10
+//! ```
+
+use {
+    crate::{collections::arr_2d::Arr, math::Number},
+    std::fmt::Debug,
+};
+
+/// Tables computed by [`interval_dp`].
+///
+/// Only cells `(i, j)` with `i <= j` are meaningful.
+#[derive(Debug)]
+pub struct IntervalDp<T: Debug> {
+    /// Minimum cost of the interval `[i, j]`.
+    pub cost: Arr<T>,
+    /// Optimal split point `k` of the interval `[i, j]` (the left part is
+    /// `[i, k]`), for `i < j`. Allows to reconstruct the optimal merge order.
+    pub split: Arr<usize>,
+}
+
+/// Runs the interval DP over `n` items, in `O(n^3)`.
+///
+/// The cost of a single item `i` is `base(i)`, and merging `[i, k]` with
+/// `[k + 1, j]` additionally costs `combine(i, k, j)`. On ties, the smallest
+/// split point is chosen.
+///
+/// # Example
+///
+/// Matrix chain multiplication: matrix `i` has dimensions `dims[i] x dims[i +
+/// 1]`.
+///
+/// ```
+/// use algorist::misc::dp::{IntervalDp, interval_dp};
+///
+/// let dims = [10, 30, 5, 60];
+/// let n = dims.len() - 1;
+/// let dp = interval_dp(n, |_| 0, |i, k, j| dims[i] * dims[k + 1] * dims[j + 1]);
+/// assert_eq!(dp.cost[(0, n - 1)], 4_500);
+///
+/// fn order(dp: &IntervalDp<usize>, i: usize, j: usize) -> String {
+///     if i == j {
+///         return format!("A{i}");
+///     }
+///     let k = dp.split[(i, j)];
+///     format!("({} {})", order(dp, i, k), order(dp, k + 1, j))
+/// }
+/// assert_eq!(order(&dp, 0, n - 1), "((A0 A1) A2)");
+/// ```
+pub fn interval_dp<T, B, C>(n: usize, mut base: B, mut combine: C) -> IntervalDp<T>
+where
+    T: Number,
+    B: FnMut(usize) -> T,
+    C: FnMut(usize, usize, usize) -> T,
+{
+    let mut cost = Arr::new(n, n);
+    let mut split = Arr::new(n, n);
+    for i in 0..n {
+        cost[(i, i)] = base(i);
+    }
+    for len in 2..=n {
+        for i in 0..=n - len {
+            let j = i + len - 1;
+            let mut best: Option<(T, usize)> = None;
+            for k in i..j {
+                let c = cost[(i, k)] + cost[(k + 1, j)] + combine(i, k, j);
+                if best.map_or(true, |(b, _)| c < b) {
+                    best = Some((c, k));
+                }
+            }
+            let (c, k) = best.unwrap();
+            cost[(i, j)] = c;
+            split[(i, j)] = k;
+        }
+    }
+    IntervalDp { cost, split }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Minimum cost over all binary trees with leaves `i..=j`, by recursion.
+    fn naive(i: usize, j: usize, dims: &[u64]) -> u64 {
+        (i..j)
+            .map(|k| {
+                naive(i, k, dims) + naive(k + 1, j, dims) + dims[i] * dims[k + 1] * dims[j + 1]
+            })
+            .min()
+            .unwrap_or(0)
+    }
+
+    #[test]
+    fn test_matrix_chain() {
+        let dims = [7, 3, 9, 2, 8, 5, 4, 6, 1];
+        let n = dims.len() - 1;
+        let dp = interval_dp(n, |_| 0, |i, k, j| dims[i] * dims[k + 1] * dims[j + 1]);
+        for i in 0..n {
+            for j in i..n {
+                assert_eq!(dp.cost[(i, j)], naive(i, j, &dims), "[{i}, {j}]");
+                if i < j {
+                    let k = dp.split[(i, j)];
+                    assert!((i..j).contains(&k));
+                    let want =
+                        dp.cost[(i, k)] + dp.cost[(k + 1, j)] + dims[i] * dims[k + 1] * dims[j + 1];
+                    assert_eq!(dp.cost[(i, j)], want);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_small() {
+        let dp = interval_dp(0, |_| 0i64, |_, _, _| 0);
+        assert_eq!(dp.cost.rows(), 0);
+
+        let dp = interval_dp(1, |i| i as i64 + 5, |_, _, _| 100);
+        assert_eq!(dp.cost[(0, 0)], 5);
+
+        // Base costs are summed up, merges are counted once each.
+        let dp = interval_dp(4, |i| i as i32, |_, _, _| 10);
+        assert_eq!(dp.cost[(0, 3)], 6 + 30);
+        assert_eq!(dp.split[(0, 3)], 0);
+    }
+}
@@ -0,0 +1,166 @@
+//! Impartial games and Sprague-Grundy theory.
+//!
+//! In an impartial game (both players have the same moves, and the player who
+//! can't move loses), every position has a Grundy value: the [`mex`] of the
+//! values of positions reachable in one move. A position is losing for the
+//! player to move iff its value is zero, and the value of a sum of
+//! independent games is the [`nim_sum`] (XOR) of their values.
+//!
+//! ```
+//! use algorist::misc::game::{grundy, nim_sum};
+//!
+//! // Subtraction game: take 1, 3 or 4 stones from a pile.
+//! let g = grundy([10, 7], |&n: &u32| {
+//!     [1, 3, 4]
+//!         .into_iter()
+//!         .filter(move |&k| k <= n)
+//!         .map(move |k| n - k)
+//! });
+//! assert_eq!(g[&7], 0); // values are periodic: 0 1 0 1 2 3 2 0 1 0 1 ...
+//! assert_eq!(g[&10], 1);
+//!
+//! // Two piles of 10 and 7: the first player wins.
+//! assert_ne!(nim_sum([g[&10], g[&7]]), 0);
+//! ```
+
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
+
+/// Returns the minimum excluded value: the smallest non-negative integer,
+/// not present in `values`.
+///
+/// # Example
+///
+/// ```
+/// use algorist::misc::game::mex;
+///
+/// assert_eq!(mex([0, 1, 3]), 2);
+/// assert_eq!(mex([]), 0);
+/// ```
+pub fn mex<I: IntoIterator<Item = usize>>(values: I) -> usize {
+    let mut seen = vec![];
+    for v in values {
+        if v >= seen.len() {
+            seen.resize(v + 1, false);
+        }
+        seen[v] = true;
+    }
+    seen.iter().position(|&s| !s).unwrap_or(seen.len())
+}
+
+/// Returns the XOR of Grundy values of independent games, which is the Grundy
+/// value of their sum.
+///
+/// # Example
+///
+/// ```
+/// use algorist::misc::game::nim_sum;
+///
+/// // Nim with piles 3, 4, 5: the first player wins.
+/// assert_eq!(nim_sum([3, 4, 5]), 2);
+/// ```
+pub fn nim_sum<I: IntoIterator<Item = usize>>(values: I) -> usize {
+    values.into_iter().fold(0, |acc, v| acc ^ v)
+}
+
+/// Computes Grundy values of all positions reachable from `states`, where
+/// `moves` returns positions reachable in one move.
+///
+/// Positions must form a DAG. The traversal is iterative, so long chains of
+/// moves do not overflow the stack.
+///
+/// # Panics
+///
+/// Panics if there is a cycle of moves.
+pub fn grundy<S, I, F, M>(states: I, mut moves: F) -> HashMap<S, usize>
+where
+    S: Hash + Eq + Clone,
+    I: IntoIterator<Item = S>,
+    F: FnMut(&S) -> M,
+    M: IntoIterator<Item = S>,
+{
+    let mut memo: HashMap<S, usize> = HashMap::new();
+    let mut on_stack: HashSet<S> = HashSet::new();
+    for start in states {
+        if memo.contains_key(&start) {
+            continue;
+        }
+        // Positions with their moves, and the index of the next move to visit.
+        let mut stack = vec![(
+            start.clone(),
+            moves(&start).into_iter().collect::<Vec<_>>(),
+            0,
+        )];
+        on_stack.insert(start);
+        while let Some((s, next, i)) = stack.last_mut() {
+            while *i < next.len() && memo.contains_key(&next[*i]) {
+                *i += 1;
+            }
+            if *i < next.len() {
+                let t = next[*i].clone();
+                assert!(!on_stack.contains(&t), "Moves must not form a cycle");
+                let t_moves = moves(&t).into_iter().collect();
+                on_stack.insert(t.clone());
+                stack.push((t, t_moves, 0));
+            } else {
+                let g = mex(next.iter().map(|t| memo[t]));
+                on_stack.remove(s);
+                memo.insert(s.clone(), g);
+                stack.pop();
+            }
+        }
+    }
+    memo
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nim() {
+        // A single Nim pile of `n` has the value `n`.
+        let g = grundy([20usize], |&n| 0..n);
+        assert_eq!(g.len(), 21);
+        assert!((0..=20).all(|n| g[&n] == n));
+    }
+
+    #[test]
+    fn test_sum_of_games() {
+        // Take 1 or 2 stones from any pile: a single pile of `n` has the value
+        // `n % 3`, and the value of several piles is the XOR of their values.
+        let moves = |piles: &Vec<u32>| {
+            let mut res = vec![];
+            for i in 0..piles.len() {
+                for k in 1..=piles[i].min(2) {
+                    let mut next = piles.clone();
+                    next[i] -= k;
+                    next.sort();
+                    res.push(next);
+                }
+            }
+            res
+        };
+        let starts: Vec<Vec<u32>> = (0..6)
+            .flat_map(|a| (a..6).flat_map(move |b| (b..6).map(move |c| vec![a, b, c])))
+            .collect();
+        let g = grundy(starts.clone(), moves);
+        for s in starts {
+            assert_eq!(g[&s], nim_sum(s.iter().map(|&n| n as usize % 3)), "{s:?}");
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Moves must not form a cycle")]
+    fn test_cycle() {
+        grundy([0], |&s: &u8| [(s + 1) % 3]);
+    }
+
+    #[test]
+    fn test_deep() {
+        let g = grundy([200_000u32], |&n| n.checked_sub(1));
+        assert_eq!(g[&200_000], 0);
+    }
+}
@@ -0,0 +1,129 @@
+//! Classic greedy algorithms.
+//!
+//! # Optimal merge costs
+//!
+//! When several items need to be combined into one, and combining items costs
+//! the sum of their weights (think "combine stones" or "connect ropes"), the
+//! optimal strategy is to always merge the smallest items first, which is
+//! exactly how the Huffman code is built.
+//!
+//! Use [`huffman_merge_cost`] for pairwise merges, and [`huffman_merge_cost_k`]
+//! when `k` items are merged at once.
+//!
+//! ```
+//! use algorist::misc::greedy::{huffman_merge_cost, huffman_merge_cost_k};
+//!
+//! // (1 + 2) = 3, (3 + 3) = 6, (4 + 6) = 10, total = 3 + 6 + 10
+//! assert_eq!(huffman_merge_cost(&[4, 3, 2, 1]), 19);
+//!
+//! // (1 + 2 + 3) = 6, (4 + 5 + 6) = 15, total = 6 + 15
+//! assert_eq!(huffman_merge_cost_k(&[1, 2, 3, 4, 5], 3), 21);
+//! ```
+
+use {
+    crate::math::Number,
+    std::{cmp::Reverse, collections::BinaryHeap},
+};
+
+/// Computes the minimal total cost of merging all `weights` into a single
+/// item, where merging two items costs the sum of their weights.
+///
+/// Returns zero if there is at most one item.
+///
+/// # Example
+///
+/// ```
+/// use algorist::misc::greedy::huffman_merge_cost;
+///
+/// assert_eq!(huffman_merge_cost(&[8, 4, 6, 12]), 58);
+/// assert_eq!(huffman_merge_cost(&[42]), 0);
+/// assert_eq!(huffman_merge_cost::<i64>(&[]), 0);
+/// ```
+pub fn huffman_merge_cost<T: Number + Ord>(weights: &[T]) -> T {
+    huffman_merge_cost_k(weights, 2)
+}
+
+/// Computes the minimal total cost of merging all `weights` into a single
+/// item, where `k` items are merged at once, and a merge costs the sum of
+/// the merged weights.
+///
+/// If the number of items does not allow for all merges to take exactly `k`
+/// items, the input is padded with zero-weight items, which makes the first
+/// merge take fewer real items (this is the optimal strategy).
+///
+/// # Panics
+///
+/// Panics if `k < 2`.
+///
+/// # Example
+///
+/// ```
+/// use algorist::misc::greedy::huffman_merge_cost_k;
+///
+/// // Padded with a single zero: (0 + 1 + 2) = 3, (3 + 3 + 4) = 10
+/// assert_eq!(huffman_merge_cost_k(&[1, 2, 3, 4], 3), 13);
+///
+/// // For `k = 2` it is the same as `huffman_merge_cost`.
+/// assert_eq!(huffman_merge_cost_k(&[8, 4, 6, 12], 2), 58);
+/// ```
+pub fn huffman_merge_cost_k<T: Number + Ord>(weights: &[T], k: usize) -> T {
+    assert!(k >= 2, "At least two items must be merged at once");
+    if weights.len() <= 1 {
+        return T::zero();
+    }
+
+    let mut heap: BinaryHeap<_> = weights.iter().map(|&w| Reverse(w)).collect();
+    while (heap.len() - 1) % (k - 1) != 0 {
+        heap.push(Reverse(T::zero()));
+    }
+
+    let mut total = T::zero();
+    while heap.len() > 1 {
+        let mut merged = T::zero();
+        for _ in 0..k {
+            if let Some(Reverse(w)) = heap.pop() {
+                merged += w;
+            }
+        }
+        total += merged;
+        heap.push(Reverse(merged));
+    }
+    total
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_huffman_merge_cost() {
+        assert_eq!(huffman_merge_cost::<i32>(&[]), 0);
+        assert_eq!(huffman_merge_cost(&[5]), 0);
+        assert_eq!(huffman_merge_cost(&[5, 7]), 12);
+        assert_eq!(huffman_merge_cost(&[1, 2, 3, 4]), 19);
+        assert_eq!(huffman_merge_cost(&[8, 4, 6, 12]), 58);
+        assert_eq!(huffman_merge_cost(&[1u64, 1, 1, 1, 1, 1, 1, 1]), 24);
+        assert_eq!(
+            huffman_merge_cost(&[1_000_000_000i64, 1_000_000_000, 1_000_000_000]),
+            5_000_000_000
+        );
+    }
+
+    #[test]
+    fn test_huffman_merge_cost_k() {
+        assert_eq!(huffman_merge_cost_k(&[1, 2, 3, 4, 5], 3), 21);
+        assert_eq!(huffman_merge_cost_k(&[1, 2, 3, 4], 3), 13);
+        assert_eq!(huffman_merge_cost_k(&[1, 2], 5), 3);
+        assert_eq!(huffman_merge_cost_k(&[1, 1, 1, 1, 1, 1, 1, 1, 1], 3), 18);
+        assert_eq!(
+            huffman_merge_cost_k(&[3, 1, 4, 1, 5, 9, 2, 6], 2),
+            huffman_merge_cost(&[3, 1, 4, 1, 5, 9, 2, 6])
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "At least two items must be merged at once")]
+    fn test_huffman_merge_cost_k_invalid() {
+        huffman_merge_cost_k(&[1, 2, 3], 1);
+    }
+}
@@ -0,0 +1,213 @@
+//! Unions of intervals and interval scheduling.
+//!
+//! Intervals are `(l, r)` pairs with `l <= r`, and [`Boundary`] tells whether
+//! their endpoints are included. This matters when intervals touch: `[1, 2]`
+//! and `[2, 3]` share the point `2`, `[1, 2)` and `[2, 3)` do not, but still
+//! form a contiguous union, while the union of `(1, 2)` and `(2, 3)` misses
+//! the point `2`.
+//!
+//! ```
+//! use algorist::misc::intervals::{
+//!     Boundary,
+//!     max_non_overlapping,
+//!     merge_intervals,
+//!     total_covered_length,
+//! };
+//!
+//! let v = vec![(5, 8), (1, 3), (3, 4), (7, 10)];
+//! assert_eq!(merge_intervals(v.clone(), Boundary::Closed), vec![
+//!     (1, 4),
+//!     (5, 10)
+//! ]);
+//! assert_eq!(merge_intervals(v.clone(), Boundary::Open), vec![
+//!     (1, 3),
+//!     (3, 4),
+//!     (5, 10)
+//! ]);
+//! assert_eq!(total_covered_length(v.clone()), 8);
+//!
+//! // Meetings `[l, r)`: the one ending at 3 doesn't conflict with the one
+//! // starting at 3.
+//! assert_eq!(max_non_overlapping(&v, Boundary::HalfOpen), vec![1, 2, 0]);
+//! assert_eq!(max_non_overlapping(&v, Boundary::Closed), vec![1, 0]);
+//! ```
+
+use crate::math::Number;
+
+/// Whether endpoints of intervals are included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Boundary {
+    /// `[l, r]`: both endpoints are included.
+    Closed,
+    /// `[l, r)`: the left endpoint is included, the right one is not.
+    HalfOpen,
+    /// `(l, r)`: both endpoints are excluded.
+    Open,
+}
+
+impl Boundary {
+    /// Whether the union of intervals ending at `r` and starting at `r` is
+    /// contiguous.
+    fn touching_merge(self) -> bool {
+        self != Boundary::Open
+    }
+
+    /// Whether intervals ending at `r` and starting at `r` intersect.
+    fn touching_overlap(self) -> bool {
+        self == Boundary::Closed
+    }
+}
+
+/// Merges intervals into the minimal set of disjoint intervals with the same
+/// union, sorted by their left endpoints.
+///
+/// # Example
+///
+/// ```
+/// use algorist::misc::intervals::{Boundary, merge_intervals};
+///
+/// let v = vec![(1, 2), (2, 3), (6, 7), (0, 1)];
+/// assert_eq!(merge_intervals(v, Boundary::HalfOpen), vec![(0, 3), (6, 7)]);
+/// ```
+pub fn merge_intervals<T: Ord + Copy>(mut intervals: Vec<(T, T)>, b: Boundary) -> Vec<(T, T)> {
+    intervals.sort_unstable();
+    let mut res: Vec<(T, T)> = Vec::with_capacity(intervals.len());
+    for (l, r) in intervals {
+        match res.last_mut() {
+            Some(last) if l < last.1 || (l == last.1 && b.touching_merge()) => {
+                last.1 = last.1.max(r);
+            }
+            _ => res.push((l, r)),
+        }
+    }
+    res
+}
+
+/// Returns indexes of a maximum set of pairwise non-overlapping intervals,
+/// ordered by their right endpoints (greedy by the earliest end).
+///
+/// # Example
+///
+/// ```
+/// use algorist::misc::intervals::{Boundary, max_non_overlapping};
+///
+/// let v = [
+///     (1, 4),
+///     (3, 5),
+///     (0, 6),
+///     (5, 7),
+///     (3, 9),
+///     (5, 9),
+///     (6, 10),
+///     (8, 11),
+/// ];
+/// assert_eq!(max_non_overlapping(&v, Boundary::HalfOpen), vec![0, 3, 7]);
+/// ```
+pub fn max_non_overlapping<T: Ord + Copy>(intervals: &[(T, T)], b: Boundary) -> Vec<usize> {
+    let mut idx: Vec<usize> = (0..intervals.len()).collect();
+    idx.sort_by_key(|&i| intervals[i].1);
+    let mut res: Vec<usize> = Vec::new();
+    for i in idx {
+        let ok = res.last().map_or(true, |&j| {
+            let (end, start) = (intervals[j].1, intervals[i].0);
+            start > end || (start == end && !b.touching_overlap())
+        });
+        if ok {
+            res.push(i);
+        }
+    }
+    res
+}
+
+/// Returns the total length of the union of intervals (endpoints do not
+/// change lengths).
+///
+/// To count integer points, covered by closed intervals, merge them with
+/// [`merge_intervals`] and sum `r - l + 1`.
+///
+/// # Example
+///
+/// ```
+/// use algorist::misc::intervals::total_covered_length;
+///
+/// assert_eq!(total_covered_length(vec![(0, 5), (2, 3), (10, 12)]), 7);
+/// assert_eq!(total_covered_length::<i32>(vec![]), 0);
+/// ```
+pub fn total_covered_length<T: Number + Ord>(intervals: Vec<(T, T)>) -> T {
+    merge_intervals(intervals, Boundary::Closed)
+        .into_iter()
+        .fold(T::zero(), |acc, (l, r)| acc + (r - l))
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::misc::rng::Rng};
+
+    fn random_intervals(rng: &mut Rng) -> Vec<(i32, i32)> {
+        let n = rng.gen_range(0..8);
+        (0..n)
+            .map(|_| {
+                let l = rng.gen_range(0..15);
+                (l, l + rng.gen_range(1..5))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_merge_against_naive() {
+        let mut rng = Rng::new(18);
+        for _ in 0..200 {
+            let v = random_intervals(&mut rng);
+            // Work with doubled coordinates, so that points between integers
+            // are represented.
+            let covered = |x: i32, b: Boundary, v: &[(i32, i32)]| {
+                v.iter().any(|&(l, r)| match b {
+                    Boundary::Closed => 2 * l <= x && x <= 2 * r,
+                    Boundary::HalfOpen => 2 * l <= x && x < 2 * r,
+                    Boundary::Open => 2 * l < x && x < 2 * r,
+                })
+            };
+            for b in [Boundary::Closed, Boundary::HalfOpen, Boundary::Open] {
+                let merged = merge_intervals(v.clone(), b);
+                for x in -1..45 {
+                    assert_eq!(covered(x, b, &v), covered(x, b, &merged), "{v:?} {b:?}");
+                }
+                assert!(merged.windows(2).all(|w| w[0].1 <= w[1].0));
+            }
+            let len = (0..40)
+                .filter(|&x| covered(2 * x + 1, Boundary::Open, &v))
+                .count();
+            assert_eq!(total_covered_length(v) as usize, len);
+        }
+    }
+
+    #[test]
+    fn test_max_non_overlapping_against_naive() {
+        let mut rng = Rng::new(19);
+        for _ in 0..200 {
+            let v = random_intervals(&mut rng);
+            for b in [Boundary::Closed, Boundary::HalfOpen] {
+                let disjoint = |i: usize, j: usize| {
+                    let ((l1, r1), (l2, r2)) = (v[i], v[j]);
+                    match b {
+                        Boundary::Closed => r1 < l2 || r2 < l1,
+                        _ => r1 <= l2 || r2 <= l1,
+                    }
+                };
+                let best = (0..1usize << v.len())
+                    .filter(|&mask| {
+                        (0..v.len()).all(|i| {
+                            (0..i)
+                                .all(|j| mask >> i & 1 == 0 || mask >> j & 1 == 0 || disjoint(i, j))
+                        })
+                    })
+                    .map(|mask| mask.count_ones() as usize)
+                    .max()
+                    .unwrap();
+                let res = max_non_overlapping(&v, b);
+                assert_eq!(res.len(), best);
+                assert!(res.windows(2).all(|w| disjoint(w[0], w[1])));
+            }
+        }
+    }
+}
@@ -0,0 +1,208 @@
+//! Knapsack DPs over items with weights and values.
+//!
+//! Items are `(weight, value)` pairs (with an extra count for the bounded
+//! variant), and all functions return `dp`, where `dp[w]` is the maximum
+//! total value of items with total weight at most `w`, for `w` in `0..=cap`.
+//! All of them run in `O(n * cap)` (bounded knapsack in `O(cap * sum(log
+//! count))`).
+//!
+//! ```
+//! use algorist::misc::knapsack::{knapsack_01, knapsack_bounded, knapsack_unbounded};
+//!
+//! let items = [(3, 4), (4, 5), (2, 3)];
+//! assert_eq!(knapsack_01(&items, 7)[7], 9); // (3, 4) + (4, 5)
+//! assert_eq!(knapsack_unbounded(&items, 7)[7], 10); // (3, 4) + 2 x (2, 3)
+//!
+//! // At most one copy of the first item, and two of the third.
+//! let items = [(3, 4, 1), (4, 5, 0), (2, 3, 2)];
+//! assert_eq!(knapsack_bounded(&items, 7)[7], 10);
+//! ```
+//!
+//! # Feasibility
+//!
+//! When only reachable total weights are of interest, [`subset_sums_bitset`]
+//! is 64 times faster, as it works on [`BitSet`]:
+//!
+//! ```
+//! use algorist::misc::knapsack::subset_sums_bitset;
+//!
+//! let reach = subset_sums_bitset(&[4, 6, 9], 12);
+//! assert!(reach.get(10) && reach.get(0) && !reach.get(12));
+//! ```
+
+use crate::{collections::bitset::BitSet, math::Number};
+
+/// 0/1 knapsack: each item can be taken at most once.
+///
+/// # Example
+///
+/// ```
+/// use algorist::misc::knapsack::knapsack_01;
+///
+/// let dp = knapsack_01(&[(1, 1), (3, 4), (4, 5), (5, 7)], 7);
+/// assert_eq!(dp, vec![0, 1, 1, 4, 5, 7, 8, 9]);
+/// ```
+pub fn knapsack_01<T: Number + Ord>(items: &[(usize, T)], cap: usize) -> Vec<T> {
+    let mut dp = vec![T::zero(); cap + 1];
+    for &(w, v) in items {
+        for c in (w..=cap).rev() {
+            dp[c] = dp[c].max(dp[c - w] + v);
+        }
+    }
+    dp
+}
+
+/// Unbounded knapsack: each item can be taken any number of times.
+///
+/// # Example
+///
+/// ```
+/// use algorist::misc::knapsack::knapsack_unbounded;
+///
+/// let dp = knapsack_unbounded(&[(2, 3), (3, 5)], 7);
+/// assert_eq!(dp, vec![0, 0, 3, 5, 6, 8, 10, 11]);
+/// ```
+pub fn knapsack_unbounded<T: Number + Ord>(items: &[(usize, T)], cap: usize) -> Vec<T> {
+    let mut dp = vec![T::zero(); cap + 1];
+    for &(w, v) in items {
+        for c in w..=cap {
+            dp[c] = dp[c].max(dp[c - w] + v);
+        }
+    }
+    dp
+}
+
+/// Bounded knapsack: items are `(weight, value, count)`, and each item can be
+/// taken at most `count` times.
+///
+/// Every item is split into `O(log count)` bundles of sizes `1, 2, 4, ...`
+/// (and the remainder), which are then used in the 0/1 knapsack.
+///
+/// # Example
+///
+/// ```
+/// use algorist::misc::knapsack::knapsack_bounded;
+///
+/// let dp = knapsack_bounded(&[(2, 3, 2), (5, 9, 1)], 10);
+/// assert_eq!(dp[10], 15);
+/// assert_eq!(dp[4], 6);
+/// ```
+pub fn knapsack_bounded<T: Number + Ord>(items: &[(usize, T, usize)], cap: usize) -> Vec<T> {
+    let mut bundles = Vec::new();
+    for &(w, v, mut cnt) in items {
+        // Values of the bundles of sizes `1, 2, 4, ...`, each twice the previous.
+        let mut pows: Vec<T> = Vec::new();
+        let mut k = 1;
+        while k <= cnt {
+            let value = pows.last().map_or(v, |&p| p + p);
+            bundles.push((w.saturating_mul(k), value));
+            pows.push(value);
+            cnt -= k;
+            k *= 2;
+        }
+        if cnt > 0 {
+            // The remainder is less than `k`, so it is a sum of the bundles above.
+            let value = (0..pows.len())
+                .filter(|&i| cnt >> i & 1 == 1)
+                .fold(T::zero(), |acc, i| acc + pows[i]);
+            bundles.push((w.saturating_mul(cnt), value));
+        }
+    }
+    knapsack_01(&bundles, cap)
+}
+
+/// Returns the set of total weights in `0..=cap`, reachable by subsets of
+/// `weights`, in `O(n * cap / 64)`.
+///
+/// # Example
+///
+/// ```
+/// use algorist::misc::knapsack::subset_sums_bitset;
+///
+/// let reach = subset_sums_bitset(&[3, 5], 10);
+/// assert_eq!(reach.iter_ones().collect::<Vec<_>>(), vec![0, 3, 5, 8]);
+/// ```
+pub fn subset_sums_bitset(weights: &[usize], cap: usize) -> BitSet {
+    let mut reach = BitSet::new(cap + 1);
+    reach.set(0, true);
+    for &w in weights {
+        if w <= cap {
+            reach.shl_or(w);
+        }
+    }
+    reach
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::misc::rng::Rng};
+
+    #[test]
+    fn test_against_naive() {
+        let mut rng = Rng::new(21);
+        for _ in 0..100 {
+            let n = rng.gen_range(0..6);
+            let items: Vec<(usize, i64, usize)> = (0..n)
+                .map(|_| {
+                    (
+                        rng.gen_range(1..6),
+                        rng.gen_range(0..10),
+                        rng.gen_range(0..4),
+                    )
+                })
+                .collect();
+            let cap = rng.gen_range(0..20);
+
+            // Brute force over the number of copies of each item.
+            let mut best = vec![0; cap + 1];
+            let mut cnt = vec![0; n];
+            loop {
+                let w: usize = (0..n).map(|i| items[i].0 * cnt[i]).sum();
+                let v: i64 = (0..n).map(|i| items[i].1 * cnt[i] as i64).sum();
+                if w <= cap {
+                    best[w..].iter_mut().for_each(|b| *b = (*b).max(v));
+                }
+                let Some(i) = (0..n).find(|&i| cnt[i] < items[i].2) else {
+                    break;
+                };
+                cnt[..i].fill(0);
+                cnt[i] += 1;
+            }
+            assert_eq!(knapsack_bounded(&items, cap), best);
+
+            let pairs: Vec<(usize, i64)> = items.iter().map(|&(w, v, _)| (w, v)).collect();
+            let ones: Vec<(usize, i64, usize)> = items.iter().map(|&(w, v, _)| (w, v, 1)).collect();
+            assert_eq!(knapsack_01(&pairs, cap), knapsack_bounded(&ones, cap));
+            let many: Vec<(usize, i64, usize)> =
+                items.iter().map(|&(w, v, _)| (w, v, 20)).collect();
+            assert_eq!(
+                knapsack_unbounded(&pairs, cap),
+                knapsack_bounded(&many, cap)
+            );
+
+            let weights: Vec<usize> = items.iter().map(|&(w, ..)| w).collect();
+            let bs = subset_sums_bitset(&weights, cap);
+            let all_reach: Vec<bool> = (0..=cap).map(|w| bs.get(w)).collect();
+            let mut want = vec![false; cap + 1];
+            for mask in 0..1usize << n {
+                let w: usize = (0..n)
+                    .filter(|&i| mask >> i & 1 == 1)
+                    .map(|i| weights[i])
+                    .sum();
+                if w <= cap {
+                    want[w] = true;
+                }
+            }
+            assert_eq!(all_reach, want);
+        }
+    }
+
+    #[test]
+    fn test_bounded_huge_count() {
+        let dp = knapsack_bounded(&[(1, 1, 2_000_000_000)], 10);
+        assert_eq!(dp, (0..=10).collect::<Vec<i64>>());
+        let dp: Vec<i64> =
+            knapsack_bounded(&[(3, 5, 1_000_000_000_000), (7, 12, 1_000_000_007)], 50);
+        assert_eq!(dp[50], 85);
+    }
+}
@@ -0,0 +1,117 @@
+//! Meet in the middle over subset sums.
+//!
+//! For `n` up to 40, enumerating all `2^n` subsets is too slow, but each half
+//! of the items has only `2^(n / 2)` subsets. Subset sums of both halves are
+//! generated (sorted) with [`subset_sums`], and then combined with
+//! [`count_pairs_with`], in linear time.
+//!
+//! ```
+//! use algorist::misc::mitm::{count_pairs_with, subset_sums};
+//!
+//! // Number of subsets with sum at most 10.
+//! let a = [3, 5, 6, 2, 7, 1];
+//! let (l, r) = a.split_at(a.len() / 2);
+//! let (l, r) = (subset_sums(l), subset_sums(r));
+//! assert_eq!(count_pairs_with(&l, &r, |s| s <= 10), 26);
+//!
+//! // Number of subsets with sum exactly 10: (sum <= 10) - (sum <= 9).
+//! let exact = count_pairs_with(&l, &r, |s| s <= 10) - count_pairs_with(&l, &r, |s| s <= 9);
+//! assert_eq!(exact, 4);
+//! ```
+
+/// Returns sums of all `2^n` subsets of `a` (including the empty one), in
+/// non-decreasing order, in `O(2^n)`.
+///
+/// # Panics
+///
+/// Panics if `a` has more than 30 elements.
+///
+/// # Example
+///
+/// ```
+/// use algorist::misc::mitm::subset_sums;
+///
+/// assert_eq!(subset_sums(&[3, -1, 2]), vec![-1, 0, 1, 2, 2, 3, 4, 5]);
+/// assert_eq!(subset_sums(&[]), vec![0]);
+/// ```
+pub fn subset_sums(a: &[i64]) -> Vec<i64> {
+    assert!(a.len() <= 30, "Too many elements: {}", a.len());
+    let mut res = Vec::with_capacity(1 << a.len());
+    res.push(0);
+    let mut merged = Vec::with_capacity(1 << a.len());
+    for &x in a {
+        // Merge the sorted sums with the same sums shifted by `x`.
+        merged.clear();
+        let (mut i, mut j) = (0, 0);
+        while i < res.len() || j < res.len() {
+            if j == res.len() || (i < res.len() && res[i] <= res[j] + x) {
+                merged.push(res[i]);
+                i += 1;
+            } else {
+                merged.push(res[j] + x);
+                j += 1;
+            }
+        }
+        std::mem::swap(&mut res, &mut merged);
+    }
+    res
+}
+
+/// Returns the number of pairs `(x, y)`, `x` from `a`, `y` from `b`, such that
+/// `pred(x + y)` holds, in `O(|a| + |b|)`.
+///
+/// Both slices must be sorted in non-decreasing order, and the predicate must
+/// be monotone: true for all sums up to some threshold, and false after it
+/// (e.g. `|s| s <= target`).
+///
+/// # Example
+///
+/// ```
+/// use algorist::misc::mitm::count_pairs_with;
+///
+/// assert_eq!(count_pairs_with(&[1, 2, 3], &[0, 10], |s| s < 3), 2);
+/// assert_eq!(count_pairs_with(&[1, 2, 3], &[0, 10], |s| s <= 100), 6);
+/// ```
+pub fn count_pairs_with<F: FnMut(i64) -> bool>(a: &[i64], b: &[i64], mut pred: F) -> u64 {
+    // For each `x` in increasing order, `b[..j]` are the matching `y`s.
+    let mut j = b.len();
+    let mut res = 0;
+    for &x in a {
+        while j > 0 && !pred(x + b[j - 1]) {
+            j -= 1;
+        }
+        res += j as u64;
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::misc::rng::Rng};
+
+    #[test]
+    fn test_against_naive() {
+        let mut rng = Rng::new(20);
+        for _ in 0..100 {
+            let n = rng.gen_range(0..12);
+            let a: Vec<i64> = (0..n).map(|_| rng.gen_range(-20..20)).collect();
+            let mut want: Vec<i64> = (0..1usize << n)
+                .map(|mask| (0..n).filter(|&i| mask >> i & 1 == 1).map(|i| a[i]).sum())
+                .collect();
+            want.sort();
+            assert_eq!(subset_sums(&a), want);
+
+            let (l, r) = a.split_at(rng.gen_range(0..=n));
+            let (l, r) = (subset_sums(l), subset_sums(r));
+            let t = rng.gen_range(-50..50);
+            let cnt = want.iter().filter(|&&s| s <= t).count() as u64;
+            assert_eq!(count_pairs_with(&l, &r, |s| s <= t), cnt);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Too many elements: 31")]
+    fn test_too_many() {
+        subset_sums(&[1; 31]);
+    }
+}
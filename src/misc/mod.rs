@@ -1,3 +1,19 @@
 //! Miscellaneous utilities.
 
 pub mod cards;
+pub mod chess;
+pub mod direction;
+pub mod dp;
+pub mod game;
+pub mod greedy;
+pub mod intervals;
+pub mod knapsack;
+pub mod mitm;
+pub mod recurse;
+pub mod rng;
+pub mod roman;
+pub mod search;
+pub mod state_bfs;
+pub mod thread;
+pub mod time;
+pub mod timer;
@@ -0,0 +1,103 @@
+//! Recursive closures.
+//!
+//! Closures can't call themselves, which forces recursive DFS or memoized
+//! searches out into free functions with long argument lists. [`recurse`]
+//! turns a closure, which receives "itself" as the first argument, into a
+//! callable recursive function:
+//!
+//! ```
+//! use algorist::misc::recurse::recurse;
+//!
+//! let fact = recurse(|fact, n: u64| if n == 0 { 1 } else { n * fact(n - 1) });
+//! assert_eq!(fact(10), 3_628_800);
+//! ```
+//!
+//! # Mutable state
+//!
+//! Captured variables can only be read, as the closure is `Fn`. For state,
+//! which is updated during the recursion (memo tables, visited flags, answer
+//! accumulators), use [`recurse_mut`], which threads a `&mut` state through
+//! the calls:
+//!
+//! ```
+//! use algorist::misc::recurse::recurse_mut;
+//!
+//! // Memoized number of paths in a DAG, from each vertex to the vertex 3.
+//! let adj = vec![vec![1, 2], vec![2, 3], vec![3], vec![]];
+//! let paths = recurse_mut(|paths, memo: &mut Vec<Option<u64>>, v: usize| {
+//!     if let Some(res) = memo[v] {
+//!         return res;
+//!     }
+//!     let res = if v == 3 {
+//!         1
+//!     } else {
+//!         adj[v].iter().map(|&u| paths(memo, u)).sum()
+//!     };
+//!     memo[v] = Some(res);
+//!     res
+//! });
+//! let mut memo = vec![None; 4];
+//! assert_eq!(paths(&mut memo, 0), 3);
+//! assert_eq!(memo[1], Some(2));
+//! ```
+
+/// Returns a function, which calls `f`, passing itself as the first argument
+/// to allow recursive calls.
+pub fn recurse<A, R, F>(f: F) -> impl Fn(A) -> R
+where
+    F: Fn(&dyn Fn(A) -> R, A) -> R,
+{
+    fn call<A, R, F>(f: &F, a: A) -> R
+    where
+        F: Fn(&dyn Fn(A) -> R, A) -> R,
+    {
+        f(&|a| call(f, a), a)
+    }
+    move |a| call(&f, a)
+}
+
+/// Same as [`recurse`], but the function also receives a mutable state,
+/// shared by all the recursive calls.
+pub fn recurse_mut<S, A, R, F>(f: F) -> impl Fn(&mut S, A) -> R
+where
+    F: Fn(&dyn Fn(&mut S, A) -> R, &mut S, A) -> R,
+{
+    fn call<S, A, R, F>(f: &F, s: &mut S, a: A) -> R
+    where
+        F: Fn(&dyn Fn(&mut S, A) -> R, &mut S, A) -> R,
+    {
+        f(&|s, a| call(f, s, a), s, a)
+    }
+    move |s, a| call(&f, s, a)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recurse() {
+        let fib = recurse(|fib, n: u32| if n < 2 { n } else { fib(n - 1) + fib(n - 2) });
+        assert_eq!(fib(20), 6765);
+
+        // Several arguments are passed as a tuple.
+        let gcd = recurse(|gcd, (a, b): (u64, u64)| if b == 0 { a } else { gcd((b, a % b)) });
+        assert_eq!(gcd((84, 36)), 12);
+    }
+
+    #[test]
+    fn test_recurse_mut() {
+        // Euler tour of a tree, given by children lists.
+        let children = [vec![1, 2], vec![3], vec![], vec![]];
+        let dfs = recurse_mut(|dfs, tour: &mut Vec<usize>, v: usize| {
+            tour.push(v);
+            for &u in &children[v] {
+                dfs(tour, u);
+                tour.push(v);
+            }
+        });
+        let mut tour = vec![];
+        dfs(&mut tour, 0);
+        assert_eq!(tour, vec![0, 1, 3, 1, 0, 2, 0]);
+    }
+}
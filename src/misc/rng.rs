@@ -0,0 +1,231 @@
+//! Pseudo-random number generator.
+//!
+//! Randomized algorithms (hashing with random salts, randomized checks,
+//! shuffling the input against anti-hash tests) need a fast generator, and
+//! the standard library doesn't provide one. [`Rng`] implements
+//! `xoshiro256**`, seeded with `splitmix64`.
+//!
+//! Use a fixed seed for reproducible stress tests, and [`Rng::from_time`] in
+//! submissions, so that the sequence can't be predicted by hackers.
+//!
+//! ```
+//! use algorist::misc::rng::Rng;
+//!
+//! let mut rng = Rng::new(42);
+//! let x = rng.gen_range(1..=6);
+//! assert!((1..=6).contains(&x));
+//!
+//! let mut v: Vec<u32> = (0..10).collect();
+//! rng.shuffle(&mut v);
+//! v.sort();
+//! assert_eq!(v, (0..10).collect::<Vec<_>>());
+//!
+//! // Same seed, same sequence.
+//! let (mut a, mut b) = (Rng::new(7), Rng::new(7));
+//! assert_eq!(a.next_u64(), b.next_u64());
+//! ```
+
+use std::{
+    ops::{Range, RangeInclusive},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// Pseudo-random number generator (`xoshiro256**`).
+///
+/// Not cryptographically secure.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: [u64; 4],
+}
+
+impl Rng {
+    /// Creates a generator with a fixed seed.
+    pub fn new(seed: u64) -> Self {
+        let mut sm = seed;
+        let mut state = [0; 4];
+        for s in &mut state {
+            *s = splitmix64(&mut sm);
+        }
+        Self { state }
+    }
+
+    /// Creates a generator seeded with the current time.
+    pub fn from_time() -> Self {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_nanos());
+        Self::new(nanos as u64 ^ (nanos >> 64) as u64)
+    }
+
+    /// Returns the next random `u64`, uniformly distributed.
+    pub fn next_u64(&mut self) -> u64 {
+        let s = &mut self.state;
+        let result = s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = s[1] << 17;
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+        result
+    }
+
+    /// Returns a random number in `[0, n)`, for `n <= 2^64`.
+    fn below(&mut self, n: u128) -> u128 {
+        // Multiply-shift: the bias is at most `n / 2^64`, negligible for all
+        // practical purposes.
+        (u128::from(self.next_u64()) * n) >> 64
+    }
+
+    /// Returns a random number in the given range (either `a..b` or `a..=b`),
+    /// for any primitive integer type up to 64 bits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::misc::rng::Rng;
+    ///
+    /// let mut rng = Rng::new(1);
+    /// for _ in 0..100 {
+    ///     assert!((-5..5).contains(&rng.gen_range(-5i64..5)));
+    ///     assert!(rng.gen_range(10usize..11) == 10);
+    /// }
+    /// let _full: u64 = rng.gen_range(0..=u64::MAX);
+    /// ```
+    pub fn gen_range<T, R: SampleRange<T>>(&mut self, range: R) -> T {
+        range.sample(self)
+    }
+
+    /// Returns a random `f64` in `[0, 1)`.
+    pub fn gen_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Shuffles the slice uniformly (Fisher–Yates).
+    pub fn shuffle<T>(&mut self, slice: &mut [T]) {
+        for i in (1..slice.len()).rev() {
+            let j = self.gen_range(0..=i);
+            slice.swap(i, j);
+        }
+    }
+
+    /// Returns a random element of the slice, or `None` if it is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::misc::rng::Rng;
+    ///
+    /// let mut rng = Rng::new(3);
+    /// assert!([1, 2, 3].contains(rng.choose(&[1, 2, 3]).unwrap()));
+    /// assert_eq!(rng.choose::<u8>(&[]), None);
+    /// ```
+    pub fn choose<'a, T>(&mut self, slice: &'a [T]) -> Option<&'a T> {
+        if slice.is_empty() {
+            None
+        } else {
+            Some(&slice[self.gen_range(0..slice.len())])
+        }
+    }
+}
+
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Range, a random value of type `T` can be sampled from, see
+/// [`Rng::gen_range`].
+pub trait SampleRange<T> {
+    fn sample(self, rng: &mut Rng) -> T;
+}
+
+macro_rules! sample_range_impl {
+    ($($t: ident)+) => {$(
+        impl SampleRange<$t> for Range<$t> {
+            fn sample(self, rng: &mut Rng) -> $t {
+                assert!(self.start < self.end, "Range must not be empty");
+                let span = (self.end as i128 - self.start as i128) as u128;
+                (self.start as i128 + rng.below(span) as i128) as $t
+            }
+        }
+
+        impl SampleRange<$t> for RangeInclusive<$t> {
+            fn sample(self, rng: &mut Rng) -> $t {
+                let (start, end) = self.into_inner();
+                assert!(start <= end, "Range must not be empty");
+                let span = (end as i128 - start as i128 + 1) as u128;
+                (start as i128 + rng.below(span) as i128) as $t
+            }
+        }
+    )+};
+}
+
+sample_range_impl!(i8 i16 i32 i64 isize u8 u16 u32 u64 usize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reference_sequence() {
+        // Reference values of xoshiro256** for the state [1, 2, 3, 4].
+        let mut rng = Rng {
+            state: [1, 2, 3, 4],
+        };
+        let got: Vec<u64> = (0..4).map(|_| rng.next_u64()).collect();
+        assert_eq!(got, vec![
+            11_520,
+            0,
+            1_509_978_240,
+            1_215_971_899_390_074_240
+        ]);
+    }
+
+    #[test]
+    fn test_distribution() {
+        let mut rng = Rng::new(12345);
+        let mut cnt = [0; 6];
+        for _ in 0..60_000 {
+            cnt[rng.gen_range(0..6usize)] += 1;
+        }
+        assert!(cnt.iter().all(|&c| (9_000..11_000).contains(&c)), "{cnt:?}");
+
+        for _ in 0..1_000 {
+            let x = rng.gen_range(i8::MIN..=i8::MAX);
+            assert!((i8::MIN..=i8::MAX).contains(&x));
+            assert!((-3..=-1).contains(&rng.gen_range(-3..=-1)));
+            let f = rng.gen_f64();
+            assert!((0.0..1.0).contains(&f));
+        }
+        assert_ne!(Rng::new(1).next_u64(), Rng::new(2).next_u64());
+    }
+
+    #[test]
+    fn test_shuffle() {
+        // All 6 permutations of 3 elements are roughly equally likely.
+        let mut rng = Rng::new(5);
+        let mut cnt = std::collections::HashMap::new();
+        for _ in 0..6_000 {
+            let mut v = [0, 1, 2];
+            rng.shuffle(&mut v);
+            *cnt.entry(v).or_insert(0) += 1;
+        }
+        assert_eq!(cnt.len(), 6);
+        assert!(cnt.values().all(|&c| (800..1_200).contains(&c)), "{cnt:?}");
+    }
+
+    #[test]
+    #[should_panic(expected = "Range must not be empty")]
+    fn test_empty_range() {
+        Rng::new(0).gen_range(5..5);
+    }
+}
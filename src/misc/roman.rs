@@ -0,0 +1,265 @@
+//! Roman numerals and numbers spelled in English words.
+//!
+//! ```
+//! use algorist::misc::roman::{digit_word, from_roman, to_roman, to_words};
+//!
+//! assert_eq!(to_roman(2024), "MMXXIV");
+//! assert_eq!(from_roman("MCMXCIV"), Some(1994));
+//! assert_eq!(digit_word(7), "seven");
+//! assert_eq!(to_words(1_000_042), "one million forty-two");
+//! ```
+
+/// Values of Roman numerals, including the subtractive pairs, from the largest.
+const ROMAN: [(u32, &str); 13] = [
+    (1000, "M"),
+    (900, "CM"),
+    (500, "D"),
+    (400, "CD"),
+    (100, "C"),
+    (90, "XC"),
+    (50, "L"),
+    (40, "XL"),
+    (10, "X"),
+    (9, "IX"),
+    (5, "V"),
+    (4, "IV"),
+    (1, "I"),
+];
+
+const ONES: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+const TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+const SCALES: [&str; 7] = [
+    "",
+    "thousand",
+    "million",
+    "billion",
+    "trillion",
+    "quadrillion",
+    "quintillion",
+];
+
+/// Returns the Roman numeral for `n`.
+///
+/// # Panics
+///
+/// Panics if `n` is not in `1..=3999`.
+///
+/// # Example
+///
+/// ```
+/// use algorist::misc::roman::to_roman;
+///
+/// assert_eq!(to_roman(4), "IV");
+/// assert_eq!(to_roman(3999), "MMMCMXCIX");
+/// ```
+pub fn to_roman(mut n: u32) -> String {
+    assert!(
+        (1..=3999).contains(&n),
+        "Number {n} can't be written in Roman numerals"
+    );
+    let mut res = String::new();
+    for &(value, numeral) in &ROMAN {
+        while n >= value {
+            res.push_str(numeral);
+            n -= value;
+        }
+    }
+    res
+}
+
+/// Parses a Roman numeral, or returns `None` if it is not a valid one.
+///
+/// Only numerals in the standard form are accepted, i.e. the ones returned by
+/// [`to_roman`]: `IIII` or `IC` are rejected.
+///
+/// # Example
+///
+/// ```
+/// use algorist::misc::roman::from_roman;
+///
+/// assert_eq!(from_roman("XLII"), Some(42));
+/// assert_eq!(from_roman("IIII"), None);
+/// assert_eq!(from_roman(""), None);
+/// ```
+pub fn from_roman(s: &str) -> Option<u32> {
+    let mut rest = s;
+    let mut n = 0;
+    for &(value, numeral) in &ROMAN {
+        while let Some(r) = rest.strip_prefix(numeral) {
+            n += value;
+            rest = r;
+        }
+    }
+    // The greedy parse accepts some non-standard forms (e.g. `IIII`), so the
+    // numeral is checked to be the canonical one.
+    ((1..=3999).contains(&n) && to_roman(n) == s).then_some(n)
+}
+
+/// Returns the English word for the digit `d`.
+///
+/// # Panics
+///
+/// Panics if `d` is not a digit, i.e. greater than 9.
+///
+/// # Example
+///
+/// ```
+/// use algorist::misc::roman::digit_word;
+///
+/// let words: Vec<_> = [2, 0, 5].into_iter().map(digit_word).collect();
+/// assert_eq!(words.join(" "), "two zero five");
+/// ```
+pub fn digit_word(d: u8) -> &'static str {
+    assert!(d < 10, "Not a digit: {d}");
+    ONES[d as usize]
+}
+
+/// Parses the English word for a digit, or returns `None` if `word` is not
+/// one (case is ignored).
+///
+/// # Example
+///
+/// ```
+/// use algorist::misc::roman::word_digit;
+///
+/// assert_eq!(word_digit("nine"), Some(9));
+/// assert_eq!(word_digit("Zero"), Some(0));
+/// assert_eq!(word_digit("ten"), None);
+/// ```
+pub fn word_digit(word: &str) -> Option<u8> {
+    ONES[..10]
+        .iter()
+        .position(|w| w.eq_ignore_ascii_case(word))
+        .map(|d| d as u8)
+}
+
+/// Returns `n`, spelled in English words, in the short scale and without
+/// "and" (e.g. `one hundred twenty-three`).
+///
+/// # Example
+///
+/// ```
+/// use algorist::misc::roman::to_words;
+///
+/// assert_eq!(to_words(0), "zero");
+/// assert_eq!(to_words(115), "one hundred fifteen");
+/// assert_eq!(to_words(7_000_801), "seven million eight hundred one");
+/// ```
+pub fn to_words(mut n: u64) -> String {
+    if n == 0 {
+        return ONES[0].to_string();
+    }
+    let mut groups = Vec::new();
+    for scale in SCALES {
+        if n % 1000 != 0 {
+            let group = below_thousand((n % 1000) as usize);
+            groups.push(if scale.is_empty() {
+                group
+            } else {
+                format!("{group} {scale}")
+            });
+        }
+        n /= 1000;
+    }
+    groups.reverse();
+    groups.join(" ")
+}
+
+/// Spells a number in `1..1000`.
+fn below_thousand(n: usize) -> String {
+    let mut words = Vec::new();
+    if n >= 100 {
+        words.push(format!("{} hundred", ONES[n / 100]));
+    }
+    match n % 100 {
+        0 => {}
+        r @ 1..=19 => words.push(ONES[r].to_string()),
+        r if r % 10 == 0 => words.push(TENS[r / 10].to_string()),
+        r => words.push(format!("{}-{}", TENS[r / 10], ONES[r % 10])),
+    }
+    words.join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roman_roundtrip() {
+        let mut seen = std::collections::HashSet::new();
+        for n in 1..=3999 {
+            let s = to_roman(n);
+            assert_eq!(from_roman(&s), Some(n), "{s}");
+            assert!(seen.insert(s));
+        }
+        for s in [
+            "IIII", "VV", "IC", "IL", "XM", "MMMM", "iv", "X I", "MCMC", "VX",
+        ] {
+            assert_eq!(from_roman(s), None, "{s}");
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Number 0 can't be written in Roman numerals")]
+    fn test_to_roman_zero() {
+        to_roman(0);
+    }
+
+    #[test]
+    fn test_digit_words() {
+        for d in 0..10 {
+            assert_eq!(word_digit(digit_word(d)), Some(d));
+        }
+        assert_eq!(word_digit("eleven"), None);
+        assert_eq!(word_digit(""), None);
+    }
+
+    #[test]
+    fn test_to_words() {
+        let cases = [
+            (7, "seven"),
+            (13, "thirteen"),
+            (40, "forty"),
+            (99, "ninety-nine"),
+            (100, "one hundred"),
+            (1_000, "one thousand"),
+            (1_010, "one thousand ten"),
+            (20_304, "twenty thousand three hundred four"),
+            (1_000_000_000, "one billion"),
+            (
+                u64::MAX,
+                "eighteen quintillion four hundred forty-six quadrillion seven hundred forty-four \
+                 trillion seventy-three billion seven hundred nine million five hundred fifty-one \
+                 thousand six hundred fifteen",
+            ),
+        ];
+        for (n, want) in cases {
+            assert_eq!(to_words(n), want);
+        }
+    }
+}
@@ -0,0 +1,244 @@
+//! Iterative deepening search.
+//!
+//! When the state space is too large to keep all visited states in memory
+//! (as [`state_bfs`](super::state_bfs) does), iterative deepening explores it
+//! with depth-first searches of increasing depth limit, using memory
+//! proportional to the depth of the solution only.
+//!
+//! [`ida_star`] additionally prunes states using an admissible heuristic
+//! (IDA*): a lower bound on the number of moves left to reach a goal. Since
+//! such searches may take unpredictably long, they run under a time budget.
+//!
+//! # Example
+//!
+//! Sort a permutation by reversing prefixes (pancake sorting). The number of
+//! adjacent elements, which are not neighbors in the sorted order, is a lower
+//! bound on the number of moves, since a reversal fixes at most one such
+//! "break".
+//!
+//! ```
+//! use {
+//!     algorist::misc::search::{Search, ida_star},
+//!     std::time::Duration,
+//! };
+//!
+//! let breaks = |p: &Vec<u8>| {
+//!     let mut p = p.clone();
+//!     p.push(p.len() as u8);
+//!     p.windows(2).filter(|w| w[0].abs_diff(w[1]) != 1).count()
+//! };
+//! let flips = |p: &Vec<u8>| {
+//!     (2..=p.len())
+//!         .map(|k| {
+//!             let mut q = p.clone();
+//!             q[..k].reverse();
+//!             q
+//!         })
+//!         .collect::<Vec<_>>()
+//! };
+//!
+//! let res = ida_star(vec![3, 1, 4, 0, 2], breaks, flips, Duration::from_secs(1));
+//! let Search::Found(path) = res else { panic!() };
+//! assert_eq!(path.len() - 1, 5); // number of moves
+//! assert_eq!(path.last(), Some(&vec![0, 1, 2, 3, 4]));
+//! ```
+//!
+//! Plain iterative deepening is IDA* with a heuristic, which is `0` for goal
+//! states and `1` otherwise.
+
+use std::time::{Duration, Instant};
+
+/// Outcome of [`ida_star`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Search<S> {
+    /// Path from the start to a goal (both inclusive), with the minimum
+    /// number of moves.
+    Found(Vec<S>),
+    /// The whole reachable space was searched, and no goal was found.
+    Exhausted,
+    /// The time budget ran out. The value is the depth limit, up to which
+    /// the space has been completely searched (no solution is shorter).
+    TimedOut(usize),
+}
+
+/// Result of a single depth-limited pass.
+enum Pass {
+    Found,
+    /// Minimum estimated cost, which exceeded the limit (`None` if nothing
+    /// exceeded it, i.e. the space is exhausted).
+    Exceeded(Option<usize>),
+    TimedOut,
+}
+
+/// How often (in visited states) the clock is checked.
+const CLOCK_PERIOD: usize = 1 << 10;
+
+struct Ida<S, H, N> {
+    h: H,
+    neighbors: N,
+    path: Vec<S>,
+    deadline: Option<Instant>,
+    visited: usize,
+}
+
+impl<S, H, N, I> Ida<S, H, N>
+where
+    S: PartialEq,
+    H: FnMut(&S) -> usize,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+{
+    fn dfs(&mut self, depth: usize, limit: usize) -> Pass {
+        self.visited += 1;
+        if self.visited % CLOCK_PERIOD == 0
+            && self
+                .deadline
+                .is_some_and(|deadline| Instant::now() >= deadline)
+        {
+            return Pass::TimedOut;
+        }
+
+        let cur = self.path.last().unwrap();
+        let h = (self.h)(cur);
+        if h == 0 {
+            return Pass::Found;
+        }
+        if depth + h > limit {
+            return Pass::Exceeded(Some(depth + h));
+        }
+
+        let mut min = None;
+        for next in (self.neighbors)(cur) {
+            // Avoid cycles along the current path.
+            if self.path.contains(&next) {
+                continue;
+            }
+            self.path.push(next);
+            match self.dfs(depth + 1, limit) {
+                Pass::Exceeded(Some(f)) => min = Some(min.map_or(f, |m: usize| m.min(f))),
+                Pass::Exceeded(None) => {}
+                found_or_timeout => return found_or_timeout,
+            }
+            self.path.pop();
+        }
+        Pass::Exceeded(min)
+    }
+}
+
+/// Finds the shortest path from `start` to a goal state, with IDA*.
+///
+/// The heuristic `h` must never overestimate the number of moves left, and
+/// must be `0` exactly at goal states. The `neighbors` function returns
+/// states reachable in one move. States already on the current path are
+/// skipped, but, unlike BFS, other states may be expanded many times.
+///
+/// The search stops after (roughly) `budget` time, see [`Search::TimedOut`].
+/// Pass [`Duration::MAX`] for no time limit.
+///
+/// # Example
+///
+/// ```
+/// use {
+///     algorist::misc::search::{Search, ida_star},
+///     std::time::Duration,
+/// };
+///
+/// // Reach 10 from 1 with moves `x + 1` and `x * 2`, plain iterative deepening.
+/// let h = |&x: &u32| usize::from(x != 10);
+/// let moves = |&x: &u32| [x + 1, x * 2].into_iter().filter(|&y| y <= 10);
+/// let res = ida_star(1, h, moves, Duration::MAX);
+/// assert_eq!(res, Search::Found(vec![1, 2, 4, 5, 10]));
+///
+/// // Unreachable goal.
+/// let res = ida_star(1, |&x: &u32| usize::from(x != 0), moves, Duration::MAX);
+/// assert_eq!(res, Search::Exhausted);
+/// ```
+pub fn ida_star<S, H, N, I>(start: S, h: H, neighbors: N, budget: Duration) -> Search<S>
+where
+    S: PartialEq,
+    H: FnMut(&S) -> usize,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+{
+    let mut ida = Ida {
+        h,
+        neighbors,
+        path: vec![start],
+        deadline: Instant::now().checked_add(budget),
+        visited: 0,
+    };
+    let mut limit = (ida.h)(&ida.path[0]);
+    loop {
+        match ida.dfs(0, limit) {
+            Pass::Found => return Search::Found(ida.path),
+            Pass::Exceeded(Some(next)) => limit = next,
+            Pass::Exceeded(None) => return Search::Exhausted,
+            Pass::TimedOut => return Search::TimedOut(limit.saturating_sub(1)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::misc::state_bfs::bfs};
+
+    /// 8-puzzle: `0` is the blank, goal is `1..=8` followed by the blank.
+    fn manhattan(board: &[u8; 9]) -> usize {
+        board
+            .iter()
+            .enumerate()
+            .filter(|&(_, &t)| t != 0)
+            .map(|(i, &t)| {
+                let j = usize::from(t) - 1;
+                (i / 3).abs_diff(j / 3) + (i % 3).abs_diff(j % 3)
+            })
+            .sum()
+    }
+
+    fn moves(board: &[u8; 9]) -> Vec<[u8; 9]> {
+        let z = board.iter().position(|&t| t == 0).unwrap();
+        let (r, c) = (z / 3, z % 3);
+        let mut res = vec![];
+        for (dr, dc) in [(0, 1), (1, 0), (0, -1), (-1, 0)] {
+            let (nr, nc) = (r as i32 + dr, c as i32 + dc);
+            if (0..3).contains(&nr) && (0..3).contains(&nc) {
+                let mut next = *board;
+                next.swap(z, (nr * 3 + nc) as usize);
+                res.push(next);
+            }
+        }
+        res
+    }
+
+    #[test]
+    fn test_eight_puzzle() {
+        let goal = [1, 2, 3, 4, 5, 6, 7, 8, 0];
+        for start in [
+            [1, 2, 3, 4, 5, 6, 7, 8, 0],
+            [1, 2, 3, 4, 5, 6, 0, 7, 8],
+            [4, 1, 3, 7, 2, 6, 0, 5, 8],
+            [8, 1, 3, 4, 0, 2, 7, 6, 5],
+        ] {
+            let want = bfs(start, moves, |b| *b == goal).dist[&goal];
+            let Search::Found(path) = ida_star(start, manhattan, moves, Duration::MAX) else {
+                panic!("Solution must exist");
+            };
+            assert_eq!(path.len() - 1, want);
+            assert_eq!(path[0], start);
+            assert_eq!(path.last(), Some(&goal));
+            for w in path.windows(2) {
+                assert!(moves(&w[0]).contains(&w[1]));
+            }
+        }
+    }
+
+    #[test]
+    fn test_timeout() {
+        // Unsolvable configuration (odd permutation), with no time to search.
+        let start = [2, 1, 3, 4, 5, 6, 7, 8, 0];
+        match ida_star(start, manhattan, moves, Duration::ZERO) {
+            Search::TimedOut(limit) => assert!(limit >= 1),
+            res => panic!("Unexpected result: {res:?}"),
+        }
+    }
+}
@@ -0,0 +1,189 @@
+//! Breadth-first search over an implicit state space.
+//!
+//! Puzzle problems ("minimum number of moves to sort the pancakes", "jugs of
+//! water", "sliding tiles") are shortest paths in a graph, which is never
+//! built explicitly: states are generated on the fly. [`bfs`] packages the
+//! usual queue and visited set boilerplate, for any `Hash + Eq` state.
+//!
+//! # Example
+//!
+//! Two jugs of 3 and 5 liters, measure exactly 4 liters:
+//!
+//! ```
+//! use algorist::misc::state_bfs::bfs;
+//!
+//! let (ca, cb) = (3, 5);
+//! let res = bfs(
+//!     (0, 0),
+//!     |&(a, b)| {
+//!         let pour_ab = a.min(cb - b);
+//!         let pour_ba = b.min(ca - a);
+//!         [
+//!             (ca, b),
+//!             (a, cb),
+//!             (0, b),
+//!             (a, 0),
+//!             (a - pour_ab, b + pour_ab),
+//!             (a + pour_ba, b - pour_ba),
+//!         ]
+//!     },
+//!     |&(_, b)| b == 4,
+//! );
+//!
+//! let goal = res.goal.unwrap();
+//! assert_eq!(goal, (3, 4));
+//! assert_eq!(res.dist[&goal], 6);
+//! let path = res.path(&goal).unwrap();
+//! assert_eq!(path.first(), Some(&(0, 0)));
+//! assert_eq!(path.len(), 7);
+//! ```
+
+use std::{
+    collections::{HashMap, VecDeque},
+    hash::Hash,
+};
+
+/// Result of the [`bfs`].
+#[derive(Debug, Clone)]
+pub struct BfsResult<S> {
+    /// The first goal state reached (thus, the closest one), or `None` if no
+    /// goal is reachable.
+    pub goal: Option<S>,
+    /// Distances from the start, for all the discovered states.
+    pub dist: HashMap<S, usize>,
+    /// Predecessor on some shortest path, for all discovered states except
+    /// the start.
+    pub prev: HashMap<S, S>,
+}
+
+impl<S: Hash + Eq + Clone> BfsResult<S> {
+    /// Returns the shortest path from the start to the `state` (both
+    /// inclusive), or `None` if the state hasn't been discovered.
+    pub fn path(&self, state: &S) -> Option<Vec<S>> {
+        if !self.dist.contains_key(state) {
+            return None;
+        }
+        let mut path = vec![state.clone()];
+        let mut cur = state;
+        while let Some(p) = self.prev.get(cur) {
+            path.push(p.clone());
+            cur = p;
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Runs breadth-first search from `start`, until a state satisfying
+/// `is_goal` is discovered, or all reachable states are exhausted.
+///
+/// The `neighbors` function returns states reachable in one move. Each state
+/// is expanded at most once, so the search runs in `O(V + E)` hash map
+/// operations, over the discovered states only.
+///
+/// To explore the whole reachable space (e.g. to get all distances), pass
+/// `|_| false` as the goal.
+///
+/// # Example
+///
+/// ```
+/// use algorist::misc::state_bfs::bfs;
+///
+/// // Reach 10 from 1 with moves `x + 1` and `x * 3`.
+/// let res = bfs(1u32, |&x| [x + 1, x * 3], |&x| x == 10);
+/// assert_eq!(res.path(&10), Some(vec![1, 3, 9, 10]));
+///
+/// // All reachable states, bounded by 20.
+/// let res = bfs(
+///     1u32,
+///     |&x| [x + 1, x * 3].into_iter().filter(|&y| y <= 20),
+///     |_| false,
+/// );
+/// assert_eq!(res.goal, None);
+/// assert_eq!(res.dist.len(), 20);
+/// assert_eq!(res.dist[&20], 5); // 1 -> 2 -> 6 -> 18 -> 19 -> 20
+/// ```
+pub fn bfs<S, I, N, G>(start: S, mut neighbors: N, mut is_goal: G) -> BfsResult<S>
+where
+    S: Hash + Eq + Clone,
+    I: IntoIterator<Item = S>,
+    N: FnMut(&S) -> I,
+    G: FnMut(&S) -> bool,
+{
+    let mut res = BfsResult {
+        goal: None,
+        dist: HashMap::from([(start.clone(), 0)]),
+        prev: HashMap::new(),
+    };
+    if is_goal(&start) {
+        res.goal = Some(start);
+        return res;
+    }
+
+    let mut queue = VecDeque::from([start]);
+    while let Some(cur) = queue.pop_front() {
+        let d = res.dist[&cur] + 1;
+        for next in neighbors(&cur) {
+            if res.dist.contains_key(&next) {
+                continue;
+            }
+            res.dist.insert(next.clone(), d);
+            res.prev.insert(next.clone(), cur.clone());
+            if is_goal(&next) {
+                res.goal = Some(next);
+                return res;
+            }
+            queue.push_back(next);
+        }
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grid() {
+        let grid = ["S.#.....", "..#.##.#", "....#..E", "##.##.#."];
+        let cell = |r: i32, c: i32| {
+            grid.get(r as usize)
+                .and_then(|row| row.as_bytes().get(c as usize))
+                .copied()
+                .filter(|&b| b != b'#' && r >= 0 && c >= 0)
+        };
+        let res = bfs(
+            (0, 0),
+            |&(r, c)| {
+                [(r + 1, c), (r - 1, c), (r, c + 1), (r, c - 1)]
+                    .into_iter()
+                    .filter(|&(r, c)| cell(r, c).is_some())
+            },
+            |&(r, c)| cell(r, c) == Some(b'E'),
+        );
+        let goal = res.goal.unwrap();
+        assert_eq!(goal, (2, 7));
+        assert_eq!(res.dist[&goal], 13);
+
+        let path = res.path(&goal).unwrap();
+        assert_eq!(path.len(), 14);
+        for (i, w) in path.windows(2).enumerate() {
+            let ((r1, c1), (r2, c2)) = (w[0], w[1]);
+            assert_eq!((r1 - r2).abs() + (c1 - c2).abs(), 1);
+            assert_eq!(res.dist[&w[1]], i + 1);
+        }
+        assert_eq!(res.path(&(0, 2)), None);
+    }
+
+    #[test]
+    fn test_unreachable_and_start_goal() {
+        let res = bfs(0u8, |&x| [x / 2], |&x| x == 1);
+        assert_eq!(res.goal, None);
+        assert_eq!(res.dist.len(), 1);
+        assert_eq!(res.path(&0), Some(vec![0]));
+
+        let res = bfs("start".to_string(), |_| Vec::new(), |s| s == "start");
+        assert_eq!(res.goal.as_deref(), Some("start"));
+        assert!(res.prev.is_empty());
+    }
+}
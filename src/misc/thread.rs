@@ -0,0 +1,88 @@
+//! Running code with a large stack.
+//!
+//! The main thread normally has a stack of 8 MB (and only 1 MB on Windows),
+//! which a recursive DFS over a path of `10^5..10^6` vertices easily
+//! overflows. Spawning the solution in a thread with a larger stack is the
+//! simplest fix, and doesn't require rewriting recursion into loops.
+//!
+//! ```
+//! use algorist::misc::thread::with_large_stack;
+//!
+//! fn depth(n: u64) -> u64 {
+//!     if n == 0 { 0 } else { 1 + depth(n - 1) }
+//! }
+//!
+//! assert_eq!(with_large_stack(|| depth(1_000_000)), 1_000_000);
+//! ```
+//!
+//! To run all test cases with a large stack, see
+//! [`io::test_cases_with_stack`](crate::io::test_cases_with_stack).
+
+use std::{panic, thread};
+
+/// Default stack size of [`with_large_stack`]: 256 MB.
+pub const DEFAULT_STACK_SIZE: usize = 256 << 20;
+
+/// Runs `f` in a thread with a stack of [`DEFAULT_STACK_SIZE`], and returns
+/// its result.
+///
+/// # Panics
+///
+/// Panics if `f` panics (with the same payload), or if the thread can't be
+/// spawned.
+pub fn with_large_stack<R: Send, F: FnOnce() -> R + Send>(f: F) -> R {
+    with_stack_size(DEFAULT_STACK_SIZE, f)
+}
+
+/// Runs `f` in a thread with a stack of `size` bytes, and returns its result.
+///
+/// The closure may borrow local variables, as the thread is scoped.
+///
+/// # Panics
+///
+/// Panics if `f` panics (with the same payload), or if the thread can't be
+/// spawned.
+///
+/// # Example
+///
+/// ```
+/// use algorist::misc::thread::with_stack_size;
+///
+/// let v = vec![1, 2, 3];
+/// let sum = with_stack_size(64 << 20, || v.iter().sum::<i32>());
+/// assert_eq!(sum, 6);
+/// ```
+pub fn with_stack_size<R: Send, F: FnOnce() -> R + Send>(size: usize, f: F) -> R {
+    thread::scope(|s| {
+        let handle = thread::Builder::new()
+            .stack_size(size)
+            .spawn_scoped(s, f)
+            .expect("Failed to spawn a thread");
+        handle
+            .join()
+            .unwrap_or_else(|payload| panic::resume_unwind(payload))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deep_recursion() {
+        fn sum(v: &[u64]) -> u64 {
+            match v.split_first() {
+                Some((x, rest)) => x + sum(rest),
+                None => 0,
+            }
+        }
+        let v: Vec<u64> = (0..1_000_000).collect();
+        assert_eq!(with_large_stack(|| sum(&v)), 499_999_500_000);
+    }
+
+    #[test]
+    #[should_panic(expected = "Inner panic")]
+    fn test_panic_propagation() {
+        with_stack_size(1 << 20, || panic!("Inner panic"));
+    }
+}
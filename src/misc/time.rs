@@ -0,0 +1,199 @@
+//! Time of day parsing and arithmetic.
+//!
+//! Many ad-hoc problems ask "what time will it be", with times given as
+//! `hh:mm:ss` (or `hh:mm`). [`Time`] stores the time of day as seconds since
+//! midnight, and all the arithmetic wraps around at 24 hours.
+//!
+//! # Example
+//!
+//! ```
+//! use algorist::misc::time::{Time, parse_hms};
+//!
+//! let t = parse_hms("23:59:30");
+//! assert_eq!(t.secs(), 86_370);
+//! assert_eq!(t.add_secs(45).to_string(), "00:00:15");
+//! assert_eq!(t.add_secs(-86_400), t);
+//!
+//! // Seconds from 22:00 until 01:30 next day.
+//! let start: Time = "22:00".parse().unwrap();
+//! let end: Time = "01:30".parse().unwrap();
+//! assert_eq!(end.since(start), 3 * 3600 + 30 * 60);
+//! ```
+//!
+//! Since [`Time`] implements [`FromStr`], it can be read with
+//! [`Scanner`](crate::io::Scanner) directly: `let t: Time = scan.next();`.
+
+use std::{fmt, str::FromStr};
+
+/// Number of seconds in a day.
+pub const DAY: i64 = 24 * 60 * 60;
+
+/// Time of day, with a one second precision.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Time(u32);
+
+impl Time {
+    /// Creates a time from hours, minutes and seconds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if some component is out of range.
+    pub fn new(h: u32, m: u32, s: u32) -> Self {
+        assert!(h < 24 && m < 60 && s < 60, "Invalid time: {h}:{m}:{s}");
+        Self(h * 3600 + m * 60 + s)
+    }
+
+    /// Creates a time from the number of seconds since midnight, wrapping
+    /// around at 24 hours (negative values count back from midnight).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::misc::time::Time;
+    ///
+    /// assert_eq!(Time::from_secs(3661), Time::new(1, 1, 1));
+    /// assert_eq!(Time::from_secs(86_400 + 60), Time::new(0, 1, 0));
+    /// assert_eq!(Time::from_secs(-1), Time::new(23, 59, 59));
+    /// ```
+    pub fn from_secs(secs: i64) -> Self {
+        Self(secs.rem_euclid(DAY) as u32)
+    }
+
+    /// Returns the number of seconds since midnight.
+    pub fn secs(&self) -> u32 {
+        self.0
+    }
+
+    /// Returns hours, minutes and seconds.
+    pub fn hms(&self) -> (u32, u32, u32) {
+        (self.0 / 3600, self.0 / 60 % 60, self.0 % 60)
+    }
+
+    /// Returns the time, `secs` seconds later (or earlier, if negative),
+    /// wrapping around at 24 hours.
+    #[must_use]
+    pub fn add_secs(self, secs: i64) -> Self {
+        Self::from_secs(i64::from(self.0) + secs)
+    }
+
+    /// Returns the number of seconds, in `[0, 24h)`, to go forward from
+    /// `earlier` to reach this time (crossing midnight, if necessary).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::misc::time::Time;
+    ///
+    /// let (a, b) = (Time::new(10, 0, 0), Time::new(9, 0, 0));
+    /// assert_eq!(a.since(b), 3600);
+    /// assert_eq!(b.since(a), 23 * 3600);
+    /// assert_eq!(a.since(a), 0);
+    /// ```
+    pub fn since(&self, earlier: Self) -> u32 {
+        Self::from_secs(i64::from(self.0) - i64::from(earlier.0)).0
+    }
+}
+
+/// Error returned when the time can't be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseTimeError;
+
+impl FromStr for Time {
+    type Err = ParseTimeError;
+
+    /// Parses `hh:mm:ss` or `hh:mm` time. Hours may have a single digit.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = [0; 3];
+        let mut cnt = 0;
+        for part in s.split(':') {
+            if cnt == 3 || part.is_empty() || part.len() > 2 {
+                return Err(ParseTimeError);
+            }
+            parts[cnt] = part.parse().map_err(|_| ParseTimeError)?;
+            cnt += 1;
+        }
+        let [h, m, s] = parts;
+        if cnt < 2 || h >= 24 || m >= 60 || s >= 60 {
+            return Err(ParseTimeError);
+        }
+        Ok(Self::new(h, m, s))
+    }
+}
+
+impl fmt::Display for Time {
+    /// Formats the time as `hh:mm:ss`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (h, m, s) = self.hms();
+        write!(f, "{h:02}:{m:02}:{s:02}")
+    }
+}
+
+/// Parses time given as `hh:mm:ss` (or `hh:mm`).
+///
+/// # Panics
+///
+/// Panics if the string is not a valid time.
+///
+/// # Example
+///
+/// ```
+/// use algorist::misc::time::{Time, parse_hms};
+///
+/// assert_eq!(parse_hms("07:05:09"), Time::new(7, 5, 9));
+/// assert_eq!(parse_hms("7:05"), Time::new(7, 5, 0));
+/// assert_eq!(parse_hms("12:34:56").hms(), (12, 34, 56));
+/// ```
+pub fn parse_hms(s: &str) -> Time {
+    s.parse().unwrap_or_else(|_| panic!("Invalid time: {s}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::io::Scanner};
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse_hms("00:00:00").secs(), 0);
+        assert_eq!(parse_hms("23:59:59").secs(), 86_399);
+        assert_eq!(parse_hms("1:2").secs(), 3720);
+        for s in [
+            "24:00:00",
+            "12:60",
+            "12:00:60",
+            "12",
+            "12:00:00:00",
+            "1::2",
+            "a:b",
+            "123:00",
+            "",
+        ] {
+            assert_eq!(s.parse::<Time>(), Err(ParseTimeError), "{s}");
+        }
+
+        let mut scan = Scanner::new(b"08:30:00 9:15\n".as_ref());
+        let (a, b): (Time, Time) = scan.pair();
+        assert_eq!(b.since(a), 45 * 60);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        for secs in (0..DAY).step_by(997) {
+            let t = Time::from_secs(secs);
+            let (h, m, s) = t.hms();
+            assert_eq!(Time::new(h, m, s), t);
+            assert_eq!(parse_hms(&t.to_string()), t);
+
+            for delta in [-3 * DAY, -DAY - 1, -1, 0, 1, 12345, DAY, 5 * DAY + 7] {
+                let u = t.add_secs(delta);
+                assert_eq!(i64::from(u.since(t)), delta.rem_euclid(DAY));
+                assert_eq!(u.add_secs(-delta), t);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid time: 25:00")]
+    fn test_parse_invalid() {
+        parse_hms("25:00");
+    }
+}
@@ -0,0 +1,104 @@
+//! Wall-clock timer, for solutions which run "while time remains".
+//!
+//! Randomized searches, simulated annealing and iterative deepening improve
+//! the answer for as long as they are allowed to run. [`Timer`] measures the
+//! time since its creation, so that such loops stop safely before the time
+//! limit.
+//!
+//! ```
+//! use algorist::misc::timer::Timer;
+//!
+//! let timer = Timer::new();
+//! let mut iterations = 0;
+//! // Run for 50 ms (e.g. use 1900 for a 2 seconds limit, leaving a margin).
+//! while timer.time_left(50) > 0 {
+//!     iterations += 1;
+//! }
+//! assert!(iterations > 0);
+//! assert!(timer.elapsed_ms() >= 50);
+//! ```
+
+use std::time::Instant;
+
+/// Timer, started at creation, see the [module documentation](self).
+#[derive(Debug, Clone, Copy)]
+pub struct Timer {
+    start: Instant,
+}
+
+impl Default for Timer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Timer {
+    /// Creates a timer, starting now.
+    pub fn new() -> Self {
+        Self {
+            start: Instant::now(),
+        }
+    }
+
+    /// Restarts the timer.
+    pub fn reset(&mut self) {
+        self.start = Instant::now();
+    }
+
+    /// Returns the number of milliseconds since the start.
+    pub fn elapsed_ms(&self) -> u64 {
+        self.start.elapsed().as_millis() as u64
+    }
+
+    /// Returns the number of milliseconds remaining until `limit_ms` since the
+    /// start (zero, if the limit has passed).
+    pub fn time_left(&self, limit_ms: u64) -> u64 {
+        limit_ms.saturating_sub(self.elapsed_ms())
+    }
+
+    /// Checks, in debug builds only, that no more than `limit_ms` milliseconds
+    /// have passed since the start. Useful to catch slow parts locally, at no
+    /// cost in the submission.
+    ///
+    /// # Panics
+    ///
+    /// Panics (in debug builds) if the limit has been exceeded.
+    pub fn assert_under(&self, limit_ms: u64) {
+        if cfg!(debug_assertions) {
+            let elapsed = self.elapsed_ms();
+            assert!(
+                elapsed <= limit_ms,
+                "Time limit of {limit_ms} ms exceeded: {elapsed} ms"
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        std::{thread, time::Duration},
+    };
+
+    #[test]
+    fn test_timer() {
+        let mut timer = Timer::new();
+        timer.assert_under(10_000);
+        thread::sleep(Duration::from_millis(20));
+        assert!(timer.elapsed_ms() >= 20);
+        assert!(timer.time_left(10) == 0);
+        assert!(timer.time_left(10_000) > 0);
+        timer.reset();
+        assert!(timer.elapsed_ms() < 20);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Time limit of 1 ms exceeded")]
+    fn test_assert_under() {
+        let timer = Timer::new();
+        thread::sleep(Duration::from_millis(5));
+        timer.assert_under(1);
+    }
+}
@@ -0,0 +1,49 @@
+//! The most frequently used items, importable with a single line.
+//!
+//! Re-exports the input/output helpers, common extension traits and basic
+//! math items, which most of the solutions need:
+//!
+//! ```
+//! use algorist::prelude::*;
+//!
+//! let mut scan = Scanner::new("5\n3 1 4 1 5".as_bytes());
+//! let mut out = Vec::new();
+//! let mut w = Writer::new(&mut out);
+//!
+//! let n = scan.u();
+//! let a = scan.vec::<i64>(n).sorted();
+//! let diffs = a.iter().sliding_window().filter(|&(x, y)| x != y).count();
+//! let mut best = i64::MAX;
+//! best.chmin(a[a.lower_bound(&2)]);
+//!
+//! wln!(w, "{diffs} {best} {}", gcd(12, 18));
+//! wln!(w, "{}", Mod7::new(-1));
+//! drop(w);
+//! assert_eq!(out, b"3 3 6\n1000000006\n");
+//! ```
+//!
+//! The prelude only consists of `pub use` items, so bundling a solution, which
+//! imports it, pulls in the re-exported modules, as with direct imports.
+//! Items, which are not listed here, still have to be imported from their
+//! modules.
+
+pub use crate::{
+    ext::{
+        bits::BitOps,
+        iter::{
+            chunks::ChunksExt,
+            counts::CountsExt,
+            run_length::RunLengthExt,
+            window::SlidingWindowExt,
+        },
+        ord::MinMaxUpdate,
+        slice::{bounds::Bounds, prefix::PrefixSums},
+        vec::{argsort::Argsort, sorted::Sorted},
+    },
+    io::{Scanner, Writer, dbgln, test_case, test_cases, wln, wvln},
+    math::{
+        arith::{ceil_div, floor_div},
+        gcd::{gcd, lcm},
+        modulo::{Mod7, Modulo},
+    },
+};
@@ -0,0 +1,224 @@
+//! Classic quadratic DPs over pairs of strings: edit distance and the longest
+//! common subsequence.
+//!
+//! [`edit_distance`] and [`lcs_length`] run in `O(nm)` time and `O(m)` memory,
+//! [`lcs`] also restores the subsequence (using `O(nm)` memory). For long
+//! strings, [`lcs_length_bitset`] computes the length in `O(nm / 64)`.
+//!
+//! ```
+//! use algorist::strings::dp::{edit_distance, lcs, lcs_length, lcs_length_bitset};
+//!
+//! assert_eq!(edit_distance(b"kitten", b"sitting"), 3);
+//! assert_eq!(lcs_length(b"abcbdab", b"bdcaba"), 4);
+//! assert_eq!(lcs_length_bitset(b"abcbdab", b"bdcaba"), 4);
+//!
+//! let (a, b) = (b"abcbdab", b"bdcaba");
+//! let common: Vec<u8> = lcs(a, b).into_iter().map(|(i, _)| a[i]).collect();
+//! assert_eq!(common.len(), 4);
+//! ```
+
+use {
+    crate::collections::bitset::BitSet,
+    std::{collections::HashMap, hash::Hash},
+};
+
+/// Returns the Levenshtein distance between `a` and `b`: the minimum number
+/// of single element insertions, deletions and substitutions, turning `a`
+/// into `b`.
+///
+/// # Example
+///
+/// ```
+/// use algorist::strings::dp::edit_distance;
+///
+/// assert_eq!(edit_distance(b"flaw", b"lawn"), 2);
+/// assert_eq!(edit_distance(b"", b"abc"), 3);
+/// assert_eq!(edit_distance(&[1, 2, 3], &[1, 2, 3]), 0);
+/// ```
+pub fn edit_distance<T: Eq>(a: &[T], b: &[T]) -> usize {
+    // `dp[j]` is the distance between the current prefix of `a` and `b[..j]`.
+    let mut dp: Vec<usize> = (0..=b.len()).collect();
+    for (i, x) in a.iter().enumerate() {
+        // `diag` is the distance between `a[..i]` and `b[..j]`.
+        let mut diag = dp[0];
+        dp[0] = i + 1;
+        for (j, y) in b.iter().enumerate() {
+            let sub = diag + usize::from(x != y);
+            diag = dp[j + 1];
+            dp[j + 1] = sub.min(dp[j] + 1).min(dp[j + 1] + 1);
+        }
+    }
+    dp[b.len()]
+}
+
+/// Returns the length of the longest common subsequence of `a` and `b`.
+///
+/// # Example
+///
+/// ```
+/// use algorist::strings::dp::lcs_length;
+///
+/// assert_eq!(lcs_length(b"abcde", b"ace"), 3);
+/// assert_eq!(lcs_length(b"abc", b"def"), 0);
+/// ```
+pub fn lcs_length<T: Eq>(a: &[T], b: &[T]) -> usize {
+    let mut dp = vec![0; b.len() + 1];
+    for x in a {
+        let mut diag = 0;
+        for (j, y) in b.iter().enumerate() {
+            let cur = if x == y {
+                diag + 1
+            } else {
+                dp[j].max(dp[j + 1])
+            };
+            diag = dp[j + 1];
+            dp[j + 1] = cur;
+        }
+    }
+    dp[b.len()]
+}
+
+/// Returns a longest common subsequence of `a` and `b`, as pairs of matched
+/// indexes `(i, j)` (with `a[i] == b[j]`), increasing in both components.
+///
+/// # Example
+///
+/// ```
+/// use algorist::strings::dp::lcs;
+///
+/// assert_eq!(lcs(b"xaybz", b"abc"), vec![(1, 0), (3, 1)]);
+/// assert!(lcs(b"abc", b"").is_empty());
+/// ```
+pub fn lcs<T: Eq>(a: &[T], b: &[T]) -> Vec<(usize, usize)> {
+    let (n, m) = (a.len(), b.len());
+    // `dp[i][j]` is the LCS length of suffixes `a[i..]` and `b[j..]`.
+    let mut dp = vec![vec![0; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            dp[i][j] = if a[i] == b[j] {
+                dp[i + 1][j + 1] + 1
+            } else {
+                dp[i + 1][j].max(dp[i][j + 1])
+            };
+        }
+    }
+    let mut res = Vec::with_capacity(dp[0][0]);
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if a[i] == b[j] {
+            res.push((i, j));
+            (i, j) = (i + 1, j + 1);
+        } else if dp[i + 1][j] >= dp[i][j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    res
+}
+
+/// Returns the length of the longest common subsequence of `a` and `b`, in
+/// `O(nm / 64)` time and `O(n * k / 64)` memory, where `k` is the number of
+/// distinct elements of `a`.
+///
+/// The bit-parallel algorithm (Allison and Dix, Crochemore et al.) keeps a
+/// row of the DP over `a` as a [`BitSet`] of positions, where the DP value
+/// increases.
+///
+/// # Example
+///
+/// ```
+/// use algorist::strings::dp::lcs_length_bitset;
+///
+/// let a = b"ab".repeat(1000);
+/// let b = b"ba".repeat(700);
+/// assert_eq!(lcs_length_bitset(&a, &b), 1400);
+/// ```
+pub fn lcs_length_bitset<T: Eq + Hash>(a: &[T], b: &[T]) -> usize {
+    let n = a.len();
+    let mut masks: HashMap<&T, BitSet> = HashMap::new();
+    for (i, x) in a.iter().enumerate() {
+        masks
+            .entry(x)
+            .or_insert_with(|| BitSet::new(n))
+            .set(i, true);
+    }
+    let mut row = BitSet::new(n);
+    for y in b {
+        let Some(mask) = masks.get(y) else {
+            continue;
+        };
+        // row = x & !(x - ((row << 1) | 1)), where x = row | mask
+        let (mut carry, mut borrow) = (1, false);
+        for (r, &m) in row.words_mut().iter_mut().zip(mask.words()) {
+            let x = *r | m;
+            let y = *r << 1 | carry;
+            carry = *r >> 63;
+            let (d, b1) = x.overflowing_sub(y);
+            let (d, b2) = d.overflowing_sub(u64::from(borrow));
+            borrow = b1 || b2;
+            *r = x & !d;
+        }
+    }
+    row.count_ones()
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::misc::rng::Rng};
+
+    fn naive_edit_distance(a: &[u8], b: &[u8]) -> usize {
+        if a.is_empty() || b.is_empty() {
+            return a.len() + b.len();
+        }
+        let sub = naive_edit_distance(&a[1..], &b[1..]) + usize::from(a[0] != b[0]);
+        let del = naive_edit_distance(&a[1..], b) + 1;
+        let ins = naive_edit_distance(a, &b[1..]) + 1;
+        sub.min(del).min(ins)
+    }
+
+    fn random_string(rng: &mut Rng, len: std::ops::Range<usize>, k: u8) -> Vec<u8> {
+        let n = rng.gen_range(len);
+        (0..n).map(|_| rng.gen_range(0..k)).collect()
+    }
+
+    #[test]
+    fn test_against_naive() {
+        let mut rng = Rng::new(11);
+        for _ in 0..300 {
+            let a = random_string(&mut rng, 0..7, 3);
+            let b = random_string(&mut rng, 0..7, 3);
+            assert_eq!(edit_distance(&a, &b), naive_edit_distance(&a, &b));
+
+            // LCS by brute force over subsequences of `a`.
+            let want = (0..1 << a.len())
+                .filter_map(|mask: usize| {
+                    let sub: Vec<u8> = (0..a.len())
+                        .filter(|&i| mask >> i & 1 == 1)
+                        .map(|i| a[i])
+                        .collect();
+                    let mut it = b.iter();
+                    sub.iter().all(|c| it.any(|d| d == c)).then_some(sub.len())
+                })
+                .max()
+                .unwrap();
+            assert_eq!(lcs_length(&a, &b), want);
+            assert_eq!(lcs_length_bitset(&a, &b), want);
+            let pairs = lcs(&a, &b);
+            assert_eq!(pairs.len(), want);
+            assert!(pairs.iter().all(|&(i, j)| a[i] == b[j]));
+            assert!(pairs.windows(2).all(|w| w[0].0 < w[1].0 && w[0].1 < w[1].1));
+        }
+    }
+
+    #[test]
+    fn test_bitset_long() {
+        let mut rng = Rng::new(12);
+        for _ in 0..20 {
+            let a = random_string(&mut rng, 50..300, 4);
+            let b = random_string(&mut rng, 50..300, 4);
+            assert_eq!(lcs_length_bitset(&a, &b), lcs_length(&a, &b));
+            assert_eq!(lcs_length_bitset(&b, &a), lcs_length(&a, &b));
+        }
+    }
+}
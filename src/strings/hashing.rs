@@ -0,0 +1,262 @@
+//! Polynomial rolling hashes of substrings.
+//!
+//! [`RollingHash`] precomputes prefix hashes of a string in `O(n)`, after
+//! which the hash of any substring is available in `O(1)`. Substrings are
+//! compared by their hashes, so equality is probabilistic: two moduli (around
+//! `1e9` each) are used, with bases chosen randomly at runtime, so that no
+//! fixed anti-hash test can break the comparison.
+//!
+//! ```
+//! use algorist::strings::hashing::RollingHash;
+//!
+//! let s = b"abracadabra";
+//! let h = RollingHash::new(s);
+//! assert_eq!(h.hash(0..4), h.hash(7..11)); // "abra"
+//! assert!(h.substr_eq(0..4, 7..11));
+//! assert!(!h.substr_eq(0..4, 1..5));
+//!
+//! // Longest common prefix of the suffixes "abracadabra" and "abra".
+//! assert_eq!(h.lcp(0, 7), 4);
+//! ```
+//!
+//! # Comparing substrings of different strings
+//!
+//! Hashes are comparable only if they are computed with the same bases, use
+//! [`RollingHash::with_bases`] for the second string:
+//!
+//! ```
+//! use algorist::strings::hashing::RollingHash;
+//!
+//! let (text, pattern) = (b"mississippi", b"ssi");
+//! let ht = RollingHash::new(text);
+//! let hp = RollingHash::with_bases(pattern, ht.bases());
+//! let target = hp.hash(0..pattern.len());
+//! let found: Vec<_> = (0..=text.len() - pattern.len())
+//!     .filter(|&i| ht.hash(i..i + pattern.len()) == target)
+//!     .collect();
+//! assert_eq!(found, vec![2, 5]);
+//! ```
+
+use {crate::misc::rng::Rng, std::ops::Range};
+
+/// Moduli of the two hashes.
+const MODS: [u64; 2] = [1_000_000_007, 998_244_353];
+
+fn mul(a: u64, b: u64, k: usize) -> u64 {
+    a * b % MODS[k]
+}
+
+fn add(a: u64, b: u64, k: usize) -> u64 {
+    (a + b) % MODS[k]
+}
+
+/// Hash of a string (or substring): comparable with hashes computed with the
+/// same bases.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StrHash {
+    h: [u64; 2],
+    len: usize,
+}
+
+impl StrHash {
+    /// Returns the length of the hashed string.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks whether the hashed string is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+/// Prefix hashes of a string, see the [module documentation](self).
+#[derive(Debug, Clone)]
+pub struct RollingHash {
+    bases: [u64; 2],
+    /// Hashes of prefixes of lengths `0..=n`.
+    pre: Vec<[u64; 2]>,
+    /// Powers of the bases, `0..=n`.
+    pow: Vec<[u64; 2]>,
+}
+
+impl RollingHash {
+    /// Precomputes prefix hashes of `s`, with random bases.
+    pub fn new<T: Copy + Into<u64>>(s: &[T]) -> Self {
+        let mut rng = Rng::from_time();
+        let bases = [0, 1].map(|k| rng.gen_range(256..MODS[k] - 1));
+        Self::with_bases(s, bases)
+    }
+
+    /// Precomputes prefix hashes of `s`, with the given bases (normally,
+    /// obtained from another hash with [`RollingHash::bases`]).
+    ///
+    /// # Panics
+    ///
+    /// Panics if bases are not less than the moduli.
+    pub fn with_bases<T: Copy + Into<u64>>(s: &[T], bases: [u64; 2]) -> Self {
+        assert!(
+            bases[0] < MODS[0] && bases[1] < MODS[1],
+            "Bases must be less than the moduli {MODS:?}"
+        );
+        let n = s.len();
+        let mut pre = Vec::with_capacity(n + 1);
+        let mut pow = Vec::with_capacity(n + 1);
+        pre.push([0; 2]);
+        pow.push([1; 2]);
+        for (i, &c) in s.iter().enumerate() {
+            let c: u64 = c.into();
+            pre.push([0, 1].map(|k| add(mul(pre[i][k], bases[k], k), c % MODS[k], k)));
+            pow.push([0, 1].map(|k| mul(pow[i][k], bases[k], k)));
+        }
+        Self { bases, pre, pow }
+    }
+
+    /// Returns the bases of the hash.
+    pub fn bases(&self) -> [u64; 2] {
+        self.bases
+    }
+
+    /// Returns the length of the string.
+    pub fn len(&self) -> usize {
+        self.pre.len() - 1
+    }
+
+    /// Checks whether the string is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the hash of the substring in the `range`, in `O(1)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    pub fn hash(&self, range: Range<usize>) -> StrHash {
+        assert!(
+            range.start <= range.end && range.end <= self.len(),
+            "Range {range:?} is out of bounds"
+        );
+        let (l, r) = (range.start, range.end);
+        let h = [0, 1].map(|k| {
+            let shifted = mul(self.pre[l][k], self.pow[r - l][k], k);
+            add(self.pre[r][k], MODS[k] - shifted, k)
+        });
+        StrHash { h, len: r - l }
+    }
+
+    /// Checks whether substrings in ranges `a` and `b` are equal (with high
+    /// probability), in `O(1)`.
+    pub fn substr_eq(&self, a: Range<usize>, b: Range<usize>) -> bool {
+        self.hash(a) == self.hash(b)
+    }
+
+    /// Returns the length of the longest common prefix of suffixes starting at
+    /// `i` and `j`, in `O(log n)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::strings::hashing::RollingHash;
+    ///
+    /// let h = RollingHash::new(b"aabaab");
+    /// assert_eq!(h.lcp(0, 3), 3);
+    /// assert_eq!(h.lcp(1, 4), 2);
+    /// assert_eq!(h.lcp(0, 6), 0);
+    /// ```
+    pub fn lcp(&self, i: usize, j: usize) -> usize {
+        let max = self.len() - i.max(j);
+        // The answer is in `[lo, hi]`.
+        let (mut lo, mut hi) = (0, max);
+        while lo < hi {
+            let mid = (lo + hi).div_ceil(2);
+            if self.substr_eq(i..i + mid, j..j + mid) {
+                lo = mid;
+            } else {
+                hi = mid - 1;
+            }
+        }
+        lo
+    }
+
+    /// Returns the hash of the concatenation of two strings, hashed with the
+    /// same bases as this one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the second string is longer than this one (powers of the
+    /// bases are only precomputed up to the length of the string).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::strings::hashing::RollingHash;
+    ///
+    /// let h = RollingHash::new(b"abcab");
+    /// let ab = h.hash(0..2);
+    /// assert_eq!(h.concat(ab, h.hash(2..3)), h.hash(0..3));
+    /// assert_eq!(h.concat(h.hash(2..3), ab), h.hash(2..5));
+    /// ```
+    pub fn concat(&self, a: StrHash, b: StrHash) -> StrHash {
+        assert!(
+            b.len <= self.len(),
+            "Length {} exceeds the precomputed powers",
+            b.len
+        );
+        let h = [0, 1].map(|k| add(mul(a.h[k], self.pow[b.len][k], k), b.h[k], k));
+        StrHash {
+            h,
+            len: a.len + b.len,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_substrings() {
+        let mut rng = Rng::new(4);
+        for _ in 0..50 {
+            let n = rng.gen_range(1..40);
+            let s: Vec<u8> = (0..n).map(|_| rng.gen_range(b'a'..=b'b')).collect();
+            let h = RollingHash::new(&s);
+            assert_eq!(h.len(), n);
+            for _ in 0..50 {
+                let (i, j) = (rng.gen_range(0..n), rng.gen_range(0..n));
+                let len = rng.gen_range(0..=n - i.max(j));
+                assert_eq!(
+                    h.substr_eq(i..i + len, j..j + len),
+                    s[i..i + len] == s[j..j + len]
+                );
+                let lcp = s[i..]
+                    .iter()
+                    .zip(&s[j..])
+                    .take_while(|(a, b)| a == b)
+                    .count();
+                assert_eq!(h.lcp(i, j), lcp);
+                let k = rng.gen_range(i..=n);
+                assert_eq!(h.concat(h.hash(i..k), h.hash(k..n)), h.hash(i..n));
+            }
+        }
+    }
+
+    #[test]
+    fn test_fixed_bases() {
+        // Hashes with equal bases are comparable across strings, including
+        // wider characters.
+        let a = RollingHash::with_bases(&[1u32, 70_000, 3], [31, 37]);
+        let b = RollingHash::with_bases(&[9u32, 1, 70_000], a.bases());
+        assert_eq!(a.hash(0..2), b.hash(1..3));
+        assert_ne!(a.hash(0..2), b.hash(0..2));
+        assert_eq!(a.hash(1..1), b.hash(3..3));
+        assert!(a.hash(0..0).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "Range 2..5 is out of bounds")]
+    fn test_out_of_bounds() {
+        RollingHash::new(b"abcd").hash(2..5);
+    }
+}
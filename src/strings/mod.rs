@@ -0,0 +1,20 @@
+//! String algorithms.
+//!
+//! Algorithms in this module work on slices (normally, the `Vec<u8>` produced
+//! by [`Scanner::bytes()`](crate::io::Scanner::bytes)), rather than on `str`.
+//!
+//! Currently, this module contains:
+//!
+//! | Module | Description
+//! | --- | ---
+//! | [`dp`] | Edit distance and longest common subsequence, including a bit-parallel LCS.
+//! | [`hashing`] | Double polynomial rolling hash, for `O(1)` substring comparisons.
+//! | [`prefix`] | Z-function and prefix function, and linear time pattern search.
+//! | [`suffix_array`] | Suffix array and LCP array construction, and pattern search over them.
+//! | [`trie`] | Trie over a small alphabet, with access to nodes for trie DP.
+
+pub mod dp;
+pub mod hashing;
+pub mod prefix;
+pub mod suffix_array;
+pub mod trie;
@@ -0,0 +1,175 @@
+//! Z-function and prefix function, and linear time pattern search.
+//!
+//! For a string `s`:
+//! - [`z_function`] gives, for each position `i`, the length of the longest
+//!   common prefix of `s` and `s[i..]`;
+//! - [`prefix_function`] gives, for each position `i`, the length of the
+//!   longest proper prefix of `s[..=i]`, which is also its suffix (the border).
+//!
+//! Both are computed in `O(n)`, and allow to find all occurrences of a pattern
+//! in a text in `O(n + m)`, see [`find_all`].
+//!
+//! ```
+//! use algorist::strings::prefix::{find_all, prefix_function, z_function};
+//!
+//! assert_eq!(z_function(b"aabxaab"), vec![7, 1, 0, 0, 3, 1, 0]);
+//! assert_eq!(prefix_function(b"aabxaab"), vec![0, 1, 0, 0, 1, 2, 3]);
+//! assert_eq!(find_all(b"aba", b"abababa"), vec![0, 2, 4]);
+//! ```
+//!
+//! # Periods
+//!
+//! The smallest period of `s` is `n - pi[n - 1]`, where `pi` is the prefix
+//! function:
+//!
+//! ```
+//! use algorist::strings::prefix::prefix_function;
+//!
+//! let s = b"abcabcab";
+//! let period = s.len() - prefix_function(s).last().unwrap();
+//! assert_eq!(period, 3);
+//! ```
+
+/// Returns the Z-function of `s`: element `i` is the length of the longest
+/// common prefix of `s` and `s[i..]` (so that the element `0` is `s.len()`).
+///
+/// # Example
+///
+/// ```
+/// use algorist::strings::prefix::z_function;
+///
+/// assert_eq!(z_function(b"aaaaa"), vec![5, 4, 3, 2, 1]);
+/// assert_eq!(z_function(b"abacaba"), vec![7, 0, 1, 0, 3, 0, 1]);
+/// assert!(z_function::<u8>(b"").is_empty());
+/// ```
+pub fn z_function<T: Eq>(s: &[T]) -> Vec<usize> {
+    let n = s.len();
+    let mut z = vec![0; n];
+    if n == 0 {
+        return z;
+    }
+    z[0] = n;
+    // `[l, r)` is the rightmost segment, matching a prefix of `s`.
+    let (mut l, mut r) = (0, 0);
+    for i in 1..n {
+        if i < r {
+            z[i] = z[i - l].min(r - i);
+        }
+        while i + z[i] < n && s[z[i]] == s[i + z[i]] {
+            z[i] += 1;
+        }
+        if i + z[i] > r {
+            (l, r) = (i, i + z[i]);
+        }
+    }
+    z
+}
+
+/// Returns the prefix function of `s`: element `i` is the length of the
+/// longest proper prefix of `s[..=i]`, which is also a suffix of it.
+///
+/// # Example
+///
+/// ```
+/// use algorist::strings::prefix::prefix_function;
+///
+/// assert_eq!(prefix_function(b"aaaaa"), vec![0, 1, 2, 3, 4]);
+/// assert_eq!(prefix_function(b"abacaba"), vec![0, 0, 1, 0, 1, 2, 3]);
+/// ```
+pub fn prefix_function<T: Eq>(s: &[T]) -> Vec<usize> {
+    let mut pi = vec![0; s.len()];
+    for i in 1..s.len() {
+        let mut k = pi[i - 1];
+        while k > 0 && s[i] != s[k] {
+            k = pi[k - 1];
+        }
+        if s[i] == s[k] {
+            k += 1;
+        }
+        pi[i] = k;
+    }
+    pi
+}
+
+/// Returns starting positions of all (possibly overlapping) occurrences of
+/// the `pattern` in the `text`, in increasing order, in `O(n + m)`
+/// (Knuth-Morris-Pratt).
+///
+/// An empty pattern occurs at every position, from `0` to `text.len()`
+/// inclusive.
+///
+/// # Example
+///
+/// ```
+/// use algorist::strings::prefix::find_all;
+///
+/// assert_eq!(find_all(b"aa", b"aaaa"), vec![0, 1, 2]);
+/// assert_eq!(find_all(b"issi", b"mississippi"), vec![1, 4]);
+/// assert!(find_all(b"abc", b"ab").is_empty());
+/// assert_eq!(find_all(b"", b"ab"), vec![0, 1, 2]);
+/// ```
+pub fn find_all<T: Eq>(pattern: &[T], text: &[T]) -> Vec<usize> {
+    let m = pattern.len();
+    if m == 0 {
+        return (0..=text.len()).collect();
+    }
+    let pi = prefix_function(pattern);
+    let mut res = vec![];
+    let mut k = 0;
+    for (i, c) in text.iter().enumerate() {
+        while k > 0 && (k == m || *c != pattern[k]) {
+            k = pi[k - 1];
+        }
+        if *c == pattern[k] {
+            k += 1;
+        }
+        if k == m {
+            res.push(i + 1 - m);
+        }
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::misc::rng::Rng};
+
+    fn random_text(rng: &mut Rng, n: usize) -> Vec<u8> {
+        (0..n).map(|_| rng.gen_range(b'a'..=b'c')).collect()
+    }
+
+    #[test]
+    fn test_against_naive() {
+        let mut rng = Rng::new(9);
+        for _ in 0..300 {
+            let n = rng.gen_range(0..30);
+            let s = random_text(&mut rng, n);
+
+            let z = z_function(&s);
+            for i in 0..n {
+                let lcp = s.iter().zip(&s[i..]).take_while(|(a, b)| a == b).count();
+                assert_eq!(z[i], lcp);
+            }
+
+            let pi = prefix_function(&s);
+            for i in 0..n {
+                let border = (0..=i).rev().find(|&k| s[..k] == s[i + 1 - k..=i]).unwrap();
+                assert_eq!(pi[i], border);
+            }
+
+            let m = rng.gen_range(1..4);
+            let pattern = random_text(&mut rng, m);
+            let want: Vec<usize> = (0..n.saturating_sub(m - 1))
+                .filter(|&i| s[i..i + m] == pattern[..])
+                .collect();
+            assert_eq!(find_all(&pattern, &s), want);
+        }
+    }
+
+    #[test]
+    fn test_generic() {
+        assert_eq!(find_all(&[1, 2], &[1, 2, 1, 2, 1]), vec![0, 2]);
+        let words = ["a", "b", "a"];
+        assert_eq!(z_function(&words), vec![3, 0, 1]);
+    }
+}
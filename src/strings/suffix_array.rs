@@ -0,0 +1,368 @@
+//! Suffix array and searching over it.
+//!
+//! Suffix array of a string is the array of starting positions of all its
+//! suffixes, in lexicographic order of the suffixes. Use [`suffix_array`] to
+//! build it.
+//!
+//! ```
+//! use algorist::strings::suffix_array::suffix_array;
+//!
+//! // Suffixes of "banana" in sorted order:
+//! // 5: a
+//! // 3: ana
+//! // 1: anana
+//! // 0: banana
+//! // 4: na
+//! // 2: nana
+//! assert_eq!(suffix_array(b"banana"), vec![5, 3, 1, 0, 4, 2]);
+//! ```
+//!
+//! # LCP array
+//!
+//! Longest common prefixes of adjacent suffixes in the suffix array are
+//! computed with [`lcp_array`] in `O(n)`. Many questions about substrings
+//! reduce to them, e.g. the number of [`distinct_substrings`]:
+//!
+//! ```
+//! use algorist::strings::suffix_array::{distinct_substrings, lcp_array, suffix_array};
+//!
+//! let s = b"banana";
+//! let sa = suffix_array(s);
+//! // a, ana, anana, banana, na, nana
+//! assert_eq!(lcp_array(s, &sa), vec![1, 3, 0, 0, 2]);
+//! assert_eq!(distinct_substrings(s), 15);
+//! ```
+//!
+//! # Pattern search
+//!
+//! Since all occurrences of a pattern are prefixes of a contiguous range of
+//! sorted suffixes, the suffix array allows to count and find occurrences of
+//! any pattern with a binary search, see [`SaSearcher`].
+//!
+//! ```
+//! use algorist::strings::suffix_array::SaSearcher;
+//!
+//! let text = b"abracadabra";
+//! let searcher = SaSearcher::new(text);
+//! assert_eq!(searcher.count(b"abra"), 2);
+//! assert_eq!(searcher.find_all(b"a"), vec![0, 3, 5, 7, 10]);
+//! assert_eq!(searcher.count(b"cab"), 0);
+//! ```
+
+use std::{cmp::Ordering, ops::Range};
+
+/// Builds the suffix array of `s` in `O(n log n)`, using prefix doubling.
+///
+/// Element at index `i` of the result is the starting position of the `i`-th
+/// smallest suffix of `s`.
+///
+/// # Example
+///
+/// ```
+/// use algorist::strings::suffix_array::suffix_array;
+///
+/// assert_eq!(suffix_array(b"abab"), vec![2, 0, 3, 1]);
+/// assert_eq!(suffix_array(b"aaaa"), vec![3, 2, 1, 0]);
+///
+/// // Works on any ordered elements, not only on bytes:
+/// assert_eq!(suffix_array(&[3, 1, 2]), vec![1, 2, 0]);
+/// ```
+pub fn suffix_array<T: Ord>(s: &[T]) -> Vec<usize> {
+    let n = s.len();
+    let mut sa: Vec<usize> = (0..n).collect();
+    if n <= 1 {
+        return sa;
+    }
+
+    sa.sort_by(|&a, &b| s[a].cmp(&s[b]));
+    let mut rank = vec![0; n];
+    for i in 1..n {
+        rank[sa[i]] = rank[sa[i - 1]] + usize::from(s[sa[i]] != s[sa[i - 1]]);
+    }
+
+    let mut k = 1;
+    let mut by_second = Vec::with_capacity(n);
+    let mut new_rank = vec![0; n];
+    while k < n && rank[sa[n - 1]] < n - 1 {
+        // Order by the second half of the key: suffixes without the second half
+        // go first, the rest are in the order of their second halves.
+        by_second.clear();
+        by_second.extend(n - k..n);
+        by_second.extend(sa.iter().filter(|&&i| i >= k).map(|&i| i - k));
+
+        // Stable counting sort by the first half of the key.
+        let mut cnt = vec![0; rank[sa[n - 1]] + 2];
+        for &i in &by_second {
+            cnt[rank[i] + 1] += 1;
+        }
+        for i in 1..cnt.len() {
+            cnt[i] += cnt[i - 1];
+        }
+        for &i in &by_second {
+            sa[cnt[rank[i]]] = i;
+            cnt[rank[i]] += 1;
+        }
+
+        let key = |i: usize| (rank[i], (i + k < n).then(|| rank[i + k]));
+        new_rank[sa[0]] = 0;
+        for i in 1..n {
+            new_rank[sa[i]] = new_rank[sa[i - 1]] + usize::from(key(sa[i - 1]) != key(sa[i]));
+        }
+        std::mem::swap(&mut rank, &mut new_rank);
+        k <<= 1;
+    }
+    sa
+}
+
+/// Returns the LCP array in `O(n)` (Kasai's algorithm): element `i` is the
+/// length of the longest common prefix of suffixes `sa[i]` and `sa[i + 1]`.
+///
+/// The result has `n - 1` elements (none for an empty string).
+///
+/// # Panics
+///
+/// Panics if `sa` is not of the same length as `s`.
+///
+/// # Example
+///
+/// ```
+/// use algorist::strings::suffix_array::{lcp_array, suffix_array};
+///
+/// let s = b"aaaa";
+/// assert_eq!(lcp_array(s, &suffix_array(s)), vec![1, 2, 3]);
+/// ```
+pub fn lcp_array<T: Eq>(s: &[T], sa: &[usize]) -> Vec<usize> {
+    let n = s.len();
+    assert_eq!(
+        n,
+        sa.len(),
+        "Suffix array must be of the same length as the string"
+    );
+    if n == 0 {
+        return vec![];
+    }
+    let mut rank = vec![0; n];
+    for (i, &p) in sa.iter().enumerate() {
+        rank[p] = i;
+    }
+    let mut lcp = vec![0; n - 1];
+    // LCP with the next suffix decreases by at most 1, when moving from the
+    // suffix `i` to the suffix `i + 1`.
+    let mut k: usize = 0;
+    for i in 0..n {
+        if rank[i] == n - 1 {
+            k = 0;
+            continue;
+        }
+        let j = sa[rank[i] + 1];
+        while i + k < n && j + k < n && s[i + k] == s[j + k] {
+            k += 1;
+        }
+        lcp[rank[i]] = k;
+        k = k.saturating_sub(1);
+    }
+    lcp
+}
+
+/// Returns the number of distinct non-empty substrings of `s`, in `O(n log
+/// n)`.
+///
+/// # Example
+///
+/// ```
+/// use algorist::strings::suffix_array::distinct_substrings;
+///
+/// assert_eq!(distinct_substrings(b"aaaa"), 4);
+/// assert_eq!(distinct_substrings(b"abc"), 6);
+/// assert_eq!(distinct_substrings::<u8>(b""), 0);
+/// ```
+pub fn distinct_substrings<T: Ord>(s: &[T]) -> u64 {
+    let n = s.len() as u64;
+    let common: usize = lcp_array(s, &suffix_array(s)).iter().sum();
+    n * (n + 1) / 2 - common as u64
+}
+
+/// Pattern searcher over the suffix array of a text.
+///
+/// Each query runs in `O(m log n)`, where `m` is the length of the pattern and
+/// `n` is the length of the text.
+///
+/// # Example
+///
+/// ```
+/// use algorist::strings::suffix_array::SaSearcher;
+///
+/// let text = b"mississippi";
+/// let searcher = SaSearcher::new(text);
+///
+/// assert_eq!(searcher.count(b"ss"), 2);
+/// assert_eq!(searcher.count(b"i"), 4);
+/// assert_eq!(searcher.count(b"x"), 0);
+/// assert_eq!(searcher.find_all(b"issi"), vec![1, 4]);
+/// assert!(searcher.contains(b"sip"));
+/// ```
+pub struct SaSearcher<'a, T> {
+    text: &'a [T],
+    sa: Vec<usize>,
+}
+
+impl<'a, T: Ord> SaSearcher<'a, T> {
+    /// Creates a new searcher, building the suffix array of the `text`.
+    pub fn new(text: &'a [T]) -> Self {
+        Self {
+            sa: suffix_array(text),
+            text,
+        }
+    }
+
+    /// Creates a new searcher from an already built suffix array of the `text`.
+    pub fn from_sa(text: &'a [T], sa: Vec<usize>) -> Self {
+        assert_eq!(text.len(), sa.len());
+        Self { text, sa }
+    }
+
+    /// Returns the suffix array of the text.
+    pub fn sa(&self) -> &[usize] {
+        &self.sa
+    }
+
+    /// Returns the range of indexes in the suffix array, for suffixes that
+    /// start with the `pattern`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algorist::strings::suffix_array::SaSearcher;
+    ///
+    /// let searcher = SaSearcher::new(b"banana");
+    /// // Suffix array is [5, 3, 1, 0, 4, 2], suffixes 3 and 1 start with "ana".
+    /// assert_eq!(searcher.range(b"ana"), 1..3);
+    /// assert!(searcher.range(b"nab").is_empty());
+    /// ```
+    pub fn range(&self, pattern: &[T]) -> Range<usize> {
+        let cmp = |i: usize| {
+            self.text[i..]
+                .iter()
+                .take(pattern.len())
+                .cmp(pattern.iter())
+        };
+        let lo = self.sa.partition_point(|&i| cmp(i) == Ordering::Less);
+        let hi = lo + self.sa[lo..].partition_point(|&i| cmp(i) == Ordering::Equal);
+        lo..hi
+    }
+
+    /// Returns the number of (possibly overlapping) occurrences of the
+    /// `pattern` in the text.
+    ///
+    /// An empty pattern occurs at every position of the text.
+    pub fn count(&self, pattern: &[T]) -> usize {
+        self.range(pattern).len()
+    }
+
+    /// Returns whether the `pattern` occurs in the text.
+    pub fn contains(&self, pattern: &[T]) -> bool {
+        !self.range(pattern).is_empty()
+    }
+
+    /// Returns starting positions of all (possibly overlapping) occurrences of
+    /// the `pattern` in the text, in increasing order.
+    pub fn find_all(&self, pattern: &[T]) -> Vec<usize> {
+        let mut positions = self.sa[self.range(pattern)].to_vec();
+        positions.sort_unstable();
+        positions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn naive_suffix_array<T: Ord>(s: &[T]) -> Vec<usize> {
+        let mut sa: Vec<usize> = (0..s.len()).collect();
+        sa.sort_by(|&a, &b| s[a..].cmp(&s[b..]));
+        sa
+    }
+
+    #[test]
+    fn test_suffix_array() {
+        assert_eq!(suffix_array::<u8>(b""), Vec::<usize>::new());
+        assert_eq!(suffix_array(b"a"), vec![0]);
+        assert_eq!(suffix_array(b"banana"), vec![5, 3, 1, 0, 4, 2]);
+        assert_eq!(suffix_array(b"mississippi"), vec![
+            10, 7, 4, 1, 0, 9, 8, 6, 3, 5, 2
+        ]);
+
+        let texts: [&[u8]; 6] = [
+            b"abracadabra",
+            b"aaaaaaaaaa",
+            b"abababababab",
+            b"zyxwvutsrqponm",
+            b"abcabcabcabd",
+            b"cbbaabbcabcaacbbcacbaabc",
+        ];
+        for text in texts {
+            assert_eq!(suffix_array(text), naive_suffix_array(text));
+        }
+    }
+
+    #[test]
+    fn test_lcp_array() {
+        let texts: [&[u8]; 5] = [
+            b"",
+            b"a",
+            b"abracadabra",
+            b"abababababab",
+            b"cbbaabbcabcaacbbcacbaabc",
+        ];
+        for text in texts {
+            let sa = suffix_array(text);
+            let lcp = lcp_array(text, &sa);
+            assert_eq!(lcp.len(), text.len().saturating_sub(1));
+            for (i, &l) in lcp.iter().enumerate() {
+                let (a, b) = (&text[sa[i]..], &text[sa[i + 1]..]);
+                assert_eq!(l, a.iter().zip(b).take_while(|(x, y)| x == y).count());
+            }
+
+            let mut all = std::collections::HashSet::new();
+            for i in 0..text.len() {
+                for j in i + 1..=text.len() {
+                    all.insert(&text[i..j]);
+                }
+            }
+            assert_eq!(distinct_substrings(text), all.len() as u64);
+        }
+    }
+
+    #[test]
+    fn test_searcher() {
+        let text = b"abracadabra";
+        let searcher = SaSearcher::new(text);
+        assert_eq!(searcher.count(b"abra"), 2);
+        assert_eq!(searcher.count(b"a"), 5);
+        assert_eq!(searcher.count(b"abracadabra"), 1);
+        assert_eq!(searcher.count(b"abracadabrab"), 0);
+        assert_eq!(searcher.count(b"z"), 0);
+        assert_eq!(searcher.count(b""), text.len());
+        assert_eq!(searcher.find_all(b"abra"), vec![0, 7]);
+        assert_eq!(searcher.find_all(b"bra"), vec![1, 8]);
+        assert_eq!(searcher.find_all(b"ra"), vec![2, 9]);
+        assert_eq!(searcher.find_all(b"cad"), vec![4]);
+        assert_eq!(searcher.find_all(b"dab"), vec![6]);
+        assert_eq!(searcher.find_all(b"x"), Vec::<usize>::new());
+        assert!(searcher.contains(b"acad"));
+        assert!(!searcher.contains(b"acab"));
+
+        // Overlapping occurrences.
+        let searcher = SaSearcher::new(b"aaaa");
+        assert_eq!(searcher.count(b"aa"), 3);
+        assert_eq!(searcher.find_all(b"aa"), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_searcher_from_sa() {
+        let text = b"banana";
+        let searcher = SaSearcher::from_sa(text, suffix_array(text));
+        assert_eq!(searcher.sa(), &[5, 3, 1, 0, 4, 2]);
+        assert_eq!(searcher.find_all(b"na"), vec![2, 4]);
+    }
+}
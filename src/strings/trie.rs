@@ -0,0 +1,240 @@
+//! Trie (prefix tree) over a small alphabet.
+//!
+//! Keys are sequences of symbols in `0..K` (e.g. `c - b'a'` for lowercase
+//! letters, with `K = 26`, or bits of numbers, with `K = 2`). Nodes are stored
+//! in a single vector, and are identified by indexes, with the root at
+//! [`Trie::ROOT`]. Each node keeps the number of inserted keys passing through
+//! it and ending at it, which allows to run DP over the trie directly.
+//!
+//! ```
+//! use algorist::strings::trie::Trie;
+//!
+//! let letters = |s: &str| s.bytes().map(|c| usize::from(c - b'a')).collect::<Vec<_>>();
+//!
+//! let mut trie = Trie::<26>::new();
+//! for w in ["apple", "app", "apply", "app", "bat"] {
+//!     trie.insert(letters(w));
+//! }
+//! assert_eq!(trie.count(letters("app")), 2);
+//! assert_eq!(trie.count(letters("ap")), 0);
+//! assert_eq!(trie.prefix_count(letters("app")), 4);
+//! assert_eq!(trie.prefix_count(letters("")), 5);
+//! ```
+//!
+//! # Traversal
+//!
+//! Nodes are created in insertion order, so that a parent always precedes its
+//! children, and a reverse pass over the nodes visits children before
+//! parents.
+//!
+//! ```
+//! use algorist::strings::trie::Trie;
+//!
+//! // Maximum XOR of two numbers, with a binary trie over 3 bits.
+//! let bits = |x: usize| (0..3).rev().map(move |i| x >> i & 1);
+//! let nums = [3, 5, 6];
+//! let mut trie = Trie::<2>::new();
+//! for &x in &nums {
+//!     trie.insert(bits(x));
+//! }
+//! let best = nums
+//!     .iter()
+//!     .map(|&x| {
+//!         let (mut node, mut res) = (Trie::<2>::ROOT, 0);
+//!         for b in bits(x) {
+//!             // Prefer the opposite bit.
+//!             match trie.child(node, b ^ 1) {
+//!                 Some(next) => (node, res) = (next, res << 1 | 1),
+//!                 None => (node, res) = (trie.child(node, b).unwrap(), res << 1),
+//!             }
+//!         }
+//!         res
+//!     })
+//!     .max();
+//! assert_eq!(best, Some(6)); // 3 ^ 5
+//! ```
+
+/// Trie node.
+#[derive(Debug, Clone)]
+struct Node<const K: usize> {
+    /// Children indexes, `0` if there is no child (the root is never a child).
+    next: [usize; K],
+    /// Number of keys passing through the node (including the ending ones).
+    pass: usize,
+    /// Number of keys ending at the node.
+    end: usize,
+}
+
+impl<const K: usize> Node<K> {
+    fn new() -> Self {
+        Self {
+            next: [0; K],
+            pass: 0,
+            end: 0,
+        }
+    }
+}
+
+/// Trie over the alphabet `0..K`, see the [module documentation](self).
+#[derive(Debug, Clone)]
+pub struct Trie<const K: usize> {
+    nodes: Vec<Node<K>>,
+}
+
+impl<const K: usize> Default for Trie<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const K: usize> Trie<K> {
+    /// Index of the root node.
+    pub const ROOT: usize = 0;
+
+    /// Creates an empty trie (consisting of the root only).
+    pub fn new() -> Self {
+        Self {
+            nodes: vec![Node::new()],
+        }
+    }
+
+    /// Inserts the key (duplicates are counted), and returns the node, where
+    /// it ends.
+    ///
+    /// # Panics
+    ///
+    /// Panics if some symbol is not in `0..K`.
+    pub fn insert<I: IntoIterator<Item = usize>>(&mut self, key: I) -> usize {
+        let mut node = Self::ROOT;
+        self.nodes[node].pass += 1;
+        for c in key {
+            assert!(c < K, "Symbol {c} is out of the alphabet 0..{K}");
+            if self.nodes[node].next[c] == 0 {
+                self.nodes[node].next[c] = self.nodes.len();
+                self.nodes.push(Node::new());
+            }
+            node = self.nodes[node].next[c];
+            self.nodes[node].pass += 1;
+        }
+        self.nodes[node].end += 1;
+        node
+    }
+
+    /// Returns the node, corresponding to the prefix `key`, or `None` if no
+    /// inserted key starts with it.
+    pub fn find<I: IntoIterator<Item = usize>>(&self, key: I) -> Option<usize> {
+        key.into_iter()
+            .try_fold(Self::ROOT, |node, c| self.child(node, c))
+    }
+
+    /// Returns the number of times the key has been inserted.
+    pub fn count<I: IntoIterator<Item = usize>>(&self, key: I) -> usize {
+        self.find(key).map_or(0, |node| self.ends(node))
+    }
+
+    /// Returns the number of inserted keys, starting with the `prefix`.
+    pub fn prefix_count<I: IntoIterator<Item = usize>>(&self, prefix: I) -> usize {
+        self.find(prefix).map_or(0, |node| self.passes(node))
+    }
+
+    /// Returns the number of nodes (including the root).
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Checks whether no keys have been inserted.
+    pub fn is_empty(&self) -> bool {
+        self.nodes[Self::ROOT].pass == 0
+    }
+
+    /// Returns the child of the `node` by the symbol `c`, if any.
+    pub fn child(&self, node: usize, c: usize) -> Option<usize> {
+        match self.nodes[node].next.get(c) {
+            Some(&next) if next != 0 => Some(next),
+            _ => None,
+        }
+    }
+
+    /// Returns an iterator over `(symbol, child)` pairs of the `node`, in the
+    /// order of symbols.
+    pub fn children(&self, node: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.nodes[node]
+            .next
+            .iter()
+            .enumerate()
+            .filter(|&(_, &next)| next != 0)
+            .map(|(c, &next)| (c, next))
+    }
+
+    /// Returns the number of keys passing through the `node`.
+    pub fn passes(&self, node: usize) -> usize {
+        self.nodes[node].pass
+    }
+
+    /// Returns the number of keys ending at the `node`.
+    pub fn ends(&self, node: usize) -> usize {
+        self.nodes[node].end
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::misc::rng::Rng, std::collections::HashMap};
+
+    #[test]
+    fn test_against_map() {
+        let mut rng = Rng::new(8);
+        let mut trie = Trie::<3>::new();
+        let mut words: HashMap<Vec<usize>, usize> = HashMap::new();
+        assert!(trie.is_empty());
+        for _ in 0..300 {
+            let len = rng.gen_range(0..6);
+            let w: Vec<usize> = (0..len).map(|_| rng.gen_range(0..3)).collect();
+            let node = trie.insert(w.iter().copied());
+            assert_eq!(trie.find(w.iter().copied()), Some(node));
+            *words.entry(w).or_default() += 1;
+        }
+        for (w, &cnt) in &words {
+            assert_eq!(trie.count(w.iter().copied()), cnt);
+            for k in 0..=w.len() {
+                let want: usize = words
+                    .iter()
+                    .filter(|(v, _)| v.starts_with(&w[..k]))
+                    .map(|(_, &c)| c)
+                    .sum();
+                assert_eq!(trie.prefix_count(w[..k].iter().copied()), want);
+            }
+        }
+        assert_eq!(trie.count([0, 0, 0, 0, 0, 0]), 0);
+    }
+
+    #[test]
+    fn test_traversal() {
+        let mut trie = Trie::<26>::new();
+        for w in ["to", "tea", "ted", "ten", "i", "in", "inn"] {
+            trie.insert(w.bytes().map(|c| usize::from(c - b'a')));
+        }
+        // Number of nodes: root, t, to, te, tea, ted, ten, i, in, inn.
+        assert_eq!(trie.len(), 10);
+        let t = trie
+            .child(Trie::<26>::ROOT, usize::from(b't' - b'a'))
+            .unwrap();
+        let symbols: Vec<_> = trie.children(t).map(|(c, _)| c as u8 + b'a').collect();
+        assert_eq!(symbols, b"eo");
+        assert_eq!(trie.child(t, 25), None);
+        assert_eq!(trie.child(t, 100), None);
+
+        // Number of words in each subtree, computed children-first.
+        let mut sub = vec![0; trie.len()];
+        for v in (0..trie.len()).rev() {
+            sub[v] = trie.ends(v) + trie.children(v).map(|(_, u)| sub[u]).sum::<usize>();
+            assert_eq!(sub[v], trie.passes(v));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Symbol 2 is out of the alphabet 0..2")]
+    fn test_invalid_symbol() {
+        Trie::<2>::new().insert([0, 2]);
+    }
+}